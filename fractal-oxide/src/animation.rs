@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use std::f64::consts::TAU;
+
+use crate::FractalViewState;
+
+pub const DEFAULT_SPIRAL_TURNS: f64 = 2.0;
+pub const DEFAULT_SPIRAL_TIGHTNESS: f64 = 3.0;
+/// Below this tightness the spiral's radius shrinks linearly
+const SPIRAL_MIN_TIGHTNESS: f64 = 1e-6;
+
+/// How the center travels between two keyframes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZoomPath {
+    /// Straight line from one center to the next
+    Linear,
+    /// Logarithmic spiral around the later keyframe's center: the offset
+    /// rotates `turns` times while its length decays like `e^(-tightness * t)`,
+    /// rescaled to land exactly on the target
+    Spiral { turns: f64, tightness: f64 },
+}
+
+impl ZoomPath {
+    pub fn spiral() -> Self {
+        ZoomPath::Spiral {
+            turns: DEFAULT_SPIRAL_TURNS,
+            tightness: DEFAULT_SPIRAL_TIGHTNESS,
+        }
+    }
+
+    /// Center at fraction `t` of the way from `start` to `end`
+    fn center(self, start: (f64, f64), end: (f64, f64), t: f64) -> (f64, f64) {
+        match self {
+            ZoomPath::Linear => (
+                start.0 + (end.0 - start.0) * t,
+                start.1 + (end.1 - start.1) * t,
+            ),
+            ZoomPath::Spiral { turns, tightness } => {
+                // Exact endpoints, so keyframes are hit despite rounding
+                if t <= 0.0 {
+                    return start;
+                }
+                if t >= 1.0 {
+                    return end;
+                }
+                let radius = if tightness.abs() < SPIRAL_MIN_TIGHTNESS {
+                    1.0 - t
+                } else {
+                    let floor = (-tightness).exp();
+                    ((-tightness * t).exp() - floor) / (1.0 - floor)
+                };
+                let (sin, cos) = (TAU * turns * t).sin_cos();
+                let (dx, dy) = (start.0 - end.0, start.1 - end.1);
+                (
+                    end.0 + radius * (dx * cos - dy * sin),
+                    end.1 + radius * (dx * sin + dy * cos),
+                )
+            }
+        }
+    }
+}
+
+/// A snapshot of the view and fractal parameters at a point in time.
+#[derive(Debug, Clone)]
+pub struct Keyframe {
+    /// Position on the timeline in seconds
+    pub time: f64,
+    pub view: FractalViewState,
+}
+
+impl Keyframe {
+    pub fn new(time: f64, view: FractalViewState) -> Self {
+        Self { time, view }
+    }
+}
+
+/// Interpolate the view at `time` from a list of keyframes sorted by time.
+///
+/// The center follows `path`; iterations and fractal parameters blend
+/// linearly; zoom blends geometrically so the apparent zoom speed stays
+/// constant. Palette and
+/// color processor (with its parameters) are discrete and taken from the
+/// earlier keyframe.
+/// Times outside the keyframe range clamp to the first/last keyframe.
+pub fn interpolate(keyframes: &[Keyframe], time: f64, path: ZoomPath) -> Option<FractalViewState> {
+    let first = keyframes.first()?;
+    let last = keyframes.last()?;
+    if time <= first.time {
+        return Some(first.view.clone());
+    }
+    if time >= last.time {
+        return Some(last.view.clone());
+    }
+
+    let (a, b) = keyframes
+        .windows(2)
+        .map(|w| (&w[0], &w[1]))
+        .find(|(a, b)| time >= a.time && time <= b.time)?;
+
+    let span = b.time - a.time;
+    if span <= 0.0 {
+        return Some(b.view.clone());
+    }
+    let t = (time - a.time) / span;
+    Some(blend_views(&a.view, &b.view, t, path))
+}
+
+/// Evenly spaced sample times covering the keyframe range, inclusive of
+/// both ends.
+pub fn frame_times(keyframes: &[Keyframe], frame_count: usize) -> Vec<f64> {
+    let (Some(first), Some(last)) = (keyframes.first(), keyframes.last()) else {
+        return Vec::new();
+    };
+    if frame_count <= 1 {
+        return vec![first.time];
+    }
+    let duration = last.time - first.time;
+    (0..frame_count)
+        .map(|i| first.time + duration * i as f64 / (frame_count - 1) as f64)
+        .collect()
+}
+
+fn blend_views(
+    a: &FractalViewState,
+    b: &FractalViewState,
+    t: f64,
+    path: ZoomPath,
+) -> FractalViewState {
+    let lerp = |x: f64, y: f64| x + (y - x) * t;
+
+    // Geometric interpolation for zoom; fall back to linear for bad values
+    let zoom = if a.zoom > 0.0 && b.zoom > 0.0 {
+        a.zoom * (b.zoom / a.zoom).powf(t)
+    } else {
+        lerp(a.zoom, b.zoom)
+    };
+
+    let mut fractal_params: HashMap<String, f64> = a.fractal_params.clone();
+    for (name, &end) in &b.fractal_params {
+        let start = a.fractal_params.get(name).copied().unwrap_or(end);
+        fractal_params.insert(name.clone(), lerp(start, end));
+    }
+
+    let (center_x, center_y) = path.center((a.center_x, a.center_y), (b.center_x, b.center_y), t);
+
+    FractalViewState {
+        center_x,
+        center_y,
+        zoom,
+        max_iterations: lerp(a.max_iterations as f64, b.max_iterations as f64).round() as u32,
+        fractal_params,
+        palette_type: a.palette_type,
+        color_processor_type: a.color_processor_type,
+        hp_center: None,
+        processor_params: a.processor_params.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(center_x: f64, zoom: f64, iterations: u32, c_real: f64) -> FractalViewState {
+        FractalViewState {
+            center_x,
+            center_y: 0.0,
+            zoom,
+            max_iterations: iterations,
+            fractal_params: HashMap::from([("c_real".to_string(), c_real)]),
+            ..Default::default()
+        }
+    }
+
+    fn keyframes() -> Vec<Keyframe> {
+        vec![
+            Keyframe::new(0.0, view(-0.5, 1.0, 100, -0.8)),
+            Keyframe::new(2.0, view(0.5, 100.0, 300, 0.2)),
+        ]
+    }
+
+    #[test]
+    fn test_interpolate_at_keyframes_is_exact() {
+        let kfs = keyframes();
+        for kf in &kfs {
+            let v = interpolate(&kfs, kf.time, ZoomPath::Linear).unwrap();
+            assert_eq!(v.center_x, kf.view.center_x);
+            assert_eq!(v.zoom, kf.view.zoom);
+            assert_eq!(v.max_iterations, kf.view.max_iterations);
+            assert_eq!(v.fractal_params["c_real"], kf.view.fractal_params["c_real"]);
+        }
+    }
+
+    #[test]
+    fn test_interpolate_midpoint() {
+        let v = interpolate(&keyframes(), 1.0, ZoomPath::Linear).unwrap();
+        // Linear for center, iterations and parameters
+        assert!((v.center_x - 0.0).abs() < 1e-12);
+        assert_eq!(v.max_iterations, 200);
+        assert!((v.fractal_params["c_real"] - (-0.3)).abs() < 1e-12);
+        // Geometric for zoom: sqrt(1 * 100)
+        assert!((v.zoom - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_interpolate_clamps_and_empty() {
+        let kfs = keyframes();
+        assert_eq!(
+            interpolate(&kfs, -1.0, ZoomPath::Linear).unwrap().center_x,
+            -0.5
+        );
+        assert_eq!(
+            interpolate(&kfs, 5.0, ZoomPath::Linear).unwrap().center_x,
+            0.5
+        );
+        assert!(interpolate(&[], 0.0, ZoomPath::Linear).is_none());
+    }
+
+    #[test]
+    fn test_spiral_path_hits_endpoints() {
+        let mut kfs = keyframes();
+        kfs[0].view.center_y = 0.3;
+        kfs[1].view.center_y = -0.2;
+        let path = ZoomPath::spiral();
+        let times = frame_times(&kfs, 30);
+        let first = interpolate(&kfs, times[0], path).unwrap();
+        let last = interpolate(&kfs, times[29], path).unwrap();
+        assert_eq!((first.center_x, first.center_y), (-0.5, 0.3));
+        assert_eq!((last.center_x, last.center_y), (0.5, -0.2));
+        // Keyframes inside the timeline are hit exactly too
+        assert_eq!(path.center((-0.5, 0.3), (0.5, -0.2), 1.0), (0.5, -0.2));
+
+        // In between, the center leaves the straight line and closes in on
+        // the target
+        let target = (0.5, -0.2);
+        let distance = |t: f64| {
+            let v = interpolate(&kfs, t, path).unwrap();
+            (v.center_x - target.0).hypot(v.center_y - target.1)
+        };
+        let linear = interpolate(&kfs, 0.5, ZoomPath::Linear).unwrap();
+        let spiral = interpolate(&kfs, 0.5, path).unwrap();
+        assert!((spiral.center_x - linear.center_x).abs() > 1e-3);
+        assert!(distance(0.5) > distance(1.0) && distance(1.0) > distance(1.5));
+        // Zoom is unaffected by the path
+        assert_eq!(spiral.zoom, linear.zoom);
+
+        let flat = ZoomPath::Spiral {
+            turns: 0.0,
+            tightness: 0.0,
+        };
+        // No turns and no tightness is the straight line
+        let v = interpolate(&kfs, 1.0, flat).unwrap();
+        let straight = interpolate(&kfs, 1.0, ZoomPath::Linear).unwrap();
+        assert!((v.center_x - straight.center_x).abs() < 1e-12);
+        assert!((v.center_y - straight.center_y).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_frame_times() {
+        let times = frame_times(&keyframes(), 5);
+        assert_eq!(times, vec![0.0, 0.5, 1.0, 1.5, 2.0]);
+        assert!(frame_times(&[], 5).is_empty());
+    }
+}
@@ -1,8 +1,41 @@
 use eframe::egui::Color32;
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
-
-use crate::palette::{get_color, PaletteType};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::fractal::{Fractal, Parameter};
+use crate::palette::{get_custom_banded_color, hsv_to_rgb, CustomPalette, PaletteType};
+
+/// Lower bound for log|z| in smooth coloring; keeps log(log|z|) finite for
+/// points that escape with |z| barely above 1
+const SMOOTH_MIN_LOG_Z: f64 = 1e-6;
+
+/// Valid range for orbit trap distance thresholds
+const MIN_TRAP_THRESHOLD: f64 = 0.001;
+const MAX_TRAP_THRESHOLD: f64 = 4.0;
+
+/// Height of the view in world units at zoom 1
+const VIEW_HEIGHT_AT_UNIT_ZOOM: f64 = 4.0;
+
+/// Valid ranges for the line trap: angle in degrees from the real axis and
+/// signed distance of the line from the origin
+const MAX_TRAP_LINE_ANGLE: f64 = 180.0;
+const MAX_TRAP_LINE_OFFSET: f64 = 2.0;
+
+/// Valid range for the dual palette stripe width, in iterations
+const MIN_BAND_WIDTH: f64 = 1.0;
+const MAX_BAND_WIDTH: f64 = 64.0;
+
+/// Hue step between successive root basins (golden ratio conjugate), so
+/// any number of roots get well-separated hues
+const BASIN_HUE_STEP: f32 = 0.618_034;
+/// Convergence steps over which basin shading fades to its darkest
+const BASIN_SHADE_STEPS: f32 = 32.0;
+/// Brightness of the slowest-converging points at full shading
+const BASIN_MIN_VALUE: f32 = 0.15;
+const DEFAULT_BASIN_SHADING: f64 = 0.8;
 
 /// Available color processor types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -13,9 +46,33 @@ pub enum ColorProcessorType {
     OrbitTrapReal,
     OrbitTrapImag,
     OrbitTrapOrigin,
+    /// Distance to the nearer axis (the stalks of Pickover Stalk)
+    OrbitTrapCross,
+    /// Distance to a line at a configurable angle and offset
+    OrbitTrapLine,
+    ImageTrap,
+    /// Alternate between two palettes every band of iterations
+    DualPalette {
+        first: PaletteType,
+        second: PaletteType,
+    },
+    /// Hue by the root a root-finding fractal (Newton) converges to
+    NewtonBasin,
 }
 
 impl ColorProcessorType {
+    /// Whether colors under this processor shift with the palette offset
+    /// when `palette` is the selected palette
+    pub fn uses_palette_offset(&self, palette: PaletteType) -> bool {
+        match self {
+            ColorProcessorType::NewtonBasin => true,
+            ColorProcessorType::DualPalette { first, second } => {
+                first.uses_offset() || second.uses_offset()
+            }
+            _ => palette.uses_offset(),
+        }
+    }
+
     pub fn display_name(&self) -> &'static str {
         match self {
             ColorProcessorType::Palette => "Standard Palette",
@@ -23,6 +80,11 @@ impl ColorProcessorType {
             ColorProcessorType::OrbitTrapReal => "Orbit Trap (Real Axis)",
             ColorProcessorType::OrbitTrapImag => "Orbit Trap (Imaginary Axis)",
             ColorProcessorType::OrbitTrapOrigin => "Orbit Trap (Origin)",
+            ColorProcessorType::OrbitTrapCross => "Orbit Trap (Cross)",
+            ColorProcessorType::OrbitTrapLine => "Orbit Trap (Line)",
+            ColorProcessorType::ImageTrap => "Image Trap",
+            ColorProcessorType::DualPalette { .. } => "Dual Palette Stripes",
+            ColorProcessorType::NewtonBasin => "Newton Basins",
         }
     }
 
@@ -39,6 +101,21 @@ impl ColorProcessorType {
             ColorProcessorType::OrbitTrapOrigin => {
                 Box::new(OrbitTrapProcessor::new(TrapType::Origin, 0.5))
             }
+            ColorProcessorType::OrbitTrapCross => {
+                Box::new(OrbitTrapProcessor::new(TrapType::Cross, 0.1))
+            }
+            ColorProcessorType::OrbitTrapLine => Box::new(OrbitTrapProcessor::new(
+                TrapType::Line {
+                    angle: 45.0,
+                    offset: 0.0,
+                },
+                0.1,
+            )),
+            ColorProcessorType::ImageTrap => Box::new(ImageTrapProcessor::new(None)),
+            ColorProcessorType::DualPalette { first, second } => {
+                Box::new(DualPaletteProcessor::new(*first, *second, MIN_BAND_WIDTH))
+            }
+            ColorProcessorType::NewtonBasin => Box::new(BasinProcessor::new(DEFAULT_BASIN_SHADING)),
         }
     }
 }
@@ -46,15 +123,24 @@ impl ColorProcessorType {
 /// Context passed to color processors during rendering
 #[derive(Debug, Clone, Copy)]
 #[allow(dead_code)]
-pub struct ColorContext {
+pub struct ColorContext<'a> {
     pub max_iterations: u32,
     pub palette_type: PaletteType,
     pub palette_offset: f32,
+    /// Hue cycles across the iteration range (Psychedelic only)
+    pub palette_frequency: f32,
     pub screen_width: u32,
     pub screen_height: u32,
+    /// World units spanned by one screen pixel
+    pub world_per_pixel: f64,
+    /// Absolute iteration window `[lo, hi]` mapped onto the palette instead
+    /// of `[0, max_iterations]`, so colors don't shift as the limit changes
+    pub iteration_range: Option<(u32, u32)>,
+    /// Stops for `PaletteType::Custom`, borrowed from the pipeline
+    pub custom_palette: Option<&'a CustomPalette>,
 }
 
-impl ColorContext {
+impl<'a> ColorContext<'a> {
     pub fn new(
         max_iterations: u32,
         palette_type: PaletteType,
@@ -66,10 +152,67 @@ impl ColorContext {
             max_iterations,
             palette_type,
             palette_offset,
+            palette_frequency: 1.0,
             screen_width,
             screen_height,
+            world_per_pixel: VIEW_HEIGHT_AT_UNIT_ZOOM / screen_height.max(1) as f64,
+            iteration_range: None,
+            custom_palette: None,
+        }
+    }
+
+    pub fn with_palette_frequency(mut self, frequency: f32) -> Self {
+        self.palette_frequency = frequency;
+        self
+    }
+
+    pub fn with_world_per_pixel(mut self, world_per_pixel: f64) -> Self {
+        self.world_per_pixel = world_per_pixel;
+        self
+    }
+
+    pub fn with_iteration_range(mut self, range: Option<(u32, u32)>) -> Self {
+        self.iteration_range = range;
+        self
+    }
+
+    pub fn with_custom_palette(mut self, palette: Option<&'a CustomPalette>) -> Self {
+        self.custom_palette = palette;
+        self
+    }
+
+    /// Palette position of a (possibly fractional) iteration count: its
+    /// fraction of `max_iterations`, or its place in the pinned iteration
+    /// range, clamped to [0, 1]
+    pub fn iteration_t(&self, iterations: f32) -> f32 {
+        match self.iteration_range {
+            Some((lo, hi)) => {
+                ((iterations - lo as f32) / hi.saturating_sub(lo).max(1) as f32).clamp(0.0, 1.0)
+            }
+            None => iterations / self.max_iterations as f32,
+        }
+    }
+
+    /// Height of the view in world units relative to zoom 1, i.e. 1/zoom
+    pub fn view_scale(&self) -> f64 {
+        let scale = self.world_per_pixel * self.screen_height as f64 / VIEW_HEIGHT_AT_UNIT_ZOOM;
+        if scale.is_finite() && scale > 0.0 {
+            scale
+        } else {
+            1.0
         }
     }
+
+    /// Palette color at position t with this context's offset and banding
+    pub fn palette_color(&self, t: f32) -> Color32 {
+        get_custom_banded_color(
+            self.palette_type,
+            self.custom_palette,
+            t,
+            self.palette_offset,
+            self.palette_frequency,
+        )
+    }
 }
 
 /// Result of fractal computation including iteration count and orbit data
@@ -79,6 +222,24 @@ pub struct FractalResult {
     pub escaped: bool,
     pub final_z: Option<Complex64>,
     pub orbit_data: OrbitData,
+    /// Index of the root reached, for convergence (root-finding) fractals
+    pub root: Option<usize>,
+    /// Orbit continued past the bailout, for fractals that smooth poorly
+    /// from the bailout point alone
+    pub smooth_escape: Option<SmoothEscape>,
+}
+
+/// Escaping orbit followed out to a large radius. With the small bailout
+/// the `+ c` term still matters at escape, so the continuous dwell jumps
+/// between iteration bands; a few steps later |z| grows as a pure power.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmoothEscape {
+    /// z once it has left the large radius
+    pub z: Complex64,
+    /// Steps taken past the bailout to get there
+    pub extra_iterations: u32,
+    /// Degree of the iteration: far out, |z| grows as |z|^power per step
+    pub power: f64,
 }
 
 impl FractalResult {
@@ -88,6 +249,8 @@ impl FractalResult {
             escaped: false,
             final_z: None,
             orbit_data: OrbitData::default(),
+            root: None,
+            smooth_escape: None,
         }
     }
 
@@ -97,8 +260,22 @@ impl FractalResult {
             escaped: true,
             final_z: Some(final_z),
             orbit_data,
+            root: None,
+            smooth_escape: None,
         }
     }
+
+    /// Mark a converged result with the root it reached
+    pub fn with_root(mut self, root: usize) -> Self {
+        self.root = Some(root);
+        self
+    }
+
+    /// Attach the continued orbit used for smooth coloring
+    pub fn with_smooth_escape(mut self, smooth_escape: SmoothEscape) -> Self {
+        self.smooth_escape = Some(smooth_escape);
+        self
+    }
 }
 
 /// Data collected during orbit computation
@@ -111,6 +288,16 @@ pub struct OrbitData {
     pub min_distance_to_origin: f64,
     pub min_distance_to_real_axis: f64,
     pub min_distance_to_imag_axis: f64,
+    /// Distance to `trap_line` over the orbit; infinite without a line
+    pub min_distance_to_line: f64,
+    /// Line of an active line trap, measured in `update`
+    pub trap_line: Option<TrapLine>,
+    /// Orbit point that came closest to the origin
+    pub trap_point: Complex64,
+    /// Stalk distance a fractal measured itself (Pickover Stalk): the
+    /// closest approach to either axis over exactly the points it tested.
+    /// Takes precedence over the axis distances for the cross trap.
+    pub stalk_distance: Option<f64>,
 }
 
 impl OrbitData {
@@ -123,6 +310,18 @@ impl OrbitData {
             min_distance_to_origin: f64::INFINITY,
             min_distance_to_real_axis: f64::INFINITY,
             min_distance_to_imag_axis: f64::INFINITY,
+            min_distance_to_line: f64::INFINITY,
+            trap_line: None,
+            trap_point: Complex64::new(0.0, 0.0),
+            stalk_distance: None,
+        }
+    }
+
+    /// Orbit data that also measures the distance to `line`
+    pub fn with_line(line: Option<TrapLine>) -> Self {
+        Self {
+            trap_line: line,
+            ..Self::new()
         }
     }
 
@@ -131,9 +330,47 @@ impl OrbitData {
         self.max_real = self.max_real.max(z.re);
         self.min_imag = self.min_imag.min(z.im);
         self.max_imag = self.max_imag.max(z.im);
-        self.min_distance_to_origin = self.min_distance_to_origin.min(z.norm());
+        let distance = z.norm();
+        if distance < self.min_distance_to_origin {
+            self.min_distance_to_origin = distance;
+            self.trap_point = z;
+        }
         self.min_distance_to_real_axis = self.min_distance_to_real_axis.min(z.im.abs());
         self.min_distance_to_imag_axis = self.min_distance_to_imag_axis.min(z.re.abs());
+        if let Some(line) = &self.trap_line {
+            self.min_distance_to_line = self.min_distance_to_line.min(line.distance(z));
+        }
+    }
+}
+
+/// Line for the line orbit trap, stored as its unit normal and signed
+/// distance from the origin
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrapLine {
+    normal: Complex64,
+    offset: f64,
+}
+
+impl Default for TrapLine {
+    /// The real axis
+    fn default() -> Self {
+        Self::new(0.0, 0.0)
+    }
+}
+
+impl TrapLine {
+    /// Line at `angle_degrees` from the real axis, shifted `offset` along
+    /// its normal
+    pub fn new(angle_degrees: f64, offset: f64) -> Self {
+        let angle = angle_degrees.to_radians();
+        Self {
+            normal: Complex64::new(-angle.sin(), angle.cos()),
+            offset,
+        }
+    }
+
+    pub fn distance(&self, z: Complex64) -> f64 {
+        (z.re * self.normal.re + z.im * self.normal.im - self.offset).abs()
     }
 }
 
@@ -145,6 +382,30 @@ pub trait ColorProcessor: Send + Sync {
     /// Get the name of this processor
     fn name(&self) -> &str;
 
+    /// Tunable parameters of this processor with their valid ranges
+    fn parameters(&self) -> Vec<Parameter> {
+        Vec::new()
+    }
+
+    /// Set a parameter by name; unknown names are ignored
+    fn set_parameter(&mut self, _name: &str, _value: f64) {}
+
+    /// Whether colors depend on `FractalResult::orbit_data`. Results from
+    /// the GPU backend carry no orbit data.
+    fn uses_orbit_data(&self) -> bool {
+        false
+    }
+
+    /// Give the processor an image to sample; only the image trap uses one
+    fn set_trap_image(&mut self, _image: Option<Arc<TrapImage>>) {}
+
+    /// Line whose distance the orbit data must carry, for line traps.
+    /// Fractals don't measure it themselves; `ColorPipeline::compute` does,
+    /// and only when this is set.
+    fn trap_line(&self) -> Option<TrapLine> {
+        None
+    }
+
     /// Clone this processor into a Box
     fn clone_box(&self) -> Box<dyn ColorProcessor>;
 }
@@ -164,8 +425,8 @@ impl ColorProcessor for PaletteProcessor {
         if !result.escaped {
             Color32::BLACK
         } else {
-            let t = result.iterations as f32 / context.max_iterations as f32;
-            get_color(context.palette_type, t, context.palette_offset)
+            let t = context.iteration_t(result.iterations as f32);
+            context.palette_color(t)
         }
     }
 
@@ -178,11 +439,51 @@ impl ColorProcessor for PaletteProcessor {
     }
 }
 
+/// Continuous (fractional) iteration count of an escaped point, using
+/// n - log(log(|z|)) / log(2), or with a continued orbit,
+/// n + k - log(log(|z_{n+k}|)) / log(power). Points without a final z keep
+/// their integer count.
+pub fn continuous_iterations(result: &FractalResult) -> f32 {
+    if !result.escaped || result.final_z.is_none() {
+        return result.iterations as f32;
+    }
+
+    if let Some(escape) = result.smooth_escape {
+        let log_z = 0.5 * escape.z.norm_sqr().ln();
+        let nu =
+            (result.iterations + escape.extra_iterations) as f64 - log_z.ln() / escape.power.ln();
+        if nu.is_finite() {
+            return nu.max(0.0) as f32;
+        }
+    }
+
+    let Some(z) = result.final_z else {
+        return result.iterations as f32;
+    };
+
+    // Use norm_sqr() to avoid sqrt, then adjust: log(sqrt(x)) = 0.5 * log(x)
+    let z_norm_sq = z.norm_sqr();
+    if !z_norm_sq.is_finite() {
+        return result.iterations as f32;
+    }
+    let log_z = (0.5 * z_norm_sq.ln()).max(SMOOTH_MIN_LOG_Z);
+    let log_log_z = log_z.ln();
+
+    // Standard smooth iteration formula: nu = n - log(log|z|) / log(2)
+    let nu = result.iterations as f64 - log_log_z / std::f64::consts::LN_2;
+    if !nu.is_finite() {
+        return result.iterations as f32;
+    }
+    nu.max(0.0) as f32
+}
+
 /// Smooth coloring using continuous iteration count
 #[derive(Clone, Copy)]
 #[allow(dead_code)]
 pub struct SmoothColoring {
     pub smoothing_enabled: bool,
+    /// Mix between discrete (0) and fully smooth (1) palette positions
+    pub blend: f32,
 }
 
 #[allow(dead_code)]
@@ -190,32 +491,32 @@ impl SmoothColoring {
     pub fn new(enabled: bool) -> Self {
         Self {
             smoothing_enabled: enabled,
+            blend: 1.0,
         }
     }
 
     /// Calculate smooth iteration count
-    /// Uses the formula: n - log(log(|z|)) / log(2)
     fn smooth_iterations(&self, result: &FractalResult, _context: &ColorContext) -> f32 {
-        if !result.escaped || result.final_z.is_none() {
-            return result.iterations as f32;
-        }
-
-        let Some(z) = result.final_z else {
-            return result.iterations as f32;
-        };
-
-        // Use norm_sqr() to avoid sqrt, then adjust: log(sqrt(x)) = 0.5 * log(x)
-        let z_norm_sq = z.norm_sqr();
-        let log_z = 0.5 * z_norm_sq.ln();
-        let log_log_z = log_z.ln();
+        continuous_iterations(result)
+    }
 
-        if !log_log_z.is_finite() {
-            return result.iterations as f32;
+    /// Palette position in [0, 1) for an escaped point
+    fn palette_position(&self, result: &FractalResult, context: &ColorContext) -> f32 {
+        let discrete_t = context.iteration_t(result.iterations as f32);
+        if !self.smoothing_enabled {
+            return discrete_t;
+        }
+        let smooth_iter = self.smooth_iterations(result, context);
+        let smooth_t = context.iteration_t(smooth_iter);
+        // Blend before wrapping, so a smooth position just past the top
+        // doesn't pull the mix back toward the bottom of the palette. A
+        // pinned range clamps instead of wrapping.
+        let t = discrete_t + (smooth_t - discrete_t) * self.blend;
+        if context.iteration_range.is_none() {
+            t.fract()
+        } else {
+            t
         }
-
-        // Standard smooth iteration formula: nu = n - log(log|z|) / log(2)
-        let nu = result.iterations as f64 - log_log_z / std::f64::consts::LN_2;
-        nu as f32
     }
 }
 
@@ -225,14 +526,8 @@ impl ColorProcessor for SmoothColoring {
             return Color32::BLACK;
         }
 
-        let t = if self.smoothing_enabled {
-            let smooth_iter = self.smooth_iterations(result, context);
-            (smooth_iter / context.max_iterations as f32).fract()
-        } else {
-            result.iterations as f32 / context.max_iterations as f32
-        };
-
-        get_color(context.palette_type, t, context.palette_offset)
+        let t = self.palette_position(result, context);
+        context.palette_color(t)
     }
 
     fn name(&self) -> &str {
@@ -243,6 +538,24 @@ impl ColorProcessor for SmoothColoring {
         }
     }
 
+    fn parameters(&self) -> Vec<Parameter> {
+        if !self.smoothing_enabled {
+            return Vec::new();
+        }
+        vec![Parameter {
+            name: "blend".to_string(),
+            value: self.blend as f64,
+            min: 0.0,
+            max: 1.0,
+        }]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) {
+        if name == "blend" && value.is_finite() {
+            self.blend = value.clamp(0.0, 1.0) as f32;
+        }
+    }
+
     fn clone_box(&self) -> Box<dyn ColorProcessor> {
         Box::new(*self)
     }
@@ -254,15 +567,23 @@ impl ColorProcessor for SmoothColoring {
 pub struct OrbitTrapProcessor {
     pub trap_type: TrapType,
     pub threshold: f64,
+    /// How far the threshold follows the zoom: 0 keeps it fixed in world
+    /// units, 1 keeps it fixed relative to the view
+    pub zoom_scaling: f64,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[allow(dead_code)]
 pub enum TrapType {
     RealAxis, // Distance to real axis
     ImagAxis, // Distance to imaginary axis
     Origin,   // Distance to origin
     Cross,    // Both axes
+    /// Line at `angle` degrees from the real axis, `offset` from the origin
+    Line {
+        angle: f64,
+        offset: f64,
+    },
 }
 
 #[allow(dead_code)]
@@ -271,17 +592,25 @@ impl OrbitTrapProcessor {
         Self {
             trap_type,
             threshold,
+            zoom_scaling: 0.0,
         }
     }
 
+    /// Threshold for the current view: `threshold * (1/zoom)^zoom_scaling`
+    fn effective_threshold(&self, context: &ColorContext) -> f64 {
+        self.threshold * context.view_scale().powf(self.zoom_scaling)
+    }
+
     fn get_trap_value(&self, data: &OrbitData) -> f64 {
         match self.trap_type {
             TrapType::RealAxis => data.min_distance_to_real_axis,
             TrapType::ImagAxis => data.min_distance_to_imag_axis,
             TrapType::Origin => data.min_distance_to_origin,
-            TrapType::Cross => data
-                .min_distance_to_real_axis
-                .min(data.min_distance_to_imag_axis),
+            TrapType::Cross => data.stalk_distance.unwrap_or(
+                data.min_distance_to_real_axis
+                    .min(data.min_distance_to_imag_axis),
+            ),
+            TrapType::Line { .. } => data.min_distance_to_line,
         }
     }
 }
@@ -293,20 +622,21 @@ impl ColorProcessor for OrbitTrapProcessor {
         }
 
         let trap_value = self.get_trap_value(&result.orbit_data);
+        let threshold = self.effective_threshold(context);
 
         // Normalize trap value to 0-1 range
         // Smaller distances = closer to trap = brighter
-        let t = if self.threshold > 0.0 {
-            (1.0 - (trap_value / self.threshold).min(1.0)) as f32
+        let t = if threshold > 0.0 {
+            (1.0 - (trap_value / threshold).min(1.0)) as f32
         } else {
             0.0
         };
 
         // Mix with palette based on iterations
-        let iter_t = result.iterations as f32 / context.max_iterations as f32;
+        let iter_t = context.iteration_t(result.iterations as f32);
         let mixed_t = t * 0.7 + iter_t * 0.3;
 
-        get_color(context.palette_type, mixed_t, context.palette_offset)
+        context.palette_color(mixed_t)
     }
 
     fn name(&self) -> &str {
@@ -315,6 +645,131 @@ impl ColorProcessor for OrbitTrapProcessor {
             TrapType::ImagAxis => "Imaginary Axis Trap",
             TrapType::Origin => "Origin Trap",
             TrapType::Cross => "Cross Trap",
+            TrapType::Line { .. } => "Line Trap",
+        }
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        let mut params = vec![
+            Parameter {
+                name: "threshold".to_string(),
+                value: self.threshold,
+                min: MIN_TRAP_THRESHOLD,
+                max: MAX_TRAP_THRESHOLD,
+            },
+            Parameter {
+                name: "zoom_scaling".to_string(),
+                value: self.zoom_scaling,
+                min: 0.0,
+                max: 1.0,
+            },
+        ];
+        if let TrapType::Line { angle, offset } = self.trap_type {
+            params.push(Parameter {
+                name: "angle".to_string(),
+                value: angle,
+                min: 0.0,
+                max: MAX_TRAP_LINE_ANGLE,
+            });
+            params.push(Parameter {
+                name: "offset".to_string(),
+                value: offset,
+                min: -MAX_TRAP_LINE_OFFSET,
+                max: MAX_TRAP_LINE_OFFSET,
+            });
+        }
+        params
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        match (name, &mut self.trap_type) {
+            ("threshold", _) => {
+                self.threshold = value.clamp(MIN_TRAP_THRESHOLD, MAX_TRAP_THRESHOLD)
+            }
+            ("zoom_scaling", _) => self.zoom_scaling = value.clamp(0.0, 1.0),
+            ("angle", TrapType::Line { angle, .. }) => {
+                *angle = value.clamp(0.0, MAX_TRAP_LINE_ANGLE)
+            }
+            ("offset", TrapType::Line { offset, .. }) => {
+                *offset = value.clamp(-MAX_TRAP_LINE_OFFSET, MAX_TRAP_LINE_OFFSET)
+            }
+            _ => {}
+        }
+    }
+
+    fn uses_orbit_data(&self) -> bool {
+        true
+    }
+
+    fn trap_line(&self) -> Option<TrapLine> {
+        match self.trap_type {
+            TrapType::Line { angle, offset } => Some(TrapLine::new(angle, offset)),
+            _ => None,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn ColorProcessor> {
+        Box::new(*self)
+    }
+}
+
+/// Root basin coloring: each root reached gets its own hue (rotated by the
+/// palette offset), darkened the longer the point took to converge. Results
+/// without a root fall back to the palette.
+#[derive(Clone, Copy)]
+pub struct BasinProcessor {
+    /// How strongly slow convergence darkens the basin color (0-1)
+    pub shading: f64,
+}
+
+impl BasinProcessor {
+    pub fn new(shading: f64) -> Self {
+        Self {
+            shading: shading.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Base hue in [0, 1) for a root index
+    fn hue(root: usize, offset: f32) -> f32 {
+        (root as f32 * BASIN_HUE_STEP + offset).rem_euclid(1.0)
+    }
+}
+
+impl ColorProcessor for BasinProcessor {
+    fn process(&self, result: &FractalResult, context: &ColorContext) -> Color32 {
+        if !result.escaped {
+            return Color32::BLACK;
+        }
+        let Some(root) = result.root else {
+            return context.palette_color(context.iteration_t(result.iterations as f32));
+        };
+        // Convergence fractals report max_iterations minus the steps taken
+        let steps = context.max_iterations.saturating_sub(result.iterations) as f32;
+        let speed = (1.0 - steps / BASIN_SHADE_STEPS).max(BASIN_MIN_VALUE);
+        let value = 1.0 - self.shading as f32 * (1.0 - speed);
+        let (r, g, b) = hsv_to_rgb(Self::hue(root, context.palette_offset), 0.85, value);
+        Color32::from_rgb((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8)
+    }
+
+    fn name(&self) -> &str {
+        "Newton Basins"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter {
+            name: "shading".to_string(),
+            value: self.shading,
+            min: 0.0,
+            max: 1.0,
+        }]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) {
+        if name == "shading" && value.is_finite() {
+            self.shading = value.clamp(0.0, 1.0);
         }
     }
 
@@ -323,6 +778,178 @@ impl ColorProcessor for OrbitTrapProcessor {
     }
 }
 
+/// Striped coloring: bands of `band_width` iterations alternate between
+/// two palettes, outlining the iteration contours
+#[derive(Clone, Copy)]
+pub struct DualPaletteProcessor {
+    pub first: PaletteType,
+    pub second: PaletteType,
+    pub band_width: f64,
+}
+
+impl DualPaletteProcessor {
+    pub fn new(first: PaletteType, second: PaletteType, band_width: f64) -> Self {
+        Self {
+            first,
+            second,
+            band_width: band_width.clamp(MIN_BAND_WIDTH, MAX_BAND_WIDTH),
+        }
+    }
+
+    /// Palette for an iteration count: even bands use the first palette,
+    /// odd bands the second
+    fn palette_for(&self, iterations: u32) -> PaletteType {
+        let band = iterations / self.band_width.round().max(MIN_BAND_WIDTH) as u32;
+        if band.is_multiple_of(2) {
+            self.first
+        } else {
+            self.second
+        }
+    }
+}
+
+impl ColorProcessor for DualPaletteProcessor {
+    fn process(&self, result: &FractalResult, context: &ColorContext) -> Color32 {
+        if !result.escaped {
+            return Color32::BLACK;
+        }
+        let t = context.iteration_t(result.iterations as f32);
+        get_custom_banded_color(
+            self.palette_for(result.iterations),
+            context.custom_palette,
+            t,
+            context.palette_offset,
+            context.palette_frequency,
+        )
+    }
+
+    fn name(&self) -> &str {
+        "Dual Palette Stripes"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter {
+            name: "band_width".to_string(),
+            value: self.band_width,
+            min: MIN_BAND_WIDTH,
+            max: MAX_BAND_WIDTH,
+        }]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) {
+        if name == "band_width" && value.is_finite() {
+            self.band_width = value.round().clamp(MIN_BAND_WIDTH, MAX_BAND_WIDTH);
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn ColorProcessor> {
+        Box::new(*self)
+    }
+}
+
+/// Image sampled by the image trap processor
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrapImage {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color32>,
+}
+
+impl TrapImage {
+    pub fn new(width: usize, height: usize, pixels: Vec<Color32>) -> Option<Self> {
+        if width == 0 || height == 0 || pixels.len() != width * height {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let img = image::open(path)
+            .map_err(|e| format!("Failed to load image: {}", e))?
+            .to_rgba8();
+        let (width, height) = (img.width() as usize, img.height() as usize);
+        let pixels = img
+            .pixels()
+            .map(|p| Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
+            .collect();
+        Self::new(width, height, pixels).ok_or_else(|| "Image is empty".to_string())
+    }
+
+    /// Nearest-pixel sample at normalized coordinates (0..1, top-left origin)
+    pub fn sample(&self, u: f64, v: f64) -> Color32 {
+        let x = ((u.clamp(0.0, 1.0) * self.width as f64) as usize).min(self.width - 1);
+        let y = ((v.clamp(0.0, 1.0) * self.height as f64) as usize).min(self.height - 1);
+        self.pixels[y * self.width + x]
+    }
+}
+
+/// Image trap coloring: maps the orbit's closest approach onto an image,
+/// normalized by the orbit's bounding box. Falls back to the palette when
+/// no image is loaded.
+#[derive(Clone)]
+pub struct ImageTrapProcessor {
+    image: Option<Arc<TrapImage>>,
+}
+
+impl ImageTrapProcessor {
+    pub fn new(image: Option<Arc<TrapImage>>) -> Self {
+        Self { image }
+    }
+
+    /// Normalized image coordinates of the trap point within the orbit bounds
+    fn trap_uv(data: &OrbitData) -> (f64, f64) {
+        let span_re = data.max_real - data.min_real;
+        let span_im = data.max_imag - data.min_imag;
+        let u = if span_re > 0.0 {
+            (data.trap_point.re - data.min_real) / span_re
+        } else {
+            0.5
+        };
+        // Image rows run top to bottom, imaginary axis bottom to top
+        let v = if span_im > 0.0 {
+            (data.max_imag - data.trap_point.im) / span_im
+        } else {
+            0.5
+        };
+        (u, v)
+    }
+}
+
+impl ColorProcessor for ImageTrapProcessor {
+    fn process(&self, result: &FractalResult, context: &ColorContext) -> Color32 {
+        if !result.escaped {
+            return Color32::BLACK;
+        }
+        match &self.image {
+            Some(image) => {
+                let (u, v) = Self::trap_uv(&result.orbit_data);
+                image.sample(u, v)
+            }
+            None => PaletteProcessor.process(result, context),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "Image Trap"
+    }
+
+    fn set_trap_image(&mut self, image: Option<Arc<TrapImage>>) {
+        self.image = image;
+    }
+
+    fn uses_orbit_data(&self) -> bool {
+        true
+    }
+
+    fn clone_box(&self) -> Box<dyn ColorProcessor> {
+        Box::new(self.clone())
+    }
+}
+
 /// Chain multiple processors together
 #[allow(dead_code)]
 pub struct ChainProcessor {
@@ -357,6 +984,14 @@ impl ColorProcessor for ChainProcessor {
         "Chain"
     }
 
+    fn uses_orbit_data(&self) -> bool {
+        self.processors.iter().any(|p| p.uses_orbit_data())
+    }
+
+    fn trap_line(&self) -> Option<TrapLine> {
+        self.processors.iter().find_map(|p| p.trap_line())
+    }
+
     fn clone_box(&self) -> Box<dyn ColorProcessor> {
         // Clone all processors in the chain
         let cloned: Vec<_> = self.processors.iter().map(|p| p.clone_box()).collect();
@@ -367,36 +1002,112 @@ impl ColorProcessor for ChainProcessor {
 /// Color pipeline that manages the active processor
 pub struct ColorPipeline {
     processor: Box<dyn ColorProcessor>,
+    /// Pinned iteration window passed to processors via `ColorContext`
+    iteration_range: Option<(u32, u32)>,
+    /// Stops for `PaletteType::Custom`, captured with the pipeline so a
+    /// palette edited mid-render doesn't reach renders already running
+    custom_palette: Option<Arc<CustomPalette>>,
 }
 
 impl Clone for ColorPipeline {
     fn clone(&self) -> Self {
         Self {
             processor: self.processor.clone_box(),
+            iteration_range: self.iteration_range,
+            custom_palette: self.custom_palette.clone(),
         }
     }
 }
 
 impl Default for ColorPipeline {
     fn default() -> Self {
-        Self {
-            processor: Box::new(PaletteProcessor),
-        }
+        Self::new(Box::new(PaletteProcessor))
     }
 }
 
 impl ColorPipeline {
     pub fn from_type(processor_type: ColorProcessorType) -> Self {
-        Self {
-            processor: processor_type.create_processor(),
+        Self::new(processor_type.create_processor())
+    }
+
+    /// Create a pipeline and apply saved processor parameters on top of the
+    /// processor's defaults
+    pub fn from_type_with_params(
+        processor_type: ColorProcessorType,
+        params: &HashMap<String, f64>,
+    ) -> Self {
+        let mut pipeline = Self::from_type(processor_type);
+        for (name, value) in params {
+            pipeline.processor.set_parameter(name, *value);
         }
+        pipeline
+    }
+
+    /// Current parameters of the active processor
+    pub fn parameters(&self) -> Vec<Parameter> {
+        self.processor.parameters()
+    }
+
+    /// Map colors over a fixed iteration window instead of the iteration
+    /// limit (None restores the default)
+    pub fn with_iteration_range(mut self, range: Option<(u32, u32)>) -> Self {
+        self.iteration_range = range;
+        self
+    }
+
+    /// Sample `image` with an image trap processor (other processors ignore
+    /// it). The pipeline holds its own reference, so replacing the image
+    /// later does not affect renders already using this pipeline.
+    pub fn with_trap_image(mut self, image: Option<Arc<TrapImage>>) -> Self {
+        self.processor.set_trap_image(image);
+        self
+    }
+
+    pub fn iteration_range(&self) -> Option<(u32, u32)> {
+        self.iteration_range
+    }
+
+    /// Color `PaletteType::Custom` with `palette` (Classic when None)
+    pub fn with_custom_palette(mut self, palette: Option<Arc<CustomPalette>>) -> Self {
+        self.custom_palette = palette;
+        self
+    }
+
+    pub fn custom_palette(&self) -> Option<&CustomPalette> {
+        self.custom_palette.as_deref()
+    }
+
+    /// Whether the active processor needs orbit data
+    pub fn uses_orbit_data(&self) -> bool {
+        self.processor.uses_orbit_data()
+    }
+
+    /// Compute a point with the orbit data the active processor reads. A
+    /// line trap's distance is measured in the same pass, over the points
+    /// `OrbitData::update` sees.
+    pub fn compute(&self, fractal: &dyn Fractal, x: f64, y: f64, max_iter: u32) -> FractalResult {
+        let orbit_data = OrbitData::with_line(self.processor.trap_line());
+        fractal.compute_full_with(x, y, max_iter, orbit_data)
+    }
+
+    /// Current parameter values of the active processor, by name
+    pub fn parameter_values(&self) -> HashMap<String, f64> {
+        self.processor
+            .parameters()
+            .into_iter()
+            .map(|p| (p.name, p.value))
+            .collect()
     }
 }
 
 #[allow(dead_code)]
 impl ColorPipeline {
     pub fn new(processor: Box<dyn ColorProcessor>) -> Self {
-        Self { processor }
+        Self {
+            processor,
+            iteration_range: None,
+            custom_palette: None,
+        }
     }
 
     pub fn set_processor(&mut self, processor: Box<dyn ColorProcessor>) {
@@ -412,6 +1123,74 @@ impl ColorPipeline {
     }
 }
 
+/// Control points per tone curve, evenly spaced over the input range
+pub const CURVE_POINTS: usize = 5;
+
+/// Piecewise-linear tone curve for one color channel. `values[i]` is the
+/// output (0..=1) for input `i / (CURVE_POINTS - 1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ColorCurve {
+    pub values: [f32; CURVE_POINTS],
+}
+
+impl Default for ColorCurve {
+    /// The identity curve
+    fn default() -> Self {
+        Self {
+            values: std::array::from_fn(|i| i as f32 / (CURVE_POINTS - 1) as f32),
+        }
+    }
+}
+
+impl ColorCurve {
+    pub fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Curve output for an input in 0..=1
+    pub fn eval(&self, x: f32) -> f32 {
+        let segments = (CURVE_POINTS - 1) as f32;
+        let position = x.clamp(0.0, 1.0) * segments;
+        let i = (position.floor() as usize).min(CURVE_POINTS - 2);
+        let frac = position - i as f32;
+        self.values[i] + (self.values[i + 1] - self.values[i]) * frac
+    }
+
+    /// Curve output for an 8-bit channel
+    pub fn map(&self, channel: u8) -> u8 {
+        (self.eval(channel as f32 / 255.0) * 255.0)
+            .round()
+            .clamp(0.0, 255.0) as u8
+    }
+}
+
+/// Independent tone curves for the red, green and blue channels
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ChannelCurves {
+    pub red: ColorCurve,
+    pub green: ColorCurve,
+    pub blue: ColorCurve,
+}
+
+impl ChannelCurves {
+    pub fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Map each RGB channel through its curve, keeping alpha
+    pub fn apply(&self, color: Color32) -> Color32 {
+        if self.is_identity() {
+            return color;
+        }
+        Color32::from_rgba_unmultiplied(
+            self.red.map(color.r()),
+            self.green.map(color.g()),
+            self.blue.map(color.b()),
+            color.a(),
+        )
+    }
+}
+
 /// Helper function to compute orbit data during fractal iteration
 #[allow(dead_code)]
 pub fn compute_with_orbit<F>(mut f: F, cx: f64, cy: f64, max_iter: u32) -> FractalResult
@@ -437,6 +1216,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::palette::get_banded_color;
 
     #[test]
     fn test_palette_processor_inside() {
@@ -469,6 +1249,181 @@ mod tests {
         assert_ne!(color, Color32::BLACK);
     }
 
+    #[test]
+    fn test_pinned_iteration_range_ignores_max_iterations() {
+        let result = FractalResult::escaped(50, Complex64::new(2.5, 0.0), OrbitData::new());
+        let processors: [Box<dyn ColorProcessor>; 2] = [
+            Box::new(PaletteProcessor),
+            Box::new(SmoothColoring::new(true)),
+        ];
+        for processor in &processors {
+            let color_at = |max_iterations: u32, range: Option<(u32, u32)>| {
+                let context = ColorContext::new(max_iterations, PaletteType::Fire, 0.0, 100, 100)
+                    .with_iteration_range(range);
+                processor.process(&result, &context)
+            };
+            assert_ne!(color_at(100, None), color_at(1000, None));
+            assert_eq!(
+                color_at(100, Some((0, 200))),
+                color_at(1000, Some((0, 200)))
+            );
+        }
+
+        // Counts outside the window clamp to its ends
+        let context = ColorContext::new(100, PaletteType::Fire, 0.0, 100, 100)
+            .with_iteration_range(Some((20, 60)));
+        assert_eq!(context.iteration_t(10.0), 0.0);
+        assert_eq!(context.iteration_t(40.0), 0.5);
+        assert_eq!(context.iteration_t(90.0), 1.0);
+    }
+
+    #[test]
+    fn test_newton_basin_coloring() {
+        use crate::fractal::{Fractal, Newton};
+
+        let newton = Newton::default();
+        let processor = BasinProcessor::new(DEFAULT_BASIN_SHADING);
+        let context = ColorContext::new(100, PaletteType::Classic, 0.0, 100, 100);
+        let roots = [(1.0, 0.0), (-0.5, 0.866), (-0.5, -0.866)];
+        let colors: Vec<Color32> = roots
+            .iter()
+            .enumerate()
+            .map(|(basin, &(re, im))| {
+                let result = newton.compute_full(re * 1.1, im * 1.1, 100);
+                assert_eq!(result.root, Some(basin));
+                processor.process(&result, &context)
+            })
+            .collect();
+        assert_ne!(colors[0], colors[1]);
+        assert_ne!(colors[1], colors[2]);
+        assert_ne!(colors[0], colors[2]);
+
+        // Same basin, slower convergence: darker
+        let brightness = |c: Color32| c.r() as u32 + c.g() as u32 + c.b() as u32;
+        let slow =
+            FractalResult::escaped(80, Complex64::new(1.0, 0.0), OrbitData::new()).with_root(0);
+        let fast =
+            FractalResult::escaped(98, Complex64::new(1.0, 0.0), OrbitData::new()).with_root(0);
+        assert!(
+            brightness(processor.process(&slow, &context))
+                < brightness(processor.process(&fast, &context))
+        );
+
+        // No root (not a convergence fractal): the palette is used
+        let escaped = FractalResult::escaped(50, Complex64::new(2.5, 0.0), OrbitData::new());
+        assert_eq!(
+            processor.process(&escaped, &context),
+            PaletteProcessor.process(&escaped, &context)
+        );
+    }
+
+    #[test]
+    fn test_smooth_coloring_blend() {
+        let context = ColorContext::new(100, PaletteType::Classic, 0.0, 100, 100);
+        let result = FractalResult::escaped(50, Complex64::new(2.5, 0.0), OrbitData::new());
+        let discrete = SmoothColoring::new(false).palette_position(&result, &context);
+        let mut processor = SmoothColoring::new(true);
+        let smooth = processor.palette_position(&result, &context);
+        assert_ne!(discrete, smooth);
+
+        processor.set_parameter("blend", 0.0);
+        assert_eq!(processor.palette_position(&result, &context), discrete);
+        processor.set_parameter("blend", 0.5);
+        let half = processor.palette_position(&result, &context);
+        assert!((half - (discrete + smooth) / 2.0).abs() < 1e-6);
+        processor.set_parameter("blend", 7.0);
+        assert_eq!(processor.blend, 1.0);
+        assert_eq!(processor.palette_position(&result, &context), smooth);
+
+        // Near the limit the smooth count passes the top of the palette;
+        // a half blend stays near the top rather than jumping to the middle
+        let late = FractalResult::escaped(99, Complex64::new(1.5, 0.0), OrbitData::new());
+        let discrete = SmoothColoring::new(false).palette_position(&late, &context);
+        assert!(processor.palette_position(&late, &context) < 0.05);
+        processor.set_parameter("blend", 0.5);
+        let half = processor.palette_position(&late, &context);
+        assert!(half > discrete && half < 1.0, "half blend at {}", half);
+    }
+
+    #[test]
+    fn test_smooth_coloring_slow_escape_is_finite() {
+        let processor = SmoothColoring::new(true);
+        let context = ColorContext::new(100, PaletteType::Classic, 0.0, 100, 100);
+
+        for z in [
+            Complex64::new(1.0 + 1e-15, 0.0),
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.5, 0.0),
+            Complex64::new(1e300, 1e300),
+            Complex64::new(f64::NAN, 0.0),
+        ] {
+            for iterations in [0, 1, 50] {
+                let result = FractalResult::escaped(iterations, z, OrbitData::new());
+                let t = processor.palette_position(&result, &context);
+                assert!(t.is_finite(), "t not finite for z={}", z);
+                assert!((0.0..=1.0).contains(&t), "t={} out of range for z={}", t, z);
+            }
+        }
+    }
+
+    #[test]
+    fn test_smooth_coloring_continuous_for_abs_fractals() {
+        use crate::fractal::{BurningShip, Celtic, Fractal};
+
+        let processor = SmoothColoring::new(true);
+        let context = ColorContext::new(200, PaletteType::Classic, 0.0, 100, 100);
+        let fractals: [Box<dyn Fractal>; 3] = [
+            Box::new(BurningShip { power: 2.0 }),
+            Box::new(BurningShip { power: 3.0 }),
+            Box::new(Celtic { power: 2.0 }),
+        ];
+        for fractal in &fractals {
+            let mut boundaries = 0;
+            for row in 0..10 {
+                let im = -1.2 + row as f64 * 0.21;
+                for k in 0..300 {
+                    let (mut a, mut b) = (-2.5 + k as f64 * 0.01, -2.5 + (k + 1) as f64 * 0.01);
+                    let (ra, rb) = (
+                        fractal.compute_full(a, im, 200),
+                        fractal.compute_full(b, im, 200),
+                    );
+                    if !ra.escaped || !rb.escaped || ra.iterations.abs_diff(rb.iterations) != 1 {
+                        continue;
+                    }
+                    // Bisect down to the band edge, then compare both sides
+                    for _ in 0..50 {
+                        let mid = 0.5 * (a + b);
+                        if fractal.compute_full(mid, im, 200).iterations == ra.iterations {
+                            a = mid;
+                        } else {
+                            b = mid;
+                        }
+                    }
+                    let (ra, rb) = (
+                        fractal.compute_full(a, im, 200),
+                        fractal.compute_full(b, im, 200),
+                    );
+                    if ra.iterations.abs_diff(rb.iterations) != 1 {
+                        continue;
+                    }
+                    boundaries += 1;
+                    let jump = (processor.smooth_iterations(&ra, &context)
+                        - processor.smooth_iterations(&rb, &context))
+                    .abs();
+                    assert!(
+                        jump < 0.05,
+                        "{} jumps by {} at ({}, {})",
+                        fractal.name(),
+                        jump,
+                        a,
+                        im
+                    );
+                }
+            }
+            assert!(boundaries > 10, "{}: too few band edges", fractal.name());
+        }
+    }
+
     #[test]
     fn test_orbit_trap_processor() {
         let processor = OrbitTrapProcessor::new(TrapType::RealAxis, 0.1);
@@ -482,12 +1437,155 @@ mod tests {
             escaped: true,
             final_z: Some(Complex64::new(1.0, 0.05)),
             orbit_data,
+            root: None,
+            smooth_escape: None,
         };
 
         let color = processor.process(&result, &context);
         assert_ne!(color, Color32::BLACK);
     }
 
+    #[test]
+    fn test_trap_threshold_zoom_scaling() {
+        let mut processor = OrbitTrapProcessor::new(TrapType::Origin, 0.5);
+        let unit = ColorContext::new(100, PaletteType::Classic, 0.0, 200, 100);
+        // Zoom 10 on the same 100px-tall screen
+        let zoomed = unit.with_world_per_pixel(4.0 / (10.0 * 100.0));
+        assert!((unit.view_scale() - 1.0).abs() < 1e-12);
+        assert!((zoomed.view_scale() - 0.1).abs() < 1e-12);
+
+        // Fixed by default
+        assert_eq!(processor.effective_threshold(&zoomed), 0.5);
+
+        processor.set_parameter("zoom_scaling", 1.0);
+        assert!((processor.effective_threshold(&unit) - 0.5).abs() < 1e-12);
+        assert!((processor.effective_threshold(&zoomed) - 0.05).abs() < 1e-12);
+
+        processor.set_parameter("zoom_scaling", 0.5);
+        let expected = 0.5 * 0.1f64.sqrt();
+        assert!((processor.effective_threshold(&zoomed) - expected).abs() < 1e-12);
+
+        // Higher resolution at the same zoom changes nothing
+        let hires = ColorContext::new(100, PaletteType::Classic, 0.0, 400, 200)
+            .with_world_per_pixel(4.0 / (10.0 * 200.0));
+        assert!((processor.effective_threshold(&hires) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_line_trap_reflection_symmetry() {
+        let context = ColorContext::new(100, PaletteType::Classic, 0.0, 100, 100);
+        let processor = OrbitTrapProcessor::new(
+            TrapType::Line {
+                angle: 45.0,
+                offset: 0.0,
+            },
+            0.5,
+        );
+
+        // Reflecting across the 45 degree line swaps re and im
+        let line = processor.trap_line().unwrap();
+        let orbit = [Complex64::new(0.3, -0.2), Complex64::new(1.1, 0.4)];
+        let mirror: Vec<_> = orbit.iter().map(|z| Complex64::new(z.im, z.re)).collect();
+        let mut data = OrbitData::with_line(Some(line));
+        let mut reflected = OrbitData::with_line(Some(line));
+        for (&z, &w) in orbit.iter().zip(&mirror) {
+            data.update(z);
+            reflected.update(w);
+        }
+        let expected = (0.3f64 + 0.2) / std::f64::consts::SQRT_2;
+        assert!((data.min_distance_to_line - expected).abs() < 1e-12);
+        assert!((reflected.min_distance_to_line - expected).abs() < 1e-12);
+
+        let result = FractalResult::escaped(10, Complex64::new(3.0, 0.0), data);
+        let mirrored = FractalResult::escaped(10, Complex64::new(0.0, 3.0), reflected);
+        assert_eq!(
+            processor.process(&result, &context),
+            processor.process(&mirrored, &context)
+        );
+
+        // Offset shifts the line along its normal
+        let line = TrapLine::new(45.0, 1.0);
+        let on_line = Complex64::new(-1.0, 1.0) / std::f64::consts::SQRT_2;
+        assert!(line.distance(on_line) < 1e-12);
+        assert!((TrapLine::default().distance(Complex64::new(5.0, -0.25)) - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_line_trap_measured_per_pipeline() {
+        let mandelbrot = crate::fractal::Mandelbrot::default();
+        let (x, y) = (0.5, 0.5);
+        let orbit = mandelbrot.compute_orbit(x, y, 100).unwrap();
+        let distance_to = |line: TrapLine| {
+            orbit[1..]
+                .iter()
+                .map(|z| line.distance(*z))
+                .fold(f64::INFINITY, f64::min)
+        };
+
+        let mut steep = ColorPipeline::from_type(ColorProcessorType::OrbitTrapLine);
+        steep.processor.set_parameter("angle", 80.0);
+        let flat = ColorPipeline::from_type(ColorProcessorType::OrbitTrapLine);
+        // Creating or tuning one line trap leaves the other's line alone
+        let steep_result = steep.compute(&mandelbrot, x, y, 100);
+        assert!(steep_result.escaped);
+        let flat_result = flat.compute(&mandelbrot, x, y, 100);
+        assert_eq!(
+            steep_result.orbit_data.min_distance_to_line,
+            distance_to(TrapLine::new(80.0, 0.0))
+        );
+        assert_eq!(
+            flat_result.orbit_data.min_distance_to_line,
+            distance_to(TrapLine::new(45.0, 0.0))
+        );
+
+        // Without a line trap nothing is measured
+        let origin = ColorPipeline::from_type(ColorProcessorType::OrbitTrapOrigin);
+        let result = origin.compute(&mandelbrot, x, y, 100);
+        assert_eq!(result.orbit_data.min_distance_to_line, f64::INFINITY);
+        assert_eq!(result.iterations, steep_result.iterations);
+
+        // Fractals that can't trace an orbit still measure the line
+        let phoenix = crate::fractal::Phoenix::default();
+        let result = flat.compute(&phoenix, 0.8, 0.6, 100);
+        assert!(result.escaped && result.iterations > 0);
+        assert!(result.orbit_data.min_distance_to_line.is_finite());
+    }
+
+    #[test]
+    fn test_color_curves() {
+        let identity = ChannelCurves::default();
+        for v in 0..=255u8 {
+            let color = Color32::from_rgb(v, 255 - v, v / 2);
+            assert_eq!(identity.apply(color), color);
+        }
+
+        let curve = ColorCurve {
+            values: [0.0, 0.0, 0.5, 1.0, 1.0],
+        };
+        assert!(!curve.is_identity());
+        assert_eq!(curve.eval(0.125), 0.0);
+        assert_eq!(curve.eval(0.5), 0.5);
+        assert_eq!(curve.eval(0.625), 0.75);
+        assert_eq!(curve.eval(1.0), 1.0);
+        assert_eq!(curve.eval(2.0), 1.0);
+
+        // Only the red channel is inverted
+        let curves = ChannelCurves {
+            red: ColorCurve {
+                values: [1.0, 0.75, 0.5, 0.25, 0.0],
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            curves.apply(Color32::from_rgb(0, 64, 255)),
+            Color32::from_rgb(255, 64, 255)
+        );
+        assert_eq!(
+            curves.apply(Color32::from_rgb(255, 0, 0)),
+            Color32::from_rgb(0, 0, 0)
+        );
+    }
+
     #[test]
     fn test_orbit_data_update() {
         let mut data = OrbitData::new();
@@ -500,6 +1598,124 @@ mod tests {
         assert_eq!(data.max_imag, 2.0);
     }
 
+    #[test]
+    fn test_orbit_data_trap_point() {
+        let mut data = OrbitData::new();
+        data.update(Complex64::new(1.0, 1.0));
+        data.update(Complex64::new(0.1, -0.2));
+        data.update(Complex64::new(-1.5, 0.5));
+        assert_eq!(data.trap_point, Complex64::new(0.1, -0.2));
+    }
+
+    #[test]
+    fn test_image_trap_samples_pixel() {
+        // 2x2 image: red, green / blue, white
+        let image = TrapImage::new(
+            2,
+            2,
+            vec![Color32::RED, Color32::GREEN, Color32::BLUE, Color32::WHITE],
+        )
+        .unwrap();
+        let processor = ImageTrapProcessor::new(Some(Arc::new(image)));
+        let context = ColorContext::new(100, PaletteType::Classic, 0.0, 100, 100);
+
+        // Orbit spans [-1, 1] x [-1, 1]; trap point in the lower-right quadrant
+        let mut orbit_data = OrbitData::new();
+        orbit_data.update(Complex64::new(-1.0, 1.0));
+        orbit_data.update(Complex64::new(1.0, -1.0));
+        orbit_data.update(Complex64::new(0.5, -0.5));
+
+        let result = FractalResult::escaped(10, Complex64::new(3.0, 0.0), orbit_data);
+        assert_eq!(processor.process(&result, &context), Color32::WHITE);
+        assert_eq!(
+            processor.process(&FractalResult::inside_set(100), &context),
+            Color32::BLACK
+        );
+    }
+
+    #[test]
+    fn test_trap_image_per_pipeline() {
+        let solid = |color| Some(Arc::new(TrapImage::new(1, 1, vec![color]).unwrap()));
+        let red = ColorPipeline::from_type(ColorProcessorType::ImageTrap)
+            .with_trap_image(solid(Color32::RED));
+        let blue = ColorPipeline::from_type(ColorProcessorType::ImageTrap)
+            .with_trap_image(solid(Color32::BLUE));
+        let plain = ColorPipeline::from_type(ColorProcessorType::ImageTrap);
+
+        let mut orbit_data = OrbitData::new();
+        orbit_data.update(Complex64::new(0.5, 0.5));
+        let result = FractalResult::escaped(10, Complex64::new(3.0, 0.0), orbit_data);
+        let context = ColorContext::new(100, PaletteType::Classic, 0.0, 100, 100);
+        assert_eq!(red.process(&result, &context), Color32::RED);
+        assert_eq!(red.clone().process(&result, &context), Color32::RED);
+        assert_eq!(blue.process(&result, &context), Color32::BLUE);
+        // No image: palette fallback
+        assert_eq!(
+            plain.process(&result, &context),
+            PaletteProcessor.process(&result, &context)
+        );
+    }
+
+    #[test]
+    fn test_dual_palette_alternates_by_parity() {
+        let processor = DualPaletteProcessor::new(PaletteType::Fire, PaletteType::Ice, 1.0);
+        let context = ColorContext::new(100, PaletteType::Classic, 0.0, 100, 100);
+        let escaped = |n| FractalResult::escaped(n, Complex64::new(3.0, 0.0), OrbitData::new());
+
+        for n in [10, 11] {
+            let t = n as f32 / 100.0;
+            let expected = if n == 10 {
+                get_banded_color(PaletteType::Fire, t, 0.0, 1.0)
+            } else {
+                get_banded_color(PaletteType::Ice, t, 0.0, 1.0)
+            };
+            assert_eq!(processor.process(&escaped(n), &context), expected);
+        }
+        assert_ne!(
+            processor.process(&escaped(10), &context),
+            processor.process(&escaped(11), &context)
+        );
+
+        // Wider bands keep neighbours in one palette until the band ends
+        let mut wide = processor;
+        wide.set_parameter("band_width", 4.0);
+        assert_eq!(wide.palette_for(4), PaletteType::Ice);
+        assert_eq!(wide.palette_for(7), PaletteType::Ice);
+        assert_eq!(wide.palette_for(8), PaletteType::Fire);
+        assert_eq!(
+            wide.process(&FractalResult::inside_set(100), &context),
+            Color32::BLACK
+        );
+    }
+
+    #[test]
+    fn test_processor_parameters() {
+        let pipeline = ColorPipeline::from_type(ColorProcessorType::OrbitTrapOrigin);
+        assert_eq!(pipeline.parameter_values()["threshold"], 0.5);
+        assert!(ColorPipeline::from_type(ColorProcessorType::Palette)
+            .parameters()
+            .is_empty());
+
+        let params = HashMap::from([
+            ("threshold".to_string(), 0.25),
+            ("unknown".to_string(), 1.0),
+        ]);
+        let pipeline =
+            ColorPipeline::from_type_with_params(ColorProcessorType::OrbitTrapOrigin, &params);
+        assert_eq!(
+            pipeline.parameter_values(),
+            HashMap::from([
+                ("threshold".to_string(), 0.25),
+                ("zoom_scaling".to_string(), 0.0),
+            ])
+        );
+
+        let params = HashMap::from([("threshold".to_string(), 100.0)]);
+        let pipeline =
+            ColorPipeline::from_type_with_params(ColorProcessorType::OrbitTrapReal, &params);
+        assert_eq!(pipeline.parameter_values()["threshold"], MAX_TRAP_THRESHOLD);
+    }
+
     #[test]
     fn test_color_pipeline() {
         let mut pipeline = ColorPipeline::default();
@@ -2,6 +2,7 @@ use crate::fractal::FractalType;
 use crate::palette::PaletteType;
 use crate::viewport::Viewport;
 use crate::FractalViewState;
+use serde::{Deserialize, Serialize};
 
 /// State that can be modified by commands.
 /// Uses FractalViewState as the canonical representation,
@@ -49,6 +50,9 @@ pub trait Command: Send + Sync {
 
     /// Clone this command into a Box
     fn clone_box(&self) -> Box<dyn Command>;
+
+    /// Serializable form of this command for view-path scripts
+    fn descriptor(&self) -> CommandDescriptor;
 }
 
 impl Clone for Box<dyn Command> {
@@ -57,6 +61,93 @@ impl Clone for Box<dyn Command> {
     }
 }
 
+/// Serializable description of a command, used to export the sequence of
+/// commands that led to a view and replay it later.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum CommandDescriptor {
+    View {
+        old_center_x: f64,
+        old_center_y: f64,
+        old_zoom: f64,
+        new_center_x: f64,
+        new_center_y: f64,
+        new_zoom: f64,
+    },
+    Parameter {
+        name: String,
+        old_value: f64,
+        new_value: f64,
+    },
+    FractalType {
+        old_type: FractalType,
+        new_type: FractalType,
+        old_view: Box<FractalViewState>,
+        new_view: Box<FractalViewState>,
+    },
+    Iteration {
+        old_iterations: u32,
+        new_iterations: u32,
+    },
+    Palette {
+        old_palette: PaletteType,
+        new_palette: PaletteType,
+        old_offset: f32,
+        new_offset: f32,
+    },
+}
+
+impl CommandDescriptor {
+    /// Rebuild the command this descriptor was exported from
+    pub fn to_command(&self) -> Box<dyn Command> {
+        match self.clone() {
+            Self::View {
+                old_center_x,
+                old_center_y,
+                old_zoom,
+                new_center_x,
+                new_center_y,
+                new_zoom,
+            } => Box::new(ViewCommand::new(
+                old_center_x,
+                old_center_y,
+                old_zoom,
+                new_center_x,
+                new_center_y,
+                new_zoom,
+            )),
+            Self::Parameter {
+                name,
+                old_value,
+                new_value,
+            } => Box::new(ParameterCommand::new(name, old_value, new_value)),
+            Self::FractalType {
+                old_type,
+                new_type,
+                old_view,
+                new_view,
+            } => Box::new(FractalTypeCommand::new(
+                old_type, new_type, *old_view, *new_view,
+            )),
+            Self::Iteration {
+                old_iterations,
+                new_iterations,
+            } => Box::new(IterationCommand::new(old_iterations, new_iterations)),
+            Self::Palette {
+                old_palette,
+                new_palette,
+                old_offset,
+                new_offset,
+            } => Box::new(PaletteCommand::new(
+                old_palette,
+                new_palette,
+                old_offset,
+                new_offset,
+            )),
+        }
+    }
+}
+
 /// Command for changing the view (pan/zoom)
 #[derive(Debug, Clone)]
 pub struct ViewCommand {
@@ -128,6 +219,17 @@ impl Command for ViewCommand {
     fn clone_box(&self) -> Box<dyn Command> {
         Box::new(self.clone())
     }
+
+    fn descriptor(&self) -> CommandDescriptor {
+        CommandDescriptor::View {
+            old_center_x: self.old_center_x,
+            old_center_y: self.old_center_y,
+            old_zoom: self.old_zoom,
+            new_center_x: self.new_center_x,
+            new_center_y: self.new_center_y,
+            new_zoom: self.new_zoom,
+        }
+    }
 }
 
 /// Command for changing fractal parameters
@@ -175,11 +277,18 @@ impl Command for ParameterCommand {
     fn clone_box(&self) -> Box<dyn Command> {
         Box::new(self.clone())
     }
+
+    fn descriptor(&self) -> CommandDescriptor {
+        CommandDescriptor::Parameter {
+            name: self.param_name.clone(),
+            old_value: self.old_value,
+            new_value: self.new_value,
+        }
+    }
 }
 
 /// Command for changing the fractal type
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct FractalTypeCommand {
     old_type: FractalType,
     new_type: FractalType,
@@ -187,7 +296,6 @@ pub struct FractalTypeCommand {
     new_view: FractalViewState,
 }
 
-#[allow(dead_code)]
 impl FractalTypeCommand {
     pub fn new(
         old_type: FractalType,
@@ -222,6 +330,15 @@ impl Command for FractalTypeCommand {
     fn clone_box(&self) -> Box<dyn Command> {
         Box::new(self.clone())
     }
+
+    fn descriptor(&self) -> CommandDescriptor {
+        CommandDescriptor::FractalType {
+            old_type: self.old_type,
+            new_type: self.new_type,
+            old_view: Box::new(self.old_view.clone()),
+            new_view: Box::new(self.new_view.clone()),
+        }
+    }
 }
 
 /// Command for changing iteration count
@@ -261,11 +378,17 @@ impl Command for IterationCommand {
     fn clone_box(&self) -> Box<dyn Command> {
         Box::new(self.clone())
     }
+
+    fn descriptor(&self) -> CommandDescriptor {
+        CommandDescriptor::Iteration {
+            old_iterations: self.old_iterations,
+            new_iterations: self.new_iterations,
+        }
+    }
 }
 
 /// Command for changing palette
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct PaletteCommand {
     old_palette: PaletteType,
     new_palette: PaletteType,
@@ -273,7 +396,6 @@ pub struct PaletteCommand {
     new_offset: f32,
 }
 
-#[allow(dead_code)]
 impl PaletteCommand {
     pub fn new(
         old_palette: PaletteType,
@@ -311,6 +433,15 @@ impl Command for PaletteCommand {
     fn clone_box(&self) -> Box<dyn Command> {
         Box::new(self.clone())
     }
+
+    fn descriptor(&self) -> CommandDescriptor {
+        CommandDescriptor::Palette {
+            old_palette: self.old_palette,
+            new_palette: self.new_palette,
+            old_offset: self.old_offset,
+            new_offset: self.new_offset,
+        }
+    }
 }
 
 /// History manager for undo/redo
@@ -374,6 +505,37 @@ impl CommandHistory {
         }
     }
 
+    /// Change the capacity. When shrinking, the oldest applied commands go
+    /// first, then the newest redo entries.
+    pub fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = max_size;
+        let excess = self.commands.len().saturating_sub(max_size);
+        let from_front = excess.min(self.current_index);
+        self.commands.drain(..from_front);
+        self.current_index -= from_front;
+        self.commands.truncate(max_size);
+    }
+
+    /// Undo or redo until `index` commands are applied (clamped to the
+    /// history length). Returns the number of steps taken.
+    pub fn seek_to(&mut self, index: usize, state: &mut AppState) -> usize {
+        let target = index.min(self.commands.len());
+        let steps = self.current_index.abs_diff(target);
+        while self.current_index > target {
+            self.undo(state);
+        }
+        while self.current_index < target {
+            self.redo(state);
+        }
+        steps
+    }
+
+    /// Number of commands currently applied, i.e. the position between
+    /// undo and redo history
+    pub fn position(&self) -> usize {
+        self.current_index
+    }
+
     /// Check if undo is available
     pub fn can_undo(&self) -> bool {
         self.current_index > 0
@@ -385,7 +547,6 @@ impl CommandHistory {
     }
 
     /// Get the number of commands in history
-    #[allow(dead_code)]
     pub fn len(&self) -> usize {
         self.commands.len()
     }
@@ -432,6 +593,15 @@ impl CommandHistory {
             .map(|cmd| cmd.description())
             .collect()
     }
+
+    /// Export the applied commands (oldest first, excluding any redo
+    /// history) as a replayable script
+    pub fn export_script(&self) -> Vec<CommandDescriptor> {
+        self.commands[..self.current_index]
+            .iter()
+            .map(|cmd| cmd.descriptor())
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -500,6 +670,39 @@ mod tests {
         assert!(!history.can_redo());
     }
 
+    #[test]
+    fn test_seek_to() {
+        let mut history = CommandHistory::new(10);
+        let mut state = AppState::default();
+        for x in 1..=3 {
+            let cmd = Box::new(ViewCommand::new(
+                (x - 1) as f64,
+                0.0,
+                1.0,
+                x as f64,
+                0.0,
+                1.0,
+            ));
+            history.execute(cmd, &mut state);
+        }
+        assert_eq!(history.position(), 3);
+
+        // Two undos: back to after the first command
+        assert_eq!(history.seek_to(1, &mut state), 2);
+        assert_eq!(history.position(), 1);
+        assert_eq!(state.view.center_x, 1.0);
+        assert_eq!(history.len(), 3);
+
+        // Forward again, clamped to the end of the redo history
+        assert_eq!(history.seek_to(10, &mut state), 2);
+        assert_eq!(state.view.center_x, 3.0);
+        assert_eq!(history.seek_to(3, &mut state), 0);
+
+        history.seek_to(0, &mut state);
+        assert_eq!(state.view.center_x, 0.0);
+        assert!(!history.can_undo());
+    }
+
     #[test]
     fn test_history_limit() {
         let mut history = CommandHistory::new(3);
@@ -525,6 +728,48 @@ mod tests {
         assert!(history.undo(&mut state).is_none());
     }
 
+    #[test]
+    fn test_set_max_size() {
+        let view_cmd =
+            |from: f64, to: f64| Box::new(ViewCommand::new(from, 0.0, 1.0, to, 0.0, 1.0));
+        let mut history = CommandHistory::new(4);
+        let mut state = AppState::default();
+        for i in 0..4 {
+            history.execute(view_cmd(i as f64, (i + 1) as f64), &mut state);
+        }
+        assert_eq!(history.len(), 4);
+
+        // N + 1 executions drop the oldest command
+        history.execute(view_cmd(4.0, 5.0), &mut state);
+        assert_eq!(history.len(), 4);
+        history.seek_to(0, &mut state);
+        assert_eq!(state.view.center_x, 1.0);
+        history.seek_to(4, &mut state);
+
+        // Shrinking trims the oldest applied commands
+        history.undo(&mut state);
+        history.set_max_size(2);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.position(), 1);
+        assert!(history.can_redo());
+        history.undo(&mut state);
+        assert!(!history.can_undo());
+        assert_eq!(state.view.center_x, 3.0);
+
+        // With nothing applied, shrinking drops the newest redo entries
+        history.set_max_size(1);
+        assert_eq!(history.len(), 1);
+        history.redo(&mut state);
+        assert_eq!(state.view.center_x, 4.0);
+
+        // Growing keeps everything and raises the limit
+        history.set_max_size(10);
+        for i in 0..5 {
+            history.execute(view_cmd(i as f64, i as f64 + 0.5), &mut state);
+        }
+        assert_eq!(history.len(), 6);
+    }
+
     #[test]
     fn test_undo_clears_redo() {
         let mut history = CommandHistory::new(10);
@@ -566,4 +811,43 @@ mod tests {
         let iter_cmd = IterationCommand::new(100, 200);
         assert!(iter_cmd.description().contains("200"));
     }
+
+    #[test]
+    fn test_script_round_trip() {
+        let mut history = CommandHistory::new(10);
+        let mut state = AppState::default();
+        history.execute(
+            Box::new(ViewCommand::new(0.0, 0.0, 1.0, -0.75, 0.1, 4.0)),
+            &mut state,
+        );
+        history.execute(
+            Box::new(ViewCommand::new(-0.75, 0.1, 4.0, -0.7436, 0.1318, 250.0)),
+            &mut state,
+        );
+        history.execute(Box::new(IterationCommand::new(0, 500)), &mut state);
+        // Undone commands are not part of the path
+        history.execute(
+            Box::new(ViewCommand::new(-0.7436, 0.1318, 250.0, 0.0, 0.0, 1.0)),
+            &mut state,
+        );
+        history.undo(&mut state);
+
+        let script = history.export_script();
+        assert_eq!(script.len(), 3);
+        let json = serde_json::to_string(&script).unwrap();
+        let parsed: Vec<CommandDescriptor> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, script);
+
+        let mut replayed = AppState::default();
+        let mut replay_history = CommandHistory::new(10);
+        for descriptor in &parsed {
+            replay_history.execute(descriptor.to_command(), &mut replayed);
+        }
+        assert_eq!(replayed.view.center_x, state.view.center_x);
+        assert_eq!(replayed.view.center_y, state.view.center_y);
+        assert_eq!(replayed.view.zoom, state.view.zoom);
+        assert_eq!(replayed.view.max_iterations, 500);
+        assert_eq!(replay_history.len(), 3);
+        assert_eq!(replay_history.export_script(), script);
+    }
 }
@@ -0,0 +1,136 @@
+//! Poster composite: the parameter plane with the seed marked, beside the
+//! Julia set it produces, over a caption strip.
+//!
+//! Captions use a built-in 3x5 pixel font so no font files are needed; it
+//! covers the characters of a complex number ("c = -0.7 + 0.27i").
+
+use image::{imageops, Rgb, RgbImage};
+
+/// Height of the caption strip under the panels
+pub const CAPTION_HEIGHT: u32 = 40;
+const CAPTION_BACKGROUND: Rgb<u8> = Rgb([16, 16, 16]);
+const CAPTION_COLOR: Rgb<u8> = Rgb([230, 230, 230]);
+/// Each font pixel is drawn as a GLYPH_SCALE x GLYPH_SCALE block
+const GLYPH_SCALE: u32 = 4;
+const GLYPH_WIDTH: u32 = 3;
+const GLYPH_HEIGHT: u32 = 5;
+/// Arm length of the seed marker, and the gap left open at its center
+const MARKER_ARM: i64 = 10;
+const MARKER_GAP: i64 = 3;
+const MARKER_COLOR: Rgb<u8> = Rgb([255, 255, 255]);
+
+/// Place `left` and `right` side by side, top-aligned, and write `caption`
+/// centered in a strip below them
+pub fn compose(left: &RgbImage, right: &RgbImage, caption: &str) -> RgbImage {
+    let panel_height = left.height().max(right.height());
+    let mut out = RgbImage::from_pixel(
+        left.width() + right.width(),
+        panel_height + CAPTION_HEIGHT,
+        CAPTION_BACKGROUND,
+    );
+    imageops::replace(&mut out, left, 0, 0);
+    imageops::replace(&mut out, right, left.width() as i64, 0);
+
+    let text_width = caption.chars().count() as u32 * (GLYPH_WIDTH + 1) * GLYPH_SCALE;
+    let x = out.width().saturating_sub(text_width) / 2;
+    let y = panel_height + (CAPTION_HEIGHT - GLYPH_HEIGHT * GLYPH_SCALE) / 2;
+    draw_text(&mut out, caption, x, y);
+    out
+}
+
+/// Crosshair centered on pixel (x, y); parts off the image are clipped
+pub fn mark_point(img: &mut RgbImage, x: i64, y: i64) {
+    for d in MARKER_GAP..=MARKER_ARM {
+        for (px, py) in [(x - d, y), (x + d, y), (x, y - d), (x, y + d)] {
+            if px >= 0 && py >= 0 && px < img.width() as i64 && py < img.height() as i64 {
+                img.put_pixel(px as u32, py as u32, MARKER_COLOR);
+            }
+        }
+    }
+}
+
+fn draw_text(img: &mut RgbImage, text: &str, x: u32, y: u32) {
+    for (i, ch) in text.chars().enumerate() {
+        let origin_x = x + i as u32 * (GLYPH_WIDTH + 1) * GLYPH_SCALE;
+        for (row, bits) in glyph(ch).iter().enumerate() {
+            for col in 0..GLYPH_WIDTH {
+                if bits & (1 << (GLYPH_WIDTH - 1 - col)) == 0 {
+                    continue;
+                }
+                for dy in 0..GLYPH_SCALE {
+                    for dx in 0..GLYPH_SCALE {
+                        let px = origin_x + col * GLYPH_SCALE + dx;
+                        let py = y + row as u32 * GLYPH_SCALE + dy;
+                        if px < img.width() && py < img.height() {
+                            img.put_pixel(px, py, CAPTION_COLOR);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Rows of a 3x5 glyph, most significant bit leftmost. Unknown characters
+/// are blank.
+fn glyph(ch: char) -> [u8; 5] {
+    match ch {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b001, 0b001, 0b001],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        '+' => [0b000, 0b010, 0b111, 0b010, 0b000],
+        '=' => [0b000, 0b111, 0b000, 0b111, 0b000],
+        'c' => [0b000, 0b000, 0b111, 0b100, 0b111],
+        'i' => [0b010, 0b000, 0b010, 0b010, 0b010],
+        _ => [0; 5],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose_places_panels() {
+        let red = RgbImage::from_pixel(6, 4, Rgb([255, 0, 0]));
+        let blue = RgbImage::from_pixel(5, 4, Rgb([0, 0, 255]));
+        let out = compose(&red, &blue, "c = -0.7 + 0.27i");
+
+        assert_eq!(out.dimensions(), (11, 4 + CAPTION_HEIGHT));
+        for (x, y) in [(0, 0), (5, 0), (0, 3), (5, 3)] {
+            assert_eq!(*out.get_pixel(x, y), Rgb([255, 0, 0]));
+        }
+        for (x, y) in [(6, 0), (10, 0), (6, 3), (10, 3)] {
+            assert_eq!(*out.get_pixel(x, y), Rgb([0, 0, 255]));
+        }
+        // The caption strip starts right under the panels
+        assert_eq!(*out.get_pixel(0, 4), CAPTION_BACKGROUND);
+    }
+
+    #[test]
+    fn test_caption_and_marker_drawn() {
+        let panel = RgbImage::from_pixel(100, 50, Rgb([0, 0, 0]));
+        let out = compose(&panel, &panel, "c = 0.25i");
+        let caption_pixels = out
+            .enumerate_pixels()
+            .filter(|(_, y, p)| *y >= 50 && **p == CAPTION_COLOR)
+            .count();
+        assert!(caption_pixels > 0);
+
+        let mut marked = panel.clone();
+        mark_point(&mut marked, 0, 25);
+        assert_eq!(*marked.get_pixel(MARKER_ARM as u32, 25), MARKER_COLOR);
+        assert_eq!(*marked.get_pixel(0, 25 + MARKER_ARM as u32), MARKER_COLOR);
+        // Center left open so the exact seed stays visible
+        assert_eq!(*marked.get_pixel(0, 25), Rgb([0, 0, 0]));
+    }
+}
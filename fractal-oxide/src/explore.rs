@@ -0,0 +1,79 @@
+//! Seeded search for visually interesting points near a fractal's boundary.
+//!
+//! Points that escape only after many iterations lie close to the boundary,
+//! where the detail is. The finder samples random candidates and keeps the
+//! one whose escape count lands closest to a target band.
+
+use crate::fractal::Fractal;
+
+/// Iteration limit used to probe candidates
+pub const PROBE_MAX_ITERATIONS: u32 = 500;
+/// Escape counts in this range (inclusive) count as near the boundary
+pub const BOUNDARY_BAND: (u32, u32) = (100, 400);
+/// Candidates sampled per search
+const PROBE_CANDIDATES: usize = 20_000;
+/// Sampling region (re_min, re_max, im_min, im_max) covering the Mandelbrot set
+const PROBE_REGION: (f64, f64, f64, f64) = (-2.0, 0.5, -1.25, 1.25);
+
+/// SplitMix64: tiny deterministic generator so the same seed always finds
+/// the same spot
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Sample random points and return the one whose escape count is closest to
+/// the middle of `BOUNDARY_BAND`, or None if no candidate reaches the band.
+pub fn find_boundary_point(fractal: &dyn Fractal, seed: u64) -> Option<(f64, f64)> {
+    let (re_min, re_max, im_min, im_max) = PROBE_REGION;
+    let target = (BOUNDARY_BAND.0 + BOUNDARY_BAND.1) / 2;
+    let mut rng = SplitMix64(seed);
+    let mut best: Option<(u32, f64, f64)> = None;
+    for _ in 0..PROBE_CANDIDATES {
+        let re = re_min + rng.next_f64() * (re_max - re_min);
+        let im = im_min + rng.next_f64() * (im_max - im_min);
+        let result = fractal.compute_full(re, im, PROBE_MAX_ITERATIONS);
+        if !result.escaped || !(BOUNDARY_BAND.0..=BOUNDARY_BAND.1).contains(&result.iterations) {
+            continue;
+        }
+        let score = result.iterations.abs_diff(target);
+        if best.is_none_or(|(best_score, _, _)| score < best_score) {
+            best = Some((score, re, im));
+        }
+    }
+    best.map(|(_, re, im)| (re, im))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fractal::registry::FractalRegistry;
+    use crate::fractal::FractalType;
+
+    #[test]
+    fn test_find_boundary_point_in_band() {
+        let fractal = FractalRegistry::default()
+            .create(FractalType::Mandelbrot)
+            .unwrap();
+        let (re, im) = find_boundary_point(fractal.as_ref(), 7).expect("a boundary point");
+        let result = fractal.compute_full(re, im, PROBE_MAX_ITERATIONS);
+        assert!(result.escaped);
+        assert!((BOUNDARY_BAND.0..=BOUNDARY_BAND.1).contains(&result.iterations));
+
+        // Deterministic per seed, different across seeds
+        assert_eq!(find_boundary_point(fractal.as_ref(), 7), Some((re, im)));
+        assert_ne!(find_boundary_point(fractal.as_ref(), 8), Some((re, im)));
+    }
+}
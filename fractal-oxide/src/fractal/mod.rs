@@ -1,15 +1,160 @@
 use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 
-use crate::color_pipeline::{FractalResult, OrbitData};
+use crate::color_pipeline::{FractalResult, OrbitData, SmoothEscape};
+use crate::precision::HpDecimal;
 
 pub mod registry;
 
 /// Squared bailout radius: escape when |z|^2 > BAILOUT_R2
 const BAILOUT_R2: f64 = 4.0;
+/// Parameters that the plane of `Fractal::parameter_space_fractal` maps to
+pub const SEED_PARAMETERS: (&str, &str) = ("c_real", "c_imag");
 /// Epsilon for power=2 fast path comparison
 const POWER2_EPSILON: f64 = 1e-10;
 
+/// Escape test for the power fractals. Also true once the orbit has
+/// overflowed: an infinite or NaN |z|^2 (e.g. inf * 0 out of the De Moivre
+/// step) never compares greater than the bailout, so without this the point
+/// would iterate to max_iter and be colored as inside the set.
+#[inline(always)]
+fn has_escaped(norm_sqr: f64, bailout2: f64) -> bool {
+    norm_sqr > bailout2 || norm_sqr.is_nan()
+}
+
+/// Squared radius an escaped orbit is followed out to for smooth coloring.
+/// Far enough that `+ c` no longer bends |z| away from |z|^power growth.
+const SMOOTH_ESCAPE_R2: f64 = 1e12;
+/// Cap on the steps taken past the bailout (power 1 would never get there)
+const MAX_SMOOTH_ESCAPE_STEPS: u32 = 32;
+
+/// Keep iterating an orbit that has just passed the bailout until it is far
+/// out, for `SmoothColoring`. `step` maps (z_re, z_im, re^2, im^2) to the
+/// next z.
+fn continue_escape(
+    mut z_re: f64,
+    mut z_im: f64,
+    power: f64,
+    step: impl Fn(f64, f64, f64, f64) -> (f64, f64),
+) -> SmoothEscape {
+    let mut extra_iterations = 0;
+    while extra_iterations < MAX_SMOOTH_ESCAPE_STEPS {
+        let r2 = z_re * z_re;
+        let i2 = z_im * z_im;
+        if has_escaped(r2 + i2, SMOOTH_ESCAPE_R2) {
+            break;
+        }
+        (z_re, z_im) = step(z_re, z_im, r2, i2);
+        extra_iterations += 1;
+    }
+    SmoothEscape {
+        z: Complex64::new(z_re, z_im),
+        extra_iterations,
+        power,
+    }
+}
+
+/// One power-2 step z^2 + c, reusing the squares already computed for the
+/// bailout test:
+///   Re = r2 - i2 + c_re
+///   Im = 2*z_re*z_im + c_im
+///
+/// With r2 and i2 shared, this is one multiply per step beyond the
+/// bailout test (doubling is exact), the same count as the "3-multiply"
+/// form (z_re + z_im)^2 - r2 - i2. That form is not used: it saves
+/// nothing here, cancels badly when one component is small and breaks
+/// the exact conjugate symmetry of the iteration.
+#[inline(always)]
+fn square_add(z_re: f64, z_im: f64, r2: f64, i2: f64, c_re: f64, c_im: f64) -> (f64, f64) {
+    (r2 - i2 + c_re, 2.0 * z_re * z_im + c_im)
+}
+
+/// One z^power + c step as the Mandelbrot and Julia loops take it: the
+/// algebraic square for power 2, De Moivre otherwise
+#[inline(always)]
+fn power_step(
+    z_re: f64,
+    z_im: f64,
+    r2: f64,
+    i2: f64,
+    c_re: f64,
+    c_im: f64,
+    power: f64,
+) -> (f64, f64) {
+    if (power - 2.0).abs() < POWER2_EPSILON {
+        square_add(z_re, z_im, r2, i2, c_re, c_im)
+    } else {
+        let angle = power * z_im.atan2(z_re);
+        let radius = (r2 + i2).powf(power / 2.0);
+        (radius * angle.cos() + c_re, radius * angle.sin() + c_im)
+    }
+}
+
+/// Orbit z_0, z_1, ... from `z0`, ending with the first value past
+/// `bailout2` or after `max_iter` steps. `step` maps (z_re, z_im, re^2,
+/// im^2) to the next z.
+fn trace_orbit(
+    z0: (f64, f64),
+    max_iter: u32,
+    bailout2: f64,
+    mut step: impl FnMut(f64, f64, f64, f64) -> (f64, f64),
+) -> Vec<Complex64> {
+    let (mut z_re, mut z_im) = z0;
+    let mut orbit = vec![Complex64::new(z_re, z_im)];
+    for _ in 0..max_iter {
+        let r2 = z_re * z_re;
+        let i2 = z_im * z_im;
+        if has_escaped(r2 + i2, bailout2) {
+            break;
+        }
+        (z_re, z_im) = step(z_re, z_im, r2, i2);
+        orbit.push(Complex64::new(z_re, z_im));
+    }
+    orbit
+}
+
+/// Decimals kept past those of the starting point when iterating in high
+/// precision, so rounding stays below the point's own digits for a while
+const HP_GUARD_DIGITS: u32 = 20;
+
+/// A point (re, im) in decimal arithmetic
+type HpPoint = (HpDecimal, HpDecimal);
+
+/// z² + c in decimal arithmetic
+fn square_add_hp(z: &HpPoint, c: &HpPoint) -> HpPoint {
+    let (z_re, z_im) = z;
+    let re_im = z_re.mul(z_im);
+    (
+        z_re.mul(z_re).add(&z_im.mul(z_im).neg()).add(&c.0),
+        re_im.add(&re_im).add(&c.1),
+    )
+}
+
+/// `trace_orbit` in decimal arithmetic for a pixel given to more digits
+/// than f64 holds. Each z is cut to `HP_GUARD_DIGITS` decimals past the
+/// pixel's own, so the digit count stays bounded.
+fn trace_orbit_hp(
+    pixel: &HpPoint,
+    z0: HpPoint,
+    max_iter: u32,
+    step: impl Fn(&HpPoint) -> HpPoint,
+) -> Vec<Complex64> {
+    let decimals = pixel.0.fraction_digits().max(pixel.1.fraction_digits());
+    let exponent = -((decimals + HP_GUARD_DIGITS) as i32);
+    let to_f64 = |z: &HpPoint| Complex64::new(z.0.to_f64(), z.1.to_f64());
+    let mut z = z0;
+    let mut orbit = vec![to_f64(&z)];
+    for _ in 0..max_iter {
+        if has_escaped(orbit[orbit.len() - 1].norm_sqr(), BAILOUT_R2) {
+            break;
+        }
+        let (re, im) = step(&z);
+        z = (re.truncate(exponent), im.truncate(exponent));
+        orbit.push(to_f64(&z));
+    }
+    orbit
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum FractalType {
     #[default]
@@ -25,6 +170,8 @@ pub enum FractalType {
     Spider,
     OrbitTrap,
     PickoverStalk,
+    PolynomialJulia,
+    MandelJuliaMorph,
 }
 
 impl FractalType {
@@ -44,10 +191,22 @@ impl FractalType {
             FractalType::Spider => (0.0, 0.0),
             FractalType::OrbitTrap => (-0.5, 0.0),
             FractalType::PickoverStalk => (-0.5, 0.0),
+            FractalType::PolynomialJulia => (0.0, 0.0),
+            FractalType::MandelJuliaMorph => (-0.5, 0.0),
         }
     }
 }
 
+/// Escape-time loops that the optional GPU backend can run in place of
+/// `compute_full` (power-2 only)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GpuKernel {
+    /// z = z^2 + c with z0 = 0 and c the pixel coordinate
+    Mandelbrot,
+    /// z = z^2 + c with z0 the pixel coordinate and a fixed c
+    Julia { c_re: f64, c_im: f64 },
+}
+
 #[derive(Debug, Clone)]
 pub struct Parameter {
     pub name: String,
@@ -56,6 +215,34 @@ pub struct Parameter {
     pub max: f64,
 }
 
+impl Parameter {
+    /// Short explanation of what the parameter controls, for UI tooltips
+    pub fn description(&self) -> &'static str {
+        match self.name.as_str() {
+            "power" => "Exponent applied to z each iteration",
+            "c_real" => "Real part of the constant c added each iteration",
+            "c_imag" => "Imaginary part of the constant c added each iteration",
+            "memory" => "Weight of the previous z value (Phoenix memory term)",
+            "conjugate" => "1 = conjugate z before raising it to the power (Tricorn-style)",
+            "escape_radius" => "Threshold for the biomorph |Re|/|Im| escape test",
+            "bailout" => "|z| at which iteration stops to avoid overflow",
+            "tolerance" => "Distance to a root at which iteration stops",
+            "trap_x" => "Real coordinate of the orbit trap point",
+            "trap_y" => "Imaginary coordinate of the orbit trap point",
+            "thickness" => "Width of the stalks around the axes",
+            "intensity" => "Brightness scaling of the stalks",
+            "feedback" => "Fraction of the previous c kept when c evolves (0.5 = classic Spider)",
+            "sign" => "Coefficient of z in the evolving c: +1 classic Spider, -1 anti-spider",
+            "degree" => "Degree of the polynomial P(z)",
+            "morph" => "0 = Mandelbrot (z0 = 0), 1 = Julia (z0 = pixel, fixed c)",
+            name if PolynomialJulia::coefficient_name(name).is_some() => {
+                "Coefficient of z^k in P(z) (aK_re / aK_im = real / imaginary part)"
+            }
+            _ => "",
+        }
+    }
+}
+
 /// Trait for fractal implementations.
 ///
 /// Each fractal provides:
@@ -67,6 +254,9 @@ pub trait Fractal: Send + Sync {
     #[allow(dead_code)]
     fn name(&self) -> &str;
 
+    /// The iteration and escape condition in plain notation, for display
+    fn iteration_formula(&self) -> &str;
+
     /// Returns the list of configurable parameters for this fractal.
     fn parameters(&self) -> Vec<Parameter>;
 
@@ -85,11 +275,23 @@ pub trait Fractal: Send + Sync {
     fn compute(&self, cx: f64, cy: f64, max_iter: u32) -> u32;
 
     /// Computes the full fractal result including orbit data and final z value.
+    fn compute_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
+        self.compute_full_with(cx, cy, max_iter, OrbitData::new())
+    }
+
+    /// `compute_full`, collecting the orbit into `orbit_data` (which may
+    /// carry a trap line to measure).
     ///
     /// The default implementation wraps `compute()` but does not provide
     /// orbit data or final_z. Override this in fractal implementations
     /// to provide rich data for smooth coloring and orbit trap processors.
-    fn compute_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
+    fn compute_full_with(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        _orbit_data: OrbitData,
+    ) -> FractalResult {
         let iterations = self.compute(cx, cy, max_iter);
         if iterations >= max_iter {
             FractalResult::inside_set(iterations)
@@ -97,6 +299,48 @@ pub trait Fractal: Send + Sync {
             FractalResult::escaped(iterations, Complex64::new(0.0, 0.0), OrbitData::default())
         }
     }
+
+    /// The orbit z_0, z_1, ... of a point, ending with the first value past
+    /// the bailout or after `max_iter` steps (so an escaping point's orbit
+    /// has its iteration count + 1 entries). None if the fractal does not
+    /// expose its orbit.
+    fn compute_orbit(&self, _x: f64, _y: f64, _max_iter: u32) -> Option<Vec<Complex64>> {
+        None
+    }
+
+    /// `compute_orbit` of a point given to more digits than f64 holds,
+    /// iterated in decimal arithmetic. None if the fractal only iterates
+    /// in f64.
+    fn compute_orbit_hp(
+        &self,
+        _x: &HpDecimal,
+        _y: &HpDecimal,
+        _max_iter: u32,
+    ) -> Option<Vec<Complex64>> {
+        None
+    }
+
+    /// The GPU kernel equivalent to this fractal with its current
+    /// parameters, or None if it must be computed on the CPU.
+    fn gpu_kernel(&self) -> Option<GpuKernel> {
+        None
+    }
+
+    /// Whether the iteration is mirror-symmetric about the real axis, so
+    /// the point conj(c) yields the conjugate orbit of c (up to rounding).
+    /// Renderers may then compute one half of an axis-centered view and
+    /// mirror the other.
+    fn has_real_axis_symmetry(&self) -> bool {
+        false
+    }
+
+    /// The fractal whose plane is this fractal's parameter space: each point
+    /// is a value of the `SEED_PARAMETERS` (c_real, c_imag). For Julia this
+    /// is the Mandelbrot set, where bounded points give connected Julia sets
+    /// and escaping points give dust. None if the fractal has no seed.
+    fn parameter_space_fractal(&self) -> Option<Box<dyn Fractal>> {
+        None
+    }
 }
 
 /// Macro to generate Fractal implementations for simple power-based fractals.
@@ -105,15 +349,27 @@ pub trait Fractal: Send + Sync {
 /// - name() method
 /// - parameters() returning a single "power" parameter
 /// - set_parameter() and get_parameter() implementations
+/// - compute_orbit() from z0 = 0 via the struct's `step` method, and
+///   compute_orbit_hp() via its `step_hp` method when power is 2
 ///
-/// Usage: impl_power_fractal!(StructName, "Display Name")
+/// Usage: impl_power_fractal!(StructName, "Display Name", "formula")
+/// or impl_power_fractal!(StructName, "Display Name", "formula", kernel,
+/// symmetric) to also offer a GPU kernel when power is 2 and/or declare
+/// real-axis symmetry
 macro_rules! impl_power_fractal {
-    ($struct_name:ident, $display_name:expr) => {
+    ($struct_name:ident, $display_name:expr, $formula:expr) => {
+        impl_power_fractal!($struct_name, $display_name, $formula, None, false);
+    };
+    ($struct_name:ident, $display_name:expr, $formula:expr, $gpu_kernel:expr, $symmetric:expr) => {
         impl Fractal for $struct_name {
             fn name(&self) -> &str {
                 $display_name
             }
 
+            fn iteration_formula(&self) -> &str {
+                $formula
+            }
+
             fn parameters(&self) -> Vec<Parameter> {
                 vec![Parameter {
                     name: "power".to_string(),
@@ -140,8 +396,49 @@ macro_rules! impl_power_fractal {
                 self.compute_point(cx, cy, max_iter)
             }
 
-            fn compute_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
-                self.compute_point_full(cx, cy, max_iter)
+            fn compute_full_with(
+                &self,
+                cx: f64,
+                cy: f64,
+                max_iter: u32,
+                orbit_data: OrbitData,
+            ) -> FractalResult {
+                self.compute_point_full(cx, cy, max_iter, orbit_data)
+            }
+
+            fn compute_orbit(&self, cx: f64, cy: f64, max_iter: u32) -> Option<Vec<Complex64>> {
+                Some(trace_orbit(
+                    (0.0, 0.0),
+                    max_iter,
+                    BAILOUT_R2,
+                    |z_re, z_im, r2, i2| self.step(z_re, z_im, r2, i2, cx, cy),
+                ))
+            }
+
+            fn compute_orbit_hp(
+                &self,
+                x: &HpDecimal,
+                y: &HpDecimal,
+                max_iter: u32,
+            ) -> Option<Vec<Complex64>> {
+                if (self.power - 2.0).abs() >= POWER2_EPSILON {
+                    return None;
+                }
+                let c = (x.clone(), y.clone());
+                let z0 = (HpDecimal::default(), HpDecimal::default());
+                Some(trace_orbit_hp(&c, z0, max_iter, |z| self.step_hp(z, &c)))
+            }
+
+            fn gpu_kernel(&self) -> Option<GpuKernel> {
+                if (self.power - 2.0).abs() < POWER2_EPSILON {
+                    $gpu_kernel
+                } else {
+                    None
+                }
+            }
+
+            fn has_real_axis_symmetry(&self) -> bool {
+                $symmetric
             }
         }
     };
@@ -169,70 +466,57 @@ impl Default for Mandelbrot {
 }
 
 impl Mandelbrot {
+    /// One iteration: z^power + c
+    #[inline(always)]
+    fn step(&self, z_re: f64, z_im: f64, r2: f64, i2: f64, c_re: f64, c_im: f64) -> (f64, f64) {
+        power_step(z_re, z_im, r2, i2, c_re, c_im, self.power)
+    }
+
+    /// `step` at power 2 in decimal arithmetic
+    fn step_hp(&self, z: &HpPoint, c: &HpPoint) -> HpPoint {
+        square_add_hp(z, c)
+    }
+
     /// Computes iterations for a single point using De Moivre's theorem
     /// for arbitrary power exponentiation.
     fn compute_point(&self, cx: f64, cy: f64, max_iter: u32) -> u32 {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
-        let c_re = cx;
-        let c_im = cy;
-        let power = self.power;
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
+            if has_escaped(r2 + i2, BAILOUT_R2) {
                 return i;
             }
 
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                // Fast path for power=2: use direct algebraic formula
-                let new_re = r2 - i2 + c_re;
-                let new_im = 2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                // Use De Moivre's theorem: (r*e^(iθ))^power = r^power * e^(i*power*θ)
-                let angle = power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = radius * angle.cos() + c_re;
-                z_im = radius * angle.sin() + c_im;
-            }
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2, cx, cy);
         }
 
         max_iter
     }
 
     /// Full computation with orbit data for color processors
-    fn compute_point_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
+    fn compute_point_full(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
-        let c_re = cx;
-        let c_im = cy;
-        let power = self.power;
-        let mut orbit_data = OrbitData::new();
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
+            if has_escaped(r2 + i2, BAILOUT_R2) {
                 return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data);
             }
 
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                let new_re = r2 - i2 + c_re;
-                let new_im = 2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                let angle = power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = radius * angle.cos() + c_re;
-                z_im = radius * angle.sin() + c_im;
-            }
-
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2, cx, cy);
             orbit_data.update(Complex64::new(z_re, z_im));
         }
 
@@ -240,7 +524,13 @@ impl Mandelbrot {
     }
 }
 
-impl_power_fractal!(Mandelbrot, "Mandelbrot");
+impl_power_fractal!(
+    Mandelbrot,
+    "Mandelbrot",
+    "z ← z^power + c, z0 = 0, c = pixel; escape when |z| > 2",
+    Some(GpuKernel::Mandelbrot),
+    true
+);
 
 // ============================================================================
 // Julia Set
@@ -271,11 +561,23 @@ impl Default for Julia {
     }
 }
 
+impl Julia {
+    /// One iteration: z^power + c with the fixed c
+    #[inline(always)]
+    fn step(&self, z_re: f64, z_im: f64, r2: f64, i2: f64) -> (f64, f64) {
+        power_step(z_re, z_im, r2, i2, self.c_real, self.c_imag, self.power)
+    }
+}
+
 impl Fractal for Julia {
     fn name(&self) -> &str {
         "Julia"
     }
 
+    fn iteration_formula(&self) -> &str {
+        "z ← z^power + c, z0 = pixel, c fixed; escape when |z| > 2"
+    }
+
     fn parameters(&self) -> Vec<Parameter> {
         vec![
             Parameter {
@@ -320,67 +622,84 @@ impl Fractal for Julia {
     fn compute(&self, zx: f64, zy: f64, max_iter: u32) -> u32 {
         let mut z_re = zx;
         let mut z_im = zy;
-        let c_re = self.c_real;
-        let c_im = self.c_imag;
-        let power = self.power;
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
+            if has_escaped(r2 + i2, BAILOUT_R2) {
                 return i;
             }
 
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                let new_re = r2 - i2 + c_re;
-                let new_im = 2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                let angle = power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = radius * angle.cos() + c_re;
-                z_im = radius * angle.sin() + c_im;
-            }
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2);
         }
 
         max_iter
     }
 
-    fn compute_full(&self, zx: f64, zy: f64, max_iter: u32) -> FractalResult {
+    fn compute_full_with(
+        &self,
+        zx: f64,
+        zy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
         let mut z_re = zx;
         let mut z_im = zy;
-        let c_re = self.c_real;
-        let c_im = self.c_imag;
-        let power = self.power;
-        let mut orbit_data = OrbitData::new();
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
+            if has_escaped(r2 + i2, BAILOUT_R2) {
                 return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data);
             }
 
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                let new_re = r2 - i2 + c_re;
-                let new_im = 2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                let angle = power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = radius * angle.cos() + c_re;
-                z_im = radius * angle.sin() + c_im;
-            }
-
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2);
             orbit_data.update(Complex64::new(z_re, z_im));
         }
 
         FractalResult::inside_set(max_iter)
     }
+
+    fn compute_orbit(&self, zx: f64, zy: f64, max_iter: u32) -> Option<Vec<Complex64>> {
+        Some(trace_orbit(
+            (zx, zy),
+            max_iter,
+            BAILOUT_R2,
+            |z_re, z_im, r2, i2| self.step(z_re, z_im, r2, i2),
+        ))
+    }
+
+    fn compute_orbit_hp(
+        &self,
+        x: &HpDecimal,
+        y: &HpDecimal,
+        max_iter: u32,
+    ) -> Option<Vec<Complex64>> {
+        if (self.power - 2.0).abs() >= POWER2_EPSILON {
+            return None;
+        }
+        let c = (
+            HpDecimal::from_f64(self.c_real)?,
+            HpDecimal::from_f64(self.c_imag)?,
+        );
+        let z0 = (x.clone(), y.clone());
+        Some(trace_orbit_hp(&z0, z0.clone(), max_iter, |z| {
+            square_add_hp(z, &c)
+        }))
+    }
+
+    fn gpu_kernel(&self) -> Option<GpuKernel> {
+        ((self.power - 2.0).abs() < POWER2_EPSILON).then_some(GpuKernel::Julia {
+            c_re: self.c_real,
+            c_im: self.c_imag,
+        })
+    }
+
+    fn parameter_space_fractal(&self) -> Option<Box<dyn Fractal>> {
+        Some(Box::new(Mandelbrot { power: self.power }))
+    }
 }
 
 // ============================================================================
@@ -404,72 +723,69 @@ impl Default for BurningShip {
 }
 
 impl BurningShip {
+    /// One iteration: abs() on both components, then z^power + c
+    #[inline(always)]
+    fn step(&self, z_re: f64, z_im: f64, r2: f64, i2: f64, c_re: f64, c_im: f64) -> (f64, f64) {
+        // Burning Ship: apply abs BEFORE power transformation
+        let z_re = z_re.abs();
+        let z_im = z_im.abs();
+
+        if (self.power - 2.0).abs() < POWER2_EPSILON {
+            (r2 - i2 + c_re, 2.0 * z_re * z_im + c_im)
+        } else {
+            let angle = self.power * z_im.atan2(z_re);
+            let radius = (r2 + i2).powf(self.power / 2.0);
+            (radius * angle.cos() + c_re, radius * angle.sin() + c_im)
+        }
+    }
+
+    /// `step` at power 2 in decimal arithmetic
+    fn step_hp(&self, z: &HpPoint, c: &HpPoint) -> HpPoint {
+        square_add_hp(&(z.0.abs(), z.1.abs()), c)
+    }
+
     fn compute_point(&self, cx: f64, cy: f64, max_iter: u32) -> u32 {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
-        let c_re = cx;
-        let c_im = cy;
-        let power = self.power;
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
+            if has_escaped(r2 + i2, BAILOUT_R2) {
                 return i;
             }
 
-            // Burning Ship: apply abs BEFORE power transformation
-            z_re = z_re.abs();
-            z_im = z_im.abs();
-
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                let new_re = r2 - i2 + c_re;
-                let new_im = 2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                let angle = power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = radius * angle.cos() + c_re;
-                z_im = radius * angle.sin() + c_im;
-            }
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2, cx, cy);
         }
 
         max_iter
     }
 
-    fn compute_point_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
+    fn compute_point_full(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
-        let c_re = cx;
-        let c_im = cy;
-        let power = self.power;
-        let mut orbit_data = OrbitData::new();
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
-                return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data);
-            }
-
-            z_re = z_re.abs();
-            z_im = z_im.abs();
-
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                let new_re = r2 - i2 + c_re;
-                let new_im = 2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                let angle = power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = radius * angle.cos() + c_re;
-                z_im = radius * angle.sin() + c_im;
+            if has_escaped(r2 + i2, BAILOUT_R2) {
+                let smooth_escape =
+                    continue_escape(z_re, z_im, self.power, |z_re, z_im, r2, i2| {
+                        self.step(z_re, z_im, r2, i2, cx, cy)
+                    });
+                return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data)
+                    .with_smooth_escape(smooth_escape);
             }
 
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2, cx, cy);
             orbit_data.update(Complex64::new(z_re, z_im));
         }
 
@@ -477,7 +793,11 @@ impl BurningShip {
     }
 }
 
-impl_power_fractal!(BurningShip, "Burning Ship");
+impl_power_fractal!(
+    BurningShip,
+    "Burning Ship",
+    "z ← (|Re z| + i|Im z|)^power + c, z0 = 0, c = pixel; escape when |z| > 2"
+);
 
 // ============================================================================
 // Tricorn (Mandelbar)
@@ -500,67 +820,60 @@ impl Default for Tricorn {
 }
 
 impl Tricorn {
+    /// One iteration: conj(z)^power + c
+    #[inline(always)]
+    fn step(&self, z_re: f64, z_im: f64, r2: f64, i2: f64, c_re: f64, c_im: f64) -> (f64, f64) {
+        if (self.power - 2.0).abs() < POWER2_EPSILON {
+            (r2 - i2 + c_re, -2.0 * z_re * z_im + c_im)
+        } else {
+            let angle = -self.power * z_im.atan2(z_re);
+            let radius = (r2 + i2).powf(self.power / 2.0);
+            (radius * angle.cos() + c_re, radius * angle.sin() + c_im)
+        }
+    }
+
+    /// `step` at power 2 in decimal arithmetic: conj(z)² + c
+    fn step_hp(&self, z: &HpPoint, c: &HpPoint) -> HpPoint {
+        square_add_hp(&(z.0.clone(), z.1.neg()), c)
+    }
+
     fn compute_point(&self, cx: f64, cy: f64, max_iter: u32) -> u32 {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
-        let c_re = cx;
-        let c_im = cy;
-        let power = self.power;
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
+            if has_escaped(r2 + i2, BAILOUT_R2) {
                 return i;
             }
 
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                // Conjugation for power=2: conj(z)^2 = (a - bi)^2 = (a^2 - b^2) - 2abi
-                let new_re = r2 - i2 + c_re;
-                let new_im = -2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                // Conjugation: flip the sign of the imaginary component
-                let angle = -power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = radius * angle.cos() + c_re;
-                z_im = radius * angle.sin() + c_im;
-            }
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2, cx, cy);
         }
 
         max_iter
     }
 
-    fn compute_point_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
+    fn compute_point_full(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
-        let c_re = cx;
-        let c_im = cy;
-        let power = self.power;
-        let mut orbit_data = OrbitData::new();
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
+            if has_escaped(r2 + i2, BAILOUT_R2) {
                 return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data);
             }
 
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                let new_re = r2 - i2 + c_re;
-                let new_im = -2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                let angle = -power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = radius * angle.cos() + c_re;
-                z_im = radius * angle.sin() + c_im;
-            }
-
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2, cx, cy);
             orbit_data.update(Complex64::new(z_re, z_im));
         }
 
@@ -568,7 +881,13 @@ impl Tricorn {
     }
 }
 
-impl_power_fractal!(Tricorn, "Tricorn");
+impl_power_fractal!(
+    Tricorn,
+    "Tricorn",
+    "z ← conj(z)^power + c, z0 = 0, c = pixel; escape when |z| > 2",
+    None,
+    true
+);
 
 // ============================================================================
 // Celtic
@@ -593,68 +912,71 @@ impl Default for Celtic {
 }
 
 impl Celtic {
+    /// One iteration: z^power + c with abs() on the real part
+    #[inline(always)]
+    fn step(&self, z_re: f64, z_im: f64, r2: f64, i2: f64, c_re: f64, c_im: f64) -> (f64, f64) {
+        if (self.power - 2.0).abs() < POWER2_EPSILON {
+            // Celtic for power=2: standard z^2+c with abs on real part
+            ((r2 - i2 + c_re).abs(), 2.0 * z_re * z_im + c_im)
+        } else {
+            // General power via De Moivre, abs on real component
+            let angle = self.power * z_im.atan2(z_re);
+            let radius = (r2 + i2).powf(self.power / 2.0);
+            (
+                (radius * angle.cos() + c_re).abs(),
+                radius * angle.sin() + c_im,
+            )
+        }
+    }
+
+    /// `step` at power 2 in decimal arithmetic
+    fn step_hp(&self, z: &HpPoint, c: &HpPoint) -> HpPoint {
+        let (re, im) = square_add_hp(z, c);
+        (re.abs(), im)
+    }
+
     fn compute_point(&self, cx: f64, cy: f64, max_iter: u32) -> u32 {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
-        let c_re = cx;
-        let c_im = cy;
-        let power = self.power;
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
+            if has_escaped(r2 + i2, BAILOUT_R2) {
                 return i;
             }
 
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                // Celtic for power=2: standard z^2+c with abs on real part
-                // Must compute both from OLD z_re/z_im before assigning
-                let new_re = (r2 - i2 + c_re).abs();
-                let new_im = 2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                // General power via De Moivre, abs on real component
-                let angle = power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = (radius * angle.cos() + c_re).abs();
-                z_im = radius * angle.sin() + c_im;
-            }
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2, cx, cy);
         }
 
         max_iter
     }
 
-    fn compute_point_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
+    fn compute_point_full(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
-        let c_re = cx;
-        let c_im = cy;
-        let power = self.power;
-        let mut orbit_data = OrbitData::new();
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
-                return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data);
-            }
-
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                let new_re = (r2 - i2 + c_re).abs();
-                let new_im = 2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                let angle = power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = (radius * angle.cos() + c_re).abs();
-                z_im = radius * angle.sin() + c_im;
+            if has_escaped(r2 + i2, BAILOUT_R2) {
+                let smooth_escape =
+                    continue_escape(z_re, z_im, self.power, |z_re, z_im, r2, i2| {
+                        self.step(z_re, z_im, r2, i2, cx, cy)
+                    });
+                return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data)
+                    .with_smooth_escape(smooth_escape);
             }
 
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2, cx, cy);
             orbit_data.update(Complex64::new(z_re, z_im));
         }
 
@@ -662,7 +984,13 @@ impl Celtic {
     }
 }
 
-impl_power_fractal!(Celtic, "Celtic");
+impl_power_fractal!(
+    Celtic,
+    "Celtic",
+    "w = z^power + c, z ← |Re w| + i·Im w, z0 = 0, c = pixel; escape when |z| > 2",
+    None,
+    true
+);
 
 // ============================================================================
 // Newton's Method Fractal
@@ -684,61 +1012,81 @@ pub struct Newton {
     pub tolerance: f64,
 }
 
-impl Default for Newton {
-    fn default() -> Self {
-        Newton { tolerance: 0.001 }
+/// Convergence tolerance range shared by root-finding fractals
+const MIN_CONVERGENCE_TOLERANCE: f64 = 0.0001;
+const MAX_CONVERGENCE_TOLERANCE: f64 = 0.1;
+const DEFAULT_CONVERGENCE_TOLERANCE: f64 = 0.001;
+/// Derivative magnitude (squared) below which a Newton step is undefined
+const NEWTON_SINGULAR_DENOM: f64 = 1e-20;
+
+/// Roots of z^3 - 1 = 0 (cube roots of unity)
+const NEWTON_ROOTS: [(f64, f64); 3] = [
+    (1.0, 0.0),
+    (-0.5, 0.8660254037844386),  // sqrt(3)/2
+    (-0.5, -0.8660254037844386), // -sqrt(3)/2
+];
+
+/// The "tolerance" parameter, with the same range for every convergence
+/// fractal
+fn convergence_tolerance_parameter(value: f64) -> Parameter {
+    Parameter {
+        name: "tolerance".to_string(),
+        value,
+        min: MIN_CONVERGENCE_TOLERANCE,
+        max: MAX_CONVERGENCE_TOLERANCE,
     }
 }
 
-impl Fractal for Newton {
-    fn name(&self) -> &str {
-        "Newton"
-    }
+fn clamp_convergence_tolerance(value: f64) -> f64 {
+    value.clamp(MIN_CONVERGENCE_TOLERANCE, MAX_CONVERGENCE_TOLERANCE)
+}
 
-    fn parameters(&self) -> Vec<Parameter> {
-        vec![Parameter {
-            name: "tolerance".to_string(),
-            value: self.tolerance,
-            min: 0.0001,
-            max: 0.1,
-        }]
-    }
+/// Index of the root within `tolerance2` (squared distance) of z, if any
+#[inline(always)]
+fn converged_root(z_re: f64, z_im: f64, roots: &[(f64, f64)], tolerance2: f64) -> Option<usize> {
+    roots.iter().position(|&(root_re, root_im)| {
+        (z_re - root_re).powi(2) + (z_im - root_im).powi(2) < tolerance2
+    })
+}
 
-    fn set_parameter(&mut self, name: &str, value: f64) {
-        if name == "tolerance" {
-            self.tolerance = value.clamp(0.0001, 0.1);
-        }
-    }
+/// Outcome of a root-finding iteration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootConvergence {
+    /// Iterations taken to land within tolerance of the root
+    pub iterations: u32,
+    /// Index of the root reached
+    pub root: usize,
+}
 
-    fn get_parameter(&self, name: &str) -> Option<f64> {
-        match name {
-            "tolerance" => Some(self.tolerance),
-            _ => None,
+impl Default for Newton {
+    fn default() -> Self {
+        Newton {
+            tolerance: DEFAULT_CONVERGENCE_TOLERANCE,
         }
     }
+}
 
-    fn compute(&self, cx: f64, cy: f64, max_iter: u32) -> u32 {
+impl Newton {
+    /// Run Newton's method from z = cx + cy*i. Returns the root reached (if
+    /// any) and the final z, recording the orbit into `orbit_data` when given.
+    fn iterate(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: Option<&mut OrbitData>,
+    ) -> (Option<RootConvergence>, Complex64) {
         let mut z_re = cx;
         let mut z_im = cy;
-        let tolerance = self.tolerance;
-        let tolerance2 = tolerance * tolerance;
-
-        // Pre-computed roots of z^3 - 1 = 0 (cube roots of unity)
-        let root1_re = 1.0;
-        let root1_im = 0.0;
-        let root2_re = -0.5;
-        let root2_im = 0.8660254037844386; // sqrt(3)/2
-        let root3_re = -0.5;
-        let root3_im = -0.8660254037844386; // -sqrt(3)/2
+        let tolerance2 = self.tolerance * self.tolerance;
 
         for i in 0..max_iter {
-            // Check convergence to any root using squared distance (faster)
-            let dist2_1 = (z_re - root1_re).powi(2) + (z_im - root1_im).powi(2);
-            let dist2_2 = (z_re - root2_re).powi(2) + (z_im - root2_im).powi(2);
-            let dist2_3 = (z_re - root3_re).powi(2) + (z_im - root3_im).powi(2);
-
-            if dist2_1 < tolerance2 || dist2_2 < tolerance2 || dist2_3 < tolerance2 {
-                return max_iter - i; // Converged - return high iteration count
+            if let Some(root) = converged_root(z_re, z_im, &NEWTON_ROOTS, tolerance2) {
+                let convergence = RootConvergence {
+                    iterations: i,
+                    root,
+                };
+                return (Some(convergence), Complex64::new(z_re, z_im));
             }
 
             // Compute z^2 and z^3 for Newton's method
@@ -749,75 +1097,81 @@ impl Fractal for Newton {
 
             let f_real = z_re3 - 1.0;
             let f_imag = z_im3;
-
             let deriv_real = 3.0 * (z_re2 - z_im2);
             let deriv_imag = 6.0 * z_re * z_im;
 
             let denom = deriv_real * deriv_real + deriv_imag * deriv_imag;
-            if denom.abs() < 1e-20 {
+            if denom.abs() < NEWTON_SINGULAR_DENOM {
                 break;
             }
 
-            let new_re = z_re - (f_real * deriv_real + f_imag * deriv_imag) / denom;
-            let new_im = z_im - (f_imag * deriv_real - f_real * deriv_imag) / denom;
+            z_re -= (f_real * deriv_real + f_imag * deriv_imag) / denom;
+            z_im -= (f_imag * deriv_real - f_real * deriv_imag) / denom;
 
-            z_re = new_re;
-            z_im = new_im;
+            if let Some(orbit) = orbit_data.as_deref_mut() {
+                orbit.update(Complex64::new(z_re, z_im));
+            }
         }
 
-        max_iter // Did not converge to a root
+        (None, Complex64::new(z_re, z_im))
     }
 
-    fn compute_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
-        let mut z_re = cx;
-        let mut z_im = cy;
-        let tolerance = self.tolerance;
-        let tolerance2 = tolerance * tolerance;
-        let mut orbit_data = OrbitData::new();
-
-        let root1_re = 1.0;
-        let root1_im = 0.0;
-        let root2_re = -0.5;
-        let root2_im = 0.8660254037844386;
-        let root3_re = -0.5;
-        let root3_im = -0.8660254037844386;
+    /// Iterations to converge and which root is reached, or None if the
+    /// point does not converge within `max_iter`
+    pub fn converge(&self, cx: f64, cy: f64, max_iter: u32) -> Option<RootConvergence> {
+        self.iterate(cx, cy, max_iter, None).0
+    }
+}
 
-        for i in 0..max_iter {
-            let dist2_1 = (z_re - root1_re).powi(2) + (z_im - root1_im).powi(2);
-            let dist2_2 = (z_re - root2_re).powi(2) + (z_im - root2_im).powi(2);
-            let dist2_3 = (z_re - root3_re).powi(2) + (z_im - root3_im).powi(2);
-
-            if dist2_1 < tolerance2 || dist2_2 < tolerance2 || dist2_3 < tolerance2 {
-                // Converged - treat as "escaped" for coloring purposes with inverted count
-                return FractalResult::escaped(
-                    max_iter - i,
-                    Complex64::new(z_re, z_im),
-                    orbit_data,
-                );
-            }
+impl Fractal for Newton {
+    fn name(&self) -> &str {
+        "Newton"
+    }
 
-            let z_re2 = z_re * z_re;
-            let z_im2 = z_im * z_im;
-            let z_re3 = z_re2 * z_re - 3.0 * z_re * z_im2;
-            let z_im3 = 3.0 * z_re2 * z_im - z_im2 * z_im;
+    fn iteration_formula(&self) -> &str {
+        "z ← z - (z³ - 1) / (3z²), z0 = pixel; stop when z is within tolerance of a root"
+    }
 
-            let f_real = z_re3 - 1.0;
-            let f_imag = z_im3;
-            let deriv_real = 3.0 * (z_re2 - z_im2);
-            let deriv_imag = 6.0 * z_re * z_im;
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![convergence_tolerance_parameter(self.tolerance)]
+    }
 
-            let denom = deriv_real * deriv_real + deriv_imag * deriv_imag;
-            if denom.abs() < 1e-20 {
-                break;
-            }
+    fn set_parameter(&mut self, name: &str, value: f64) {
+        if name == "tolerance" {
+            self.tolerance = clamp_convergence_tolerance(value);
+        }
+    }
 
-            z_re -= (f_real * deriv_real + f_imag * deriv_imag) / denom;
-            z_im -= (f_imag * deriv_real - f_real * deriv_imag) / denom;
+    fn get_parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "tolerance" => Some(self.tolerance),
+            _ => None,
+        }
+    }
 
-            orbit_data.update(Complex64::new(z_re, z_im));
+    fn compute(&self, cx: f64, cy: f64, max_iter: u32) -> u32 {
+        match self.converge(cx, cy, max_iter) {
+            // Converged - return high iteration count for early convergence
+            Some(convergence) => max_iter - convergence.iterations,
+            None => max_iter, // Did not converge to a root
         }
+    }
 
-        FractalResult::inside_set(max_iter)
+    fn compute_full_with(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
+        match self.iterate(cx, cy, max_iter, Some(&mut orbit_data)) {
+            // Converged - treat as "escaped" for coloring purposes with inverted count
+            (Some(convergence), z) => {
+                FractalResult::escaped(max_iter - convergence.iterations, z, orbit_data)
+                    .with_root(convergence.root)
+            }
+            (None, _) => FractalResult::inside_set(max_iter),
+        }
     }
 }
 
@@ -825,18 +1179,25 @@ impl Fractal for Newton {
 // Biomorph
 // ============================================================================
 
+const MIN_BIOMORPH_BAILOUT: f64 = 2.0;
+const MAX_BIOMORPH_BAILOUT: f64 = 1e10;
+const DEFAULT_BIOMORPH_BAILOUT: f64 = 1e6;
+
 /// Biomorph fractal (Clifford Pickover).
 ///
 /// Uses the standard Mandelbrot-type iteration z = z^power + c, but with
 /// a different escape test: a point is considered "inside" if EITHER
 ///   |Re(z)| < escape_radius  OR  |Im(z)| < escape_radius
-/// after the final iteration.
+/// once |z| exceeds the (much larger) overflow `bailout`, or after the final
+/// iteration. Keeping the two radii separate, as classic renderers do, lets
+/// orbits run far enough for the component test to pick out thin filaments.
 ///
 /// This produces organic, biological-looking structures (amoebas,
 /// microorganisms) hence the name "biomorph."
 pub struct Biomorph {
     pub power: f64,
     pub escape_radius: f64,
+    pub bailout: f64,
 }
 
 impl Default for Biomorph {
@@ -844,6 +1205,7 @@ impl Default for Biomorph {
         Biomorph {
             power: 3.0,
             escape_radius: 10.0,
+            bailout: DEFAULT_BIOMORPH_BAILOUT,
         }
     }
 }
@@ -853,6 +1215,10 @@ impl Fractal for Biomorph {
         "Biomorph"
     }
 
+    fn iteration_formula(&self) -> &str {
+        "z ← z^power + c, z0 = 0, c = pixel; once |z| > bailout, inside if |Re z| or |Im z| < escape radius"
+    }
+
     fn parameters(&self) -> Vec<Parameter> {
         vec![
             Parameter {
@@ -867,13 +1233,29 @@ impl Fractal for Biomorph {
                 min: 2.0,
                 max: 100.0,
             },
+            Parameter {
+                name: "bailout".to_string(),
+                value: self.bailout,
+                min: self.escape_radius.max(MIN_BIOMORPH_BAILOUT),
+                max: MAX_BIOMORPH_BAILOUT,
+            },
         ]
     }
 
     fn set_parameter(&mut self, name: &str, value: f64) {
         match name {
             "power" => self.power = value.clamp(2.0, 8.0),
-            "escape_radius" => self.escape_radius = value.clamp(2.0, 100.0),
+            // The bailout never drops below the escape radius, or the
+            // component test would run on orbits that have not escaped
+            "escape_radius" => {
+                self.escape_radius = value.clamp(2.0, 100.0);
+                self.bailout = self.bailout.max(self.escape_radius);
+            }
+            "bailout" => {
+                self.bailout = value
+                    .clamp(MIN_BIOMORPH_BAILOUT, MAX_BIOMORPH_BAILOUT)
+                    .max(self.escape_radius)
+            }
             _ => {}
         }
     }
@@ -882,6 +1264,7 @@ impl Fractal for Biomorph {
         match name {
             "power" => Some(self.power),
             "escape_radius" => Some(self.escape_radius),
+            "bailout" => Some(self.bailout),
             _ => None,
         }
     }
@@ -893,14 +1276,14 @@ impl Fractal for Biomorph {
         let c_im = cy;
         let power = self.power;
         let big_r = self.escape_radius;
-        let big_r2 = big_r * big_r;
+        let bailout2 = self.bailout * self.bailout;
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            // Standard bailout to prevent overflow
-            if r2 + i2 > big_r2 {
+            // Overflow bailout, independent of the biomorph test radius
+            if has_escaped(r2 + i2, bailout2) {
                 // Pickover biomorph test: if either component is still small, it's "inside"
                 if z_re.abs() < big_r || z_im.abs() < big_r {
                     return max_iter - i; // Biomorph region -- high iteration count
@@ -909,17 +1292,7 @@ impl Fractal for Biomorph {
             }
 
             // z = z^power + c
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                let new_re = r2 - i2 + c_re;
-                let new_im = 2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                let angle = power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = radius * angle.cos() + c_re;
-                z_im = radius * angle.sin() + c_im;
-            }
+            (z_re, z_im) = power_step(z_re, z_im, r2, i2, c_re, c_im, power);
         }
 
         // After all iterations: apply biomorph test on final z
@@ -930,21 +1303,26 @@ impl Fractal for Biomorph {
         }
     }
 
-    fn compute_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
+    fn compute_full_with(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
         let mut z_re = 0.0_f64;
         let mut z_im = 0.0_f64;
         let c_re = cx;
         let c_im = cy;
         let power = self.power;
         let big_r = self.escape_radius;
-        let big_r2 = big_r * big_r;
-        let mut orbit_data = OrbitData::new();
+        let bailout2 = self.bailout * self.bailout;
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > big_r2 {
+            if has_escaped(r2 + i2, bailout2) {
                 if z_re.abs() < big_r || z_im.abs() < big_r {
                     return FractalResult::escaped(
                         max_iter - i,
@@ -955,17 +1333,7 @@ impl Fractal for Biomorph {
                 return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data);
             }
 
-            if (power - 2.0).abs() < POWER2_EPSILON {
-                let new_re = r2 - i2 + c_re;
-                let new_im = 2.0 * z_re * z_im + c_im;
-                z_re = new_re;
-                z_im = new_im;
-            } else {
-                let angle = power * z_im.atan2(z_re);
-                let radius = (r2 + i2).powf(power / 2.0);
-                z_re = radius * angle.cos() + c_re;
-                z_im = radius * angle.sin() + c_im;
-            }
+            (z_re, z_im) = power_step(z_re, z_im, r2, i2, c_re, c_im, power);
 
             orbit_data.update(Complex64::new(z_re, z_im));
         }
@@ -985,15 +1353,20 @@ impl Fractal for Biomorph {
 /// Phoenix fractal (Shigehiro Ushiki, 1988).
 ///
 /// A Julia-type iteration with a memory term feeding back the previous z:
-///   z_{n+1} = z_n^2 + c + p * z_{n-1}
+///   z_{n+1} = z_n^power + c + p * z_{n-1}
 /// where c is a fixed complex constant and p is the "memory" coefficient.
 ///
-/// The classic Ushiki Phoenix uses c = 0.5667, p = -0.5 which produces
-/// the iconic phoenix-shaped connected Julia set.
+/// With `conjugate` set, z_n is conjugated before the power is taken, as in
+/// the Tricorn: z_{n+1} = conj(z_n)^power + c + p * z_{n-1}.
+///
+/// The classic Ushiki Phoenix uses power = 2, c = 0.5667, p = -0.5 which
+/// produces the iconic phoenix-shaped connected Julia set.
 pub struct Phoenix {
     pub c_real: f64,
     pub c_imag: f64,
     pub memory: f64,
+    pub power: f64,
+    pub conjugate: bool,
 }
 
 impl Default for Phoenix {
@@ -1002,6 +1375,8 @@ impl Default for Phoenix {
             c_real: 0.5667,
             c_imag: 0.0,
             memory: -0.5,
+            power: 2.0,
+            conjugate: false,
         }
     }
 }
@@ -1011,6 +1386,10 @@ impl Fractal for Phoenix {
         "Phoenix"
     }
 
+    fn iteration_formula(&self) -> &str {
+        "z ← z^power + c + memory·z_prev (conj(z) with conjugate), z0 = pixel, c fixed; escape when |z| > 2"
+    }
+
     fn parameters(&self) -> Vec<Parameter> {
         vec![
             Parameter {
@@ -1031,6 +1410,18 @@ impl Fractal for Phoenix {
                 min: -1.0,
                 max: 1.0,
             },
+            Parameter {
+                name: "power".to_string(),
+                value: self.power,
+                min: 1.0,
+                max: 8.0,
+            },
+            Parameter {
+                name: "conjugate".to_string(),
+                value: if self.conjugate { 1.0 } else { 0.0 },
+                min: 0.0,
+                max: 1.0,
+            },
         ]
     }
 
@@ -1039,6 +1430,8 @@ impl Fractal for Phoenix {
             "c_real" => self.c_real = value.clamp(-2.0, 2.0),
             "c_imag" => self.c_imag = value.clamp(-2.0, 2.0),
             "memory" => self.memory = value.clamp(-1.0, 1.0),
+            "power" => self.power = value.clamp(1.0, 8.0),
+            "conjugate" => self.conjugate = value >= 0.5,
             _ => {}
         }
     }
@@ -1048,70 +1441,158 @@ impl Fractal for Phoenix {
             "c_real" => Some(self.c_real),
             "c_imag" => Some(self.c_imag),
             "memory" => Some(self.memory),
+            "power" => Some(self.power),
+            "conjugate" => Some(if self.conjugate { 1.0 } else { 0.0 }),
             _ => None,
         }
     }
 
     fn compute(&self, cx: f64, cy: f64, max_iter: u32) -> u32 {
-        let mut z_re = cx;
-        let mut z_im = cy;
+        self.iterate(cx, cy, self.c_real, self.c_imag, max_iter, None)
+            .0
+    }
+
+    fn compute_full_with(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
+        match self.iterate(
+            cx,
+            cy,
+            self.c_real,
+            self.c_imag,
+            max_iter,
+            Some(&mut orbit_data),
+        ) {
+            (i, Some(z)) => FractalResult::escaped(i, z, orbit_data),
+            (i, None) => FractalResult::inside_set(i),
+        }
+    }
+
+    fn parameter_space_fractal(&self) -> Option<Box<dyn Fractal>> {
+        Some(Box::new(PhoenixParameterSpace {
+            inner: Phoenix {
+                c_real: 0.0,
+                c_imag: 0.0,
+                ..*self
+            },
+        }))
+    }
+}
+
+impl Phoenix {
+    /// Iterate from z0 = z (with z_{-1} = 0) under the constant c. Returns the
+    /// escape iteration and final z, or (max_iter, None) if bounded; records
+    /// the orbit into `orbit_data` when given.
+    fn iterate(
+        &self,
+        z_re: f64,
+        z_im: f64,
+        c_re: f64,
+        c_im: f64,
+        max_iter: u32,
+        mut orbit_data: Option<&mut OrbitData>,
+    ) -> (u32, Option<Complex64>) {
+        let (mut z_re, mut z_im) = (z_re, z_im);
         let mut z_prev_re = 0.0;
         let mut z_prev_im = 0.0;
-        let c_re = self.c_real;
-        let c_im = self.c_imag;
         let p = self.memory;
+        let power = self.power;
+        // Conjugating z flips the sign of its imaginary part
+        let im_sign = if self.conjugate { -1.0 } else { 1.0 };
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
             let i2 = z_im * z_im;
 
-            if r2 + i2 > BAILOUT_R2 {
-                return i;
+            if has_escaped(r2 + i2, BAILOUT_R2) {
+                return (i, Some(Complex64::new(z_re, z_im)));
             }
 
-            // z^2 + c + p * z_prev
-            let new_re = r2 - i2 + c_re + p * z_prev_re;
-            let new_im = 2.0 * z_re * z_im + c_im + p * z_prev_im;
+            // z^power + c + p * z_prev (z conjugated first if enabled)
+            let (pow_re, pow_im) = if (power - 2.0).abs() < POWER2_EPSILON {
+                (r2 - i2, im_sign * 2.0 * z_re * z_im)
+            } else {
+                let angle = power * (im_sign * z_im).atan2(z_re);
+                let radius = (r2 + i2).powf(power / 2.0);
+                (radius * angle.cos(), radius * angle.sin())
+            };
+            let new_re = pow_re + c_re + p * z_prev_re;
+            let new_im = pow_im + c_im + p * z_prev_im;
 
             z_prev_re = z_re;
             z_prev_im = z_im;
             z_re = new_re;
             z_im = new_im;
+
+            if let Some(orbit_data) = orbit_data.as_deref_mut() {
+                orbit_data.update(Complex64::new(z_re, z_im));
+            }
         }
 
-        max_iter
+        (max_iter, None)
     }
+}
 
-    fn compute_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
-        let mut z_re = cx;
-        let mut z_im = cy;
-        let mut z_prev_re = 0.0;
-        let mut z_prev_im = 0.0;
-        let c_re = self.c_real;
-        let c_im = self.c_imag;
-        let p = self.memory;
-        let mut orbit_data = OrbitData::new();
+/// The Phoenix c-plane: each point is a value of c, iterated from the
+/// critical start z0 = 0 with the Phoenix memory and power. Bounded points
+/// give connected Phoenix Julia sets. Not a registry type; it backs the
+/// Phoenix parameter map.
+pub struct PhoenixParameterSpace {
+    inner: Phoenix,
+}
 
-        for i in 0..max_iter {
-            let r2 = z_re * z_re;
-            let i2 = z_im * z_im;
+impl Fractal for PhoenixParameterSpace {
+    fn name(&self) -> &str {
+        "Phoenix Parameter Space"
+    }
 
-            if r2 + i2 > BAILOUT_R2 {
-                return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data);
-            }
+    fn iteration_formula(&self) -> &str {
+        "z ← z^power + c + memory·z_prev (conj(z) with conjugate), z0 = 0, c = pixel; escape when |z| > 2"
+    }
 
-            let new_re = r2 - i2 + c_re + p * z_prev_re;
-            let new_im = 2.0 * z_re * z_im + c_im + p * z_prev_im;
+    fn parameters(&self) -> Vec<Parameter> {
+        self.inner
+            .parameters()
+            .into_iter()
+            .filter(|param| matches!(param.name.as_str(), "memory" | "power" | "conjugate"))
+            .collect()
+    }
 
-            z_prev_re = z_re;
-            z_prev_im = z_im;
-            z_re = new_re;
-            z_im = new_im;
+    fn set_parameter(&mut self, name: &str, value: f64) {
+        if matches!(name, "memory" | "power" | "conjugate") {
+            self.inner.set_parameter(name, value);
+        }
+    }
 
-            orbit_data.update(Complex64::new(z_re, z_im));
+    fn get_parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "memory" | "power" | "conjugate" => self.inner.get_parameter(name),
+            _ => None,
         }
+    }
 
-        FractalResult::inside_set(max_iter)
+    fn compute(&self, cx: f64, cy: f64, max_iter: u32) -> u32 {
+        self.inner.iterate(0.0, 0.0, cx, cy, max_iter, None).0
+    }
+
+    fn compute_full_with(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
+        match self
+            .inner
+            .iterate(0.0, 0.0, cx, cy, max_iter, Some(&mut orbit_data))
+        {
+            (i, Some(z)) => FractalResult::escaped(i, z, orbit_data),
+            (i, None) => FractalResult::inside_set(i),
+        }
     }
 }
 
@@ -1146,6 +1627,10 @@ impl Fractal for Multibrot {
         "Multibrot"
     }
 
+    fn iteration_formula(&self) -> &str {
+        "z ← z^power + c, z0 = 0, c = pixel; escape when |z| > 2"
+    }
+
     fn parameters(&self) -> Vec<Parameter> {
         vec![Parameter {
             name: "power".to_string(),
@@ -1172,8 +1657,31 @@ impl Fractal for Multibrot {
         self.inner.compute_point(cx, cy, max_iter)
     }
 
-    fn compute_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
-        self.inner.compute_point_full(cx, cy, max_iter)
+    fn compute_full_with(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        orbit_data: OrbitData,
+    ) -> FractalResult {
+        self.inner.compute_point_full(cx, cy, max_iter, orbit_data)
+    }
+
+    fn has_real_axis_symmetry(&self) -> bool {
+        true
+    }
+
+    fn compute_orbit(&self, cx: f64, cy: f64, max_iter: u32) -> Option<Vec<Complex64>> {
+        self.inner.compute_orbit(cx, cy, max_iter)
+    }
+
+    fn compute_orbit_hp(
+        &self,
+        x: &HpDecimal,
+        y: &HpDecimal,
+        max_iter: u32,
+    ) -> Option<Vec<Complex64>> {
+        self.inner.compute_orbit_hp(x, y, max_iter)
     }
 }
 
@@ -1185,16 +1693,37 @@ impl Fractal for Multibrot {
 ///
 /// A Mandelbrot variant where the c parameter also evolves each iteration:
 ///   z_{n+1} = z_n^2 + c_n
-///   c_{n+1} = c_n / 2 + z_{n+1}
-/// with z_0 = 0, c_0 = pixel coordinate.
+///   c_{n+1} = feedback * c_n + sign * z_{n+1}
+/// with z_0 = 0, c_0 = pixel coordinate. The classic Spider uses
+/// feedback = 0.5 and sign = +1; sign = -1 gives the subtractive
+/// "anti-spider", and values in between scale the z term continuously.
 ///
 /// The evolving c creates distinctive spiderweb-like filaments radiating
 /// from the main body of the set.
-pub struct Spider;
+pub struct Spider {
+    pub feedback: f64,
+    /// Coefficient of z in the c update, -1 to +1
+    pub sign: f64,
+}
 
 impl Default for Spider {
     fn default() -> Self {
-        Spider
+        Spider {
+            feedback: 0.5,
+            sign: 1.0,
+        }
+    }
+}
+
+impl Spider {
+    /// One iteration: z ← z² + c, then c ← feedback·c + sign·z. Returns the
+    /// new z and updates c in place.
+    #[inline(always)]
+    fn step(&self, z_re: f64, z_im: f64, r2: f64, i2: f64, c: &mut (f64, f64)) -> (f64, f64) {
+        let z = square_add(z_re, z_im, r2, i2, c.0, c.1);
+        c.0 = self.feedback * c.0 + self.sign * z.0;
+        c.1 = self.feedback * c.1 + self.sign * z.1;
+        z
     }
 }
 
@@ -1203,21 +1732,47 @@ impl Fractal for Spider {
         "Spider"
     }
 
+    fn iteration_formula(&self) -> &str {
+        "z ← z² + c, then c ← feedback·c + sign·z; z0 = 0, c0 = pixel; escape when |z| > 2"
+    }
+
     fn parameters(&self) -> Vec<Parameter> {
-        vec![]
+        vec![
+            Parameter {
+                name: "feedback".to_string(),
+                value: self.feedback,
+                min: 0.0,
+                max: 1.0,
+            },
+            Parameter {
+                name: "sign".to_string(),
+                value: self.sign,
+                min: -1.0,
+                max: 1.0,
+            },
+        ]
     }
 
-    fn set_parameter(&mut self, _name: &str, _value: f64) {}
+    fn set_parameter(&mut self, name: &str, value: f64) {
+        match name {
+            "feedback" => self.feedback = value.clamp(0.0, 1.0),
+            "sign" => self.sign = value.clamp(-1.0, 1.0),
+            _ => {}
+        }
+    }
 
-    fn get_parameter(&self, _name: &str) -> Option<f64> {
-        None
+    fn get_parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "feedback" => Some(self.feedback),
+            "sign" => Some(self.sign),
+            _ => None,
+        }
     }
 
     fn compute(&self, cx: f64, cy: f64, max_iter: u32) -> u32 {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
-        let mut c_re = cx;
-        let mut c_im = cy;
+        let mut c = (cx, cy);
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
@@ -1227,26 +1782,22 @@ impl Fractal for Spider {
                 return i;
             }
 
-            // z = z^2 + c
-            let new_z_re = r2 - i2 + c_re;
-            let new_z_im = 2.0 * z_re * z_im + c_im;
-            z_re = new_z_re;
-            z_im = new_z_im;
-
-            // c = c/2 + z
-            c_re = c_re / 2.0 + z_re;
-            c_im = c_im / 2.0 + z_im;
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2, &mut c);
         }
 
         max_iter
     }
 
-    fn compute_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
+    fn compute_full_with(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
-        let mut c_re = cx;
-        let mut c_im = cy;
-        let mut orbit_data = OrbitData::new();
+        let mut c = (cx, cy);
 
         for i in 0..max_iter {
             let r2 = z_re * z_re;
@@ -1256,19 +1807,26 @@ impl Fractal for Spider {
                 return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data);
             }
 
-            let new_z_re = r2 - i2 + c_re;
-            let new_z_im = 2.0 * z_re * z_im + c_im;
-            z_re = new_z_re;
-            z_im = new_z_im;
-
-            c_re = c_re / 2.0 + z_re;
-            c_im = c_im / 2.0 + z_im;
-
+            (z_re, z_im) = self.step(z_re, z_im, r2, i2, &mut c);
             orbit_data.update(Complex64::new(z_re, z_im));
         }
 
         FractalResult::inside_set(max_iter)
     }
+
+    fn has_real_axis_symmetry(&self) -> bool {
+        true
+    }
+
+    fn compute_orbit(&self, cx: f64, cy: f64, max_iter: u32) -> Option<Vec<Complex64>> {
+        let mut c = (cx, cy);
+        Some(trace_orbit(
+            (0.0, 0.0),
+            max_iter,
+            BAILOUT_R2,
+            |z_re, z_im, r2, i2| self.step(z_re, z_im, r2, i2, &mut c),
+        ))
+    }
 }
 
 // ============================================================================
@@ -1299,6 +1857,10 @@ impl Fractal for OrbitTrap {
         "Orbit Trap"
     }
 
+    fn iteration_formula(&self) -> &str {
+        "z ← z² + c, z0 = 0, c = pixel; escape when |z| > 2, shaded by the closest approach to the trap point"
+    }
+
     fn parameters(&self) -> Vec<Parameter> {
         vec![
             Parameter {
@@ -1357,21 +1919,23 @@ impl Fractal for OrbitTrap {
                 min_distance_sq = dist_sq;
             }
 
-            let new_re = r2 - i2 + c_re;
-            let new_im = 2.0 * z_re * z_im + c_im;
-            z_re = new_re;
-            z_im = new_im;
+            (z_re, z_im) = square_add(z_re, z_im, r2, i2, c_re, c_im);
         }
 
         max_iter
     }
 
-    fn compute_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
+    fn compute_full_with(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
         let mut z_re: f64 = 0.0;
         let mut z_im: f64 = 0.0;
         let c_re = cx;
         let c_im = cy;
-        let mut orbit_data = OrbitData::new();
         let mut min_distance_sq = f64::MAX;
 
         for _i in 0..max_iter {
@@ -1392,16 +1956,22 @@ impl Fractal for OrbitTrap {
                 min_distance_sq = dist_sq;
             }
 
-            let new_re = r2 - i2 + c_re;
-            let new_im = 2.0 * z_re * z_im + c_im;
-            z_re = new_re;
-            z_im = new_im;
+            (z_re, z_im) = square_add(z_re, z_im, r2, i2, c_re, c_im);
 
             orbit_data.update(Complex64::new(z_re, z_im));
         }
 
         FractalResult::inside_set(max_iter)
     }
+
+    fn compute_orbit(&self, cx: f64, cy: f64, max_iter: u32) -> Option<Vec<Complex64>> {
+        Some(trace_orbit(
+            (0.0, 0.0),
+            max_iter,
+            BAILOUT_R2,
+            |z_re, z_im, r2, i2| square_add(z_re, z_im, r2, i2, cx, cy),
+        ))
+    }
 }
 
 // ============================================================================
@@ -1432,6 +2002,10 @@ impl Fractal for PickoverStalk {
         "Pickover Stalk"
     }
 
+    fn iteration_formula(&self) -> &str {
+        "z ← z² + c, z0 = c = pixel; escape when |z| > 2, shaded by the closest approach to the axes"
+    }
+
     fn parameters(&self) -> Vec<Parameter> {
         vec![
             Parameter {
@@ -1492,21 +2066,23 @@ impl Fractal for PickoverStalk {
                 trap_distance = smallest_distance;
             }
 
-            let new_re = r2 - i2 + c_re;
-            let new_im = 2.0 * z_re * z_im + c_im;
-            z_re = new_re;
-            z_im = new_im;
+            (z_re, z_im) = square_add(z_re, z_im, r2, i2, c_re, c_im);
         }
 
         max_iter
     }
 
-    fn compute_full(&self, cx: f64, cy: f64, max_iter: u32) -> FractalResult {
+    fn compute_full_with(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
         let c_re = cx;
         let c_im = cy;
         let mut z_re = cx;
         let mut z_im = cy;
-        let mut orbit_data = OrbitData::new();
         let mut trap_distance = f64::MAX;
 
         for iteration in 0..max_iter {
@@ -1518,6 +2094,9 @@ impl Fractal for PickoverStalk {
                 let stalk_brightness = 1.0 / (1.0 + normalized * self.stalk_intensity);
                 let final_value = (stalk_brightness * iteration as f64) as u32;
                 let iters = final_value.max(1);
+                // The quantized count loses the distance; keep it for the
+                // orbit-trap processors
+                orbit_data.stalk_distance = Some(trap_distance);
                 return FractalResult::escaped(iters, Complex64::new(z_re, z_im), orbit_data);
             }
 
@@ -1529,16 +2108,430 @@ impl Fractal for PickoverStalk {
                 trap_distance = smallest_distance;
             }
 
-            let new_re = r2 - i2 + c_re;
-            let new_im = 2.0 * z_re * z_im + c_im;
-            z_re = new_re;
-            z_im = new_im;
+            (z_re, z_im) = square_add(z_re, z_im, r2, i2, c_re, c_im);
 
             orbit_data.update(Complex64::new(z_re, z_im));
         }
 
         FractalResult::inside_set(max_iter)
     }
+
+    fn has_real_axis_symmetry(&self) -> bool {
+        true
+    }
+
+    fn compute_orbit(&self, cx: f64, cy: f64, max_iter: u32) -> Option<Vec<Complex64>> {
+        Some(trace_orbit(
+            (cx, cy),
+            max_iter,
+            BAILOUT_R2,
+            |z_re, z_im, r2, i2| square_add(z_re, z_im, r2, i2, cx, cy),
+        ))
+    }
+}
+
+// ============================================================================
+// Polynomial Julia
+// ============================================================================
+
+/// Highest polynomial degree the coefficient editor offers
+const MAX_POLYNOMIAL_DEGREE: usize = 8;
+/// Coefficient components are edited within ±this
+const POLYNOMIAL_COEFFICIENT_RANGE: f64 = 2.0;
+/// Smallest escape radius, well past the usual 2 so smooth coloring has
+/// room to settle
+const POLYNOMIAL_MIN_BAILOUT: f64 = 10.0;
+/// Cap on the derived escape radius for near-zero leading coefficients
+const POLYNOMIAL_MAX_BAILOUT: f64 = 1e10;
+
+/// Julia sets of an arbitrary polynomial: z_{n+1} = P(z_n) + c with
+///   P(z) = a_0 + a_1*z + ... + a_d*z^d
+/// evaluated by Horner's method. z0 is the pixel coordinate and c is fixed.
+///
+/// Parameters are dynamic: "degree" picks d, and each active coefficient
+/// a_k is exposed as "a{k}_re" / "a{k}_im". Coefficients above the degree
+/// are kept (but unused) so lowering and raising the degree, or restoring
+/// parameters in any order, doesn't lose them.
+pub struct PolynomialJulia {
+    /// a_0..=a_MAX_POLYNOMIAL_DEGREE, lowest degree first
+    pub coefficients: Vec<Complex64>,
+    pub degree: usize,
+    pub c_real: f64,
+    pub c_imag: f64,
+    /// Squared escape radius for the current coefficients and c
+    bailout_r2: f64,
+    /// Iteration formula quoting the escape radius
+    formula: String,
+}
+
+impl Default for PolynomialJulia {
+    /// z^3 - 0.5z + c, a cubic with two critical points
+    fn default() -> Self {
+        PolynomialJulia::new(
+            vec![
+                Complex64::new(0.0, 0.0),
+                Complex64::new(-0.5, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(1.0, 0.0),
+            ],
+            0.05,
+            0.78,
+        )
+    }
+}
+
+impl PolynomialJulia {
+    /// Polynomial with the given coefficients (lowest degree first; at most
+    /// MAX_POLYNOMIAL_DEGREE + 1 are used) and constant c
+    pub fn new(coefficients: Vec<Complex64>, c_real: f64, c_imag: f64) -> Self {
+        let degree = coefficients.len().clamp(2, MAX_POLYNOMIAL_DEGREE + 1) - 1;
+        let mut coefficients = coefficients;
+        coefficients.resize(MAX_POLYNOMIAL_DEGREE + 1, Complex64::new(0.0, 0.0));
+        let mut polynomial = PolynomialJulia {
+            coefficients,
+            degree,
+            c_real,
+            c_imag,
+            bailout_r2: 0.0,
+            formula: String::new(),
+        };
+        polynomial.update_bailout();
+        polynomial
+    }
+
+    /// Radius past which every orbit escapes. For |z| >= 1,
+    /// |P(z) + c| >= |z|^(d-1) (|a_d||z| - S), S being |c| plus the lower
+    /// coefficient magnitudes, so beyond (S + 2) / |a_d| each step at least
+    /// doubles |z|. Zero leading coefficients lower d; below degree 2
+    /// nothing is guaranteed and the minimum is used.
+    fn escape_radius(&self) -> f64 {
+        let Some(d) = (2..=self.degree)
+            .rev()
+            .find(|&k| self.coefficients[k].norm() > 0.0)
+        else {
+            return POLYNOMIAL_MIN_BAILOUT;
+        };
+        let lower = self.coefficients[..d].iter().map(|a| a.norm()).sum::<f64>()
+            + self.c_real.hypot(self.c_imag);
+        ((lower + 2.0) / self.coefficients[d].norm())
+            .clamp(POLYNOMIAL_MIN_BAILOUT, POLYNOMIAL_MAX_BAILOUT)
+    }
+
+    /// Recompute the escape radius and formula after a parameter change
+    fn update_bailout(&mut self) {
+        let radius = self.escape_radius();
+        self.bailout_r2 = radius * radius;
+        let radius = if radius < 1e4 {
+            format!("{}", (radius * 1000.0).round() / 1000.0)
+        } else {
+            format!("{:.2e}", radius)
+        };
+        self.formula = format!(
+            "z ← P(z) + c, P(z) = a0 + a1·z + ... + ad·z^d, z0 = pixel, c fixed; escape when |z| > {}",
+            radius
+        );
+    }
+
+    /// P(z) + c via Horner's method
+    #[inline(always)]
+    fn step(&self, z: Complex64) -> Complex64 {
+        let mut p = self.coefficients[self.degree];
+        for &a in self.coefficients[..self.degree].iter().rev() {
+            p = p * z + a;
+        }
+        p + Complex64::new(self.c_real, self.c_imag)
+    }
+
+    /// Coefficient index and component (true = real) for names like "a3_re"
+    fn coefficient_name(name: &str) -> Option<(usize, bool)> {
+        let rest = name.strip_prefix('a')?;
+        let (index, is_real) = if let Some(index) = rest.strip_suffix("_re") {
+            (index, true)
+        } else {
+            (rest.strip_suffix("_im")?, false)
+        };
+        let index: usize = index.parse().ok()?;
+        (index <= MAX_POLYNOMIAL_DEGREE).then_some((index, is_real))
+    }
+}
+
+impl Fractal for PolynomialJulia {
+    fn name(&self) -> &str {
+        "Polynomial Julia"
+    }
+
+    fn iteration_formula(&self) -> &str {
+        &self.formula
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        let mut params = vec![
+            Parameter {
+                name: "degree".to_string(),
+                value: self.degree as f64,
+                min: 1.0,
+                max: MAX_POLYNOMIAL_DEGREE as f64,
+            },
+            Parameter {
+                name: "c_real".to_string(),
+                value: self.c_real,
+                min: -2.0,
+                max: 2.0,
+            },
+            Parameter {
+                name: "c_imag".to_string(),
+                value: self.c_imag,
+                min: -2.0,
+                max: 2.0,
+            },
+        ];
+        for (k, a) in self.coefficients[..=self.degree].iter().enumerate() {
+            for (suffix, value) in [("re", a.re), ("im", a.im)] {
+                params.push(Parameter {
+                    name: format!("a{}_{}", k, suffix),
+                    value,
+                    min: -POLYNOMIAL_COEFFICIENT_RANGE,
+                    max: POLYNOMIAL_COEFFICIENT_RANGE,
+                });
+            }
+        }
+        params
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) {
+        match name {
+            "degree" => self.degree = (value.round() as usize).clamp(1, MAX_POLYNOMIAL_DEGREE),
+            "c_real" => self.c_real = value.clamp(-2.0, 2.0),
+            "c_imag" => self.c_imag = value.clamp(-2.0, 2.0),
+            _ => {
+                if let Some((k, is_real)) = Self::coefficient_name(name) {
+                    let value =
+                        value.clamp(-POLYNOMIAL_COEFFICIENT_RANGE, POLYNOMIAL_COEFFICIENT_RANGE);
+                    if is_real {
+                        self.coefficients[k].re = value;
+                    } else {
+                        self.coefficients[k].im = value;
+                    }
+                }
+            }
+        }
+        self.update_bailout();
+    }
+
+    fn get_parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "degree" => Some(self.degree as f64),
+            "c_real" => Some(self.c_real),
+            "c_imag" => Some(self.c_imag),
+            _ => {
+                let (k, is_real) = Self::coefficient_name(name)?;
+                let a = self.coefficients[k];
+                Some(if is_real { a.re } else { a.im })
+            }
+        }
+    }
+
+    fn compute(&self, zx: f64, zy: f64, max_iter: u32) -> u32 {
+        let mut z = Complex64::new(zx, zy);
+        for i in 0..max_iter {
+            if has_escaped(z.norm_sqr(), self.bailout_r2) {
+                return i;
+            }
+            z = self.step(z);
+        }
+        max_iter
+    }
+
+    fn compute_full_with(
+        &self,
+        zx: f64,
+        zy: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
+        let mut z = Complex64::new(zx, zy);
+        for i in 0..max_iter {
+            if has_escaped(z.norm_sqr(), self.bailout_r2) {
+                return FractalResult::escaped(i, z, orbit_data);
+            }
+            z = self.step(z);
+            orbit_data.update(z);
+        }
+        FractalResult::inside_set(max_iter)
+    }
+
+    fn compute_orbit(&self, zx: f64, zy: f64, max_iter: u32) -> Option<Vec<Complex64>> {
+        Some(trace_orbit(
+            (zx, zy),
+            max_iter,
+            self.bailout_r2,
+            |z_re, z_im, _, _| {
+                let z = self.step(Complex64::new(z_re, z_im));
+                (z.re, z.im)
+            },
+        ))
+    }
+}
+
+// ============================================================================
+// Mandelbrot-Julia Morph
+// ============================================================================
+
+/// Linear blend between the Mandelbrot set and a Julia set.
+///
+/// Iterates z_{n+1} = z_n^2 + c with, for pixel p,
+///   z_0 = morph * p
+///   c   = (1 - morph) * p + morph * (c_real + i*c_imag)
+///
+/// morph = 0 is the Mandelbrot set and morph = 1 the Julia set for the fixed
+/// c; values in between trace a continuous path from one to the other, so
+/// animating `morph` across keyframes morphs between them.
+pub struct MandelJuliaMorph {
+    pub morph: f64,
+    pub c_real: f64,
+    pub c_imag: f64,
+}
+
+impl Default for MandelJuliaMorph {
+    fn default() -> Self {
+        let julia = Julia::default();
+        MandelJuliaMorph {
+            morph: 0.0,
+            c_real: julia.c_real,
+            c_imag: julia.c_imag,
+        }
+    }
+}
+
+impl MandelJuliaMorph {
+    /// Initial z and the constant c for pixel (px, py). Written as
+    /// (1 - m) * a + m * b rather than a + m * (b - a) so both ends
+    /// reproduce Mandelbrot and Julia exactly.
+    #[inline(always)]
+    fn start(&self, px: f64, py: f64) -> (f64, f64, f64, f64) {
+        let m = self.morph;
+        (
+            m * px,
+            m * py,
+            (1.0 - m) * px + m * self.c_real,
+            (1.0 - m) * py + m * self.c_imag,
+        )
+    }
+}
+
+impl Fractal for MandelJuliaMorph {
+    fn name(&self) -> &str {
+        "Mandelbrot-Julia Morph"
+    }
+
+    fn iteration_formula(&self) -> &str {
+        "z ← z² + c, z0 = morph·pixel, c = (1 - morph)·pixel + morph·c_fixed; escape when |z| > 2"
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter {
+                name: "morph".to_string(),
+                value: self.morph,
+                min: 0.0,
+                max: 1.0,
+            },
+            Parameter {
+                name: "c_real".to_string(),
+                value: self.c_real,
+                min: -2.0,
+                max: 2.0,
+            },
+            Parameter {
+                name: "c_imag".to_string(),
+                value: self.c_imag,
+                min: -2.0,
+                max: 2.0,
+            },
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) {
+        match name {
+            "morph" => self.morph = value.clamp(0.0, 1.0),
+            "c_real" => self.c_real = value.clamp(-2.0, 2.0),
+            "c_imag" => self.c_imag = value.clamp(-2.0, 2.0),
+            _ => {}
+        }
+    }
+
+    fn get_parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "morph" => Some(self.morph),
+            "c_real" => Some(self.c_real),
+            "c_imag" => Some(self.c_imag),
+            _ => None,
+        }
+    }
+
+    fn compute(&self, px: f64, py: f64, max_iter: u32) -> u32 {
+        let (mut z_re, mut z_im, c_re, c_im) = self.start(px, py);
+
+        for i in 0..max_iter {
+            let r2 = z_re * z_re;
+            let i2 = z_im * z_im;
+
+            if has_escaped(r2 + i2, BAILOUT_R2) {
+                return i;
+            }
+
+            (z_re, z_im) = square_add(z_re, z_im, r2, i2, c_re, c_im);
+        }
+
+        max_iter
+    }
+
+    fn compute_full_with(
+        &self,
+        px: f64,
+        py: f64,
+        max_iter: u32,
+        mut orbit_data: OrbitData,
+    ) -> FractalResult {
+        let (mut z_re, mut z_im, c_re, c_im) = self.start(px, py);
+
+        for i in 0..max_iter {
+            let r2 = z_re * z_re;
+            let i2 = z_im * z_im;
+
+            if has_escaped(r2 + i2, BAILOUT_R2) {
+                return FractalResult::escaped(i, Complex64::new(z_re, z_im), orbit_data);
+            }
+
+            (z_re, z_im) = square_add(z_re, z_im, r2, i2, c_re, c_im);
+            orbit_data.update(Complex64::new(z_re, z_im));
+        }
+
+        FractalResult::inside_set(max_iter)
+    }
+
+    fn compute_orbit(&self, px: f64, py: f64, max_iter: u32) -> Option<Vec<Complex64>> {
+        let (z_re, z_im, c_re, c_im) = self.start(px, py);
+        Some(trace_orbit(
+            (z_re, z_im),
+            max_iter,
+            BAILOUT_R2,
+            |z_re, z_im, r2, i2| square_add(z_re, z_im, r2, i2, c_re, c_im),
+        ))
+    }
+
+    fn gpu_kernel(&self) -> Option<GpuKernel> {
+        if self.morph == 0.0 {
+            Some(GpuKernel::Mandelbrot)
+        } else if self.morph == 1.0 {
+            Some(GpuKernel::Julia {
+                c_re: self.c_real,
+                c_im: self.c_imag,
+            })
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1661,6 +2654,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pickover_stalk_trap_coloring() {
+        use crate::color_pipeline::{ColorContext, ColorProcessorType};
+        use crate::palette::PaletteType;
+
+        let ps = PickoverStalk::default();
+        let processor = ColorProcessorType::OrbitTrapCross.create_processor();
+        let context = ColorContext::new(100, PaletteType::Classic, 0.0, 100, 100);
+
+        let a = ps.compute_full(0.5, 0.03, 100);
+        let b = ps.compute_full(0.5, 0.06, 100);
+        // Both orbits come closest to an axis at c itself (|Im c|)
+        assert_eq!(a.orbit_data.stalk_distance, Some(0.03));
+        assert_eq!(b.orbit_data.stalk_distance, Some(0.06));
+        // The quantized counts agree, so only the distance tells them apart
+        assert_eq!(a.iterations, b.iterations);
+        assert_ne!(
+            processor.process(&a, &context),
+            processor.process(&b, &context)
+        );
+
+        // Inside points never escape, so carry no stalk distance
+        assert_eq!(
+            ps.compute_full(-0.5, 0.0, 100).orbit_data.stalk_distance,
+            None
+        );
+    }
+
     // ========================================================================
     // Tests for previously untested fractals
     // ========================================================================
@@ -1754,6 +2775,196 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_biomorph_bailout_not_below_escape_radius() {
+        let mut b = Biomorph::default();
+        b.set_parameter("escape_radius", 50.0);
+        b.set_parameter("bailout", 2.0);
+        assert_eq!(b.bailout, 50.0);
+
+        b.set_parameter("escape_radius", 100.0);
+        assert_eq!(b.bailout, 100.0);
+        let bailout = b
+            .parameters()
+            .into_iter()
+            .find(|p| p.name == "bailout")
+            .unwrap();
+        assert_eq!(bailout.min, 100.0);
+    }
+
+    #[test]
+    fn test_biomorph_bailout_separate_from_radius() {
+        let tight = {
+            let mut b = Biomorph::default();
+            b.set_parameter("bailout", b.escape_radius);
+            b
+        };
+        let loose = Biomorph::default();
+        assert_eq!(tight.escape_radius, loose.escape_radius);
+        assert!(loose.bailout > tight.bailout);
+
+        // With the larger bailout, orbits that pass |z| = escape_radius keep
+        // iterating, so the component test sees different final values and
+        // filament pixels change classification
+        let max_iter = 50;
+        let in_biomorph = |b: &Biomorph, x: f64, y: f64| b.compute(x, y, max_iter) > max_iter / 2;
+        let changed = (0..41 * 41)
+            .filter(|&i| {
+                let x = -1.5 + (i % 41) as f64 * 0.075;
+                let y = -1.5 + (i / 41) as f64 * 0.075;
+                in_biomorph(&tight, x, y) != in_biomorph(&loose, x, y)
+            })
+            .count();
+        assert!(changed > 0, "bailout had no effect on structure");
+        // The core biomorph body is unaffected
+        assert_eq!(
+            tight.compute(0.0, 0.0, max_iter),
+            loose.compute(0.0, 0.0, max_iter)
+        );
+    }
+
+    #[test]
+    fn test_polynomial_julia_cubic_minus_one() {
+        let coefficient = |re| Complex64::new(re, 0.0);
+        let p = PolynomialJulia::new(
+            vec![
+                coefficient(-1.0),
+                coefficient(0.0),
+                coefficient(0.0),
+                coefficient(1.0),
+            ],
+            0.0,
+            0.0,
+        );
+        assert_eq!(p.degree, 3);
+
+        // Critical orbit of z^3 - 1: 0 -> -1 -> -2 -> -9 -> -730 (escaped)
+        assert_eq!(p.compute(0.0, 0.0, 100), 4);
+        let full = p.compute_full(0.0, 0.0, 100);
+        assert!(full.escaped);
+        assert_eq!(full.iterations, 4);
+        assert_eq!(full.final_z, Some(coefficient(-730.0)));
+
+        // Horner evaluation agrees with iterating z^3 - 1 directly
+        for &(x, y) in &[(0.5, 0.5), (-0.7, 0.2), (1.2, -0.3), (0.1, 0.9)] {
+            let mut z = Complex64::new(x, y);
+            let mut expected = 100;
+            for i in 0..100 {
+                if z.norm_sqr() > p.bailout_r2 {
+                    expected = i;
+                    break;
+                }
+                z = z * z * z - 1.0;
+            }
+            assert_eq!(p.compute(x, y, 100), expected, "at ({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    fn test_polynomial_julia_escape_radius() {
+        let quadratic = |a2| {
+            PolynomialJulia::new(
+                vec![
+                    Complex64::new(0.0, 0.0),
+                    Complex64::new(0.0, 0.0),
+                    Complex64::new(a2, 0.0),
+                ],
+                0.0,
+                0.0,
+            )
+        };
+        // z^2 stays at the minimum radius
+        let p = quadratic(1.0);
+        assert_eq!(
+            p.bailout_r2,
+            POLYNOMIAL_MIN_BAILOUT * POLYNOMIAL_MIN_BAILOUT
+        );
+        assert!(p.iteration_formula().ends_with("|z| > 10"));
+
+        // 0.01z^2 attracts everything inside |z| = 100 to 0; a fixed radius
+        // of 10 would count those points as escaped
+        let mut p = quadratic(0.01);
+        assert!((p.bailout_r2 - 200.0 * 200.0).abs() < 1e-6);
+        assert!(p.iteration_formula().ends_with("|z| > 200"));
+        assert_eq!(p.compute(50.0, 0.0, 50), 50);
+        // Past the radius every orbit at least doubles
+        let z = Complex64::new(0.0, 201.0);
+        assert!(p.step(z).norm() > 2.0 * z.norm());
+        assert_eq!(p.compute_orbit(201.0, 0.0, 5).unwrap().len(), 1);
+
+        // c counts towards the bound, and a zero leading coefficient leaves
+        // nothing to guarantee escape, so the minimum is used
+        p.set_parameter("c_real", 2.0);
+        assert!((p.bailout_r2 - 400.0 * 400.0).abs() < 1e-6);
+        p.set_parameter("a2_re", 0.0);
+        assert_eq!(
+            p.bailout_r2,
+            POLYNOMIAL_MIN_BAILOUT * POLYNOMIAL_MIN_BAILOUT
+        );
+    }
+
+    #[test]
+    fn test_polynomial_julia_parameters() {
+        let mut p = PolynomialJulia::default();
+        let names: Vec<String> = p.parameters().into_iter().map(|param| param.name).collect();
+        assert!(names.contains(&"a3_im".to_string()));
+        assert!(!names.contains(&"a4_re".to_string()));
+
+        // Coefficients above the degree survive a degree round trip
+        p.set_parameter("degree", 5.0);
+        p.set_parameter("a5_re", 0.25);
+        p.set_parameter("degree", 2.0);
+        assert_eq!(p.parameters().len(), 3 + 2 * 3);
+        p.set_parameter("degree", 5.0);
+        assert_eq!(p.get_parameter("a5_re"), Some(0.25));
+
+        p.set_parameter("degree", 99.0);
+        assert_eq!(
+            p.get_parameter("degree"),
+            Some(MAX_POLYNOMIAL_DEGREE as f64)
+        );
+        p.set_parameter("a1_im", 10.0);
+        assert_eq!(p.get_parameter("a1_im"), Some(POLYNOMIAL_COEFFICIENT_RANGE));
+        assert_eq!(p.get_parameter("a9_re"), None);
+        assert_eq!(p.get_parameter("abc"), None);
+    }
+
+    #[test]
+    fn test_morph_endpoints() {
+        let mandelbrot = Mandelbrot::default();
+        let julia = Julia {
+            c_real: -0.123,
+            c_imag: 0.745,
+            power: 2.0,
+        };
+        let mut morph = MandelJuliaMorph {
+            morph: 0.0,
+            c_real: julia.c_real,
+            c_imag: julia.c_imag,
+        };
+        let points: Vec<(f64, f64)> = (0..21)
+            .flat_map(|j| (0..21).map(move |i| (-2.0 + i as f64 * 0.13, -1.3 + j as f64 * 0.13)))
+            .collect();
+
+        for &(x, y) in &points {
+            assert_eq!(morph.compute(x, y, 200), mandelbrot.compute(x, y, 200));
+            let (a, b) = (
+                morph.compute_full(x, y, 200),
+                mandelbrot.compute_full(x, y, 200),
+            );
+            assert_eq!((a.iterations, a.final_z), (b.iterations, b.final_z));
+        }
+        morph.set_parameter("morph", 1.0);
+        for &(x, y) in &points {
+            assert_eq!(morph.compute(x, y, 200), julia.compute(x, y, 200));
+            let (a, b) = (morph.compute_full(x, y, 200), julia.compute_full(x, y, 200));
+            assert_eq!((a.iterations, a.final_z), (b.iterations, b.final_z));
+        }
+
+        morph.set_parameter("morph", 5.0);
+        assert_eq!(morph.get_parameter("morph"), Some(1.0));
+    }
+
     #[test]
     fn test_phoenix_outside() {
         let p = Phoenix::default();
@@ -1768,12 +2979,123 @@ mod tests {
         // quickly but should survive more than 1 iteration
         let result = p.compute(0.0, 0.0, 200);
         assert!(
-            result >= 2 && result < 200,
+            (2..200).contains(&result),
             "Origin should escape after a few iterations, got {}",
             result
         );
     }
 
+    #[test]
+    fn test_phoenix_power2_matches_quadratic() {
+        // Reference: the original hardcoded z^2 Phoenix iteration
+        fn reference(cx: f64, cy: f64, max_iter: u32) -> u32 {
+            let (mut z_re, mut z_im) = (cx, cy);
+            let (mut prev_re, mut prev_im) = (0.0, 0.0);
+            for i in 0..max_iter {
+                let r2 = z_re * z_re;
+                let i2 = z_im * z_im;
+                if r2 + i2 > BAILOUT_R2 {
+                    return i;
+                }
+                let new_re = r2 - i2 + 0.5667 - 0.5 * prev_re;
+                let new_im = 2.0 * z_re * z_im - 0.5 * prev_im;
+                prev_re = z_re;
+                prev_im = z_im;
+                z_re = new_re;
+                z_im = new_im;
+            }
+            max_iter
+        }
+
+        let p = Phoenix::default();
+        assert_eq!(p.get_parameter("power"), Some(2.0));
+        for &(cx, cy) in &[(0.0, 0.0), (0.3, -0.2), (-0.6, 0.4), (0.1, 0.65)] {
+            assert_eq!(p.compute(cx, cy, 200), reference(cx, cy, 200));
+            assert_eq!(
+                p.compute_full(cx, cy, 200).iterations,
+                reference(cx, cy, 200)
+            );
+        }
+    }
+
+    #[test]
+    fn test_phoenix_conjugate() {
+        // Reference: quadratic Phoenix on conj(z), the Tricorn-style variant
+        fn conjugate_reference(cx: f64, cy: f64, max_iter: u32) -> u32 {
+            let (mut z_re, mut z_im) = (cx, cy);
+            let (mut prev_re, mut prev_im) = (0.0, 0.0);
+            for i in 0..max_iter {
+                let r2 = z_re * z_re;
+                let i2 = z_im * z_im;
+                if r2 + i2 > BAILOUT_R2 {
+                    return i;
+                }
+                let new_re = r2 - i2 + 0.5667 - 0.5 * prev_re;
+                let new_im = -2.0 * z_re * z_im + 0.2 - 0.5 * prev_im;
+                prev_re = z_re;
+                prev_im = z_im;
+                z_re = new_re;
+                z_im = new_im;
+            }
+            max_iter
+        }
+
+        let classic = Phoenix::default();
+        assert_eq!(classic.get_parameter("conjugate"), Some(0.0));
+        let mut explicit = Phoenix::default();
+        explicit.set_parameter("conjugate", 0.0);
+        explicit.set_parameter("power", 2.0);
+        let points = [
+            (0.0, 0.0),
+            (0.3, -0.2),
+            (-0.6, 0.4),
+            (0.1, 0.65),
+            (0.45, 0.3),
+        ];
+        for &(cx, cy) in &points {
+            assert_eq!(explicit.compute(cx, cy, 200), classic.compute(cx, cy, 200));
+        }
+
+        // With real c and p, conjugating every other step leaves |z_n|
+        // unchanged, so the variants only differ for complex c
+        let mut plain = Phoenix::default();
+        plain.set_parameter("c_imag", 0.2);
+        let mut conjugate = Phoenix::default();
+        conjugate.set_parameter("c_imag", 0.2);
+        conjugate.set_parameter("conjugate", 1.0);
+        assert_eq!(conjugate.get_parameter("conjugate"), Some(1.0));
+
+        let mut differs = false;
+        for &(cx, cy) in &points {
+            let conj = conjugate.compute(cx, cy, 200);
+            assert_eq!(conj, conjugate_reference(cx, cy, 200));
+            assert_eq!(conjugate.compute_full(cx, cy, 200).iterations, conj);
+            differs |= conj != plain.compute(cx, cy, 200);
+        }
+        assert!(differs, "conjugation should change some escape counts");
+
+        // The general-power path conjugates too
+        conjugate.set_parameter("power", 3.0);
+        plain.set_parameter("power", 3.0);
+        assert!(points
+            .iter()
+            .any(|&(cx, cy)| conjugate.compute(cx, cy, 200) != plain.compute(cx, cy, 200)));
+
+        // The parameter map follows the flag
+        let space = conjugate.parameter_space_fractal().unwrap();
+        assert_eq!(space.get_parameter("conjugate"), Some(1.0));
+    }
+
+    #[test]
+    fn test_phoenix_power_parameter() {
+        let mut p = Phoenix::default();
+        p.set_parameter("power", 3.0);
+        assert_eq!(p.get_parameter("power"), Some(3.0));
+        p.set_parameter("power", 20.0);
+        assert_eq!(p.get_parameter("power"), Some(8.0));
+        assert!(p.parameters().iter().any(|param| param.name == "power"));
+    }
+
     #[test]
     fn test_multibrot_center() {
         let m = Multibrot::default(); // power=3
@@ -1789,6 +3111,42 @@ mod tests {
         assert!(result < 10, "Far outside should escape quickly");
     }
 
+    #[test]
+    fn test_non_finite_orbit_escapes() {
+        assert!(has_escaped(f64::INFINITY, BAILOUT_R2));
+        assert!(has_escaped(f64::NAN, BAILOUT_R2));
+        assert!(!has_escaped(BAILOUT_R2, BAILOUT_R2));
+
+        let mut m = Multibrot::default();
+        m.set_parameter("power", 10.0);
+        // Overflows to infinity on the first De Moivre step
+        let overflow = m.compute_full(f64::MAX, f64::MAX, 100);
+        assert!(overflow.escaped);
+        assert_eq!(overflow.iterations, 1);
+        // A NaN orbit used to run to max_iter and render as interior
+        assert!(m.compute(f64::NAN, 0.0, 100) < 100);
+        let nan = m.compute_full(f64::NAN, 0.0, 100);
+        assert!(nan.escaped);
+        assert!(nan.iterations < 100);
+
+        let power_fractals: [Box<dyn Fractal>; 4] = [
+            Box::new(BurningShip { power: 8.0 }),
+            Box::new(Tricorn { power: 8.0 }),
+            Box::new(Celtic { power: 8.0 }),
+            Box::new(Biomorph {
+                power: 8.0,
+                ..Biomorph::default()
+            }),
+        ];
+        for fractal in &power_fractals {
+            assert!(
+                fractal.compute(f64::NAN, 0.0, 100) < 100,
+                "{}",
+                fractal.name()
+            );
+        }
+    }
+
     #[test]
     fn test_spider_center() {
         let s = Spider::default();
@@ -1804,6 +3162,208 @@ mod tests {
         assert!(result < 10, "Far outside should escape quickly");
     }
 
+    #[test]
+    fn test_square_add_matches_direct_formula() {
+        let c = (-0.75, 0.125);
+        for &(z_re, z_im) in &[
+            (0.0, 0.0),
+            (0.5, 0.25),
+            (-1.25, 0.75),
+            (1.5, -0.5),
+            (-0.375, -1.0),
+            (0.0, 1.0),
+        ] {
+            let r2 = z_re * z_re;
+            let i2 = z_im * z_im;
+            let (re, im) = square_add(z_re, z_im, r2, i2, c.0, c.1);
+            assert_eq!(re.to_bits(), (r2 - i2 + c.0).to_bits());
+            assert_eq!(im.to_bits(), (2.0 * z_re * z_im + c.1).to_bits());
+        }
+    }
+
+    #[test]
+    fn test_square_add_needs_one_product() {
+        for &(z_re, z_im) in &[(0.3, -0.7), (-1.2566, 0.3801), (1e-9, 0.9563), (0.1, 0.1)] {
+            let (r2, i2) = (z_re * z_re, z_im * z_im);
+            let (_, im) = square_add(z_re, z_im, r2, i2, 0.25, -0.5);
+            // Doubling is exact, so 2*z_re*z_im costs a single multiply
+            assert_eq!(im.to_bits(), ((z_re + z_re) * z_im + -0.5).to_bits());
+        }
+    }
+
+    #[test]
+    fn test_mandelbrot_conjugate_symmetry() {
+        // None of these are exact in binary, so every step rounds; the
+        // iteration must still mirror exactly across the real axis
+        let m = Mandelbrot::default();
+        for &(x, y) in &[
+            (-0.7453, 0.1127),
+            (0.3, 0.1),
+            (-1.2566, 0.3801),
+            (-0.1011, 0.9563),
+            (0.2501, 1e-9),
+        ] {
+            let upper = m.compute_full(x, y, 1000);
+            let lower = m.compute_full(x, -y, 1000);
+            assert_eq!(upper.iterations, lower.iterations, "at {} + {}i", x, y);
+            assert_eq!(
+                upper.final_z.map(|z| (z.re.to_bits(), z.im.to_bits())),
+                lower.final_z.map(|z| (z.re.to_bits(), (-z.im).to_bits()))
+            );
+            assert_eq!(m.compute(x, y, 1000), m.compute(x, -y, 1000));
+        }
+    }
+
+    #[test]
+    fn test_spider_feedback() {
+        // Classic Spider with a hardcoded c = c/2 + z update
+        fn classic(cx: f64, cy: f64, max_iter: u32) -> u32 {
+            let (mut z_re, mut z_im, mut c_re, mut c_im) = (0.0f64, 0.0f64, cx, cy);
+            for i in 0..max_iter {
+                if z_re * z_re + z_im * z_im > BAILOUT_R2 {
+                    return i;
+                }
+                let new_re = z_re * z_re - z_im * z_im + c_re;
+                z_im = 2.0 * z_re * z_im + c_im;
+                z_re = new_re;
+                c_re = c_re / 2.0 + z_re;
+                c_im = c_im / 2.0 + z_im;
+            }
+            max_iter
+        }
+
+        let mut s = Spider::default();
+        assert_eq!(s.get_parameter("feedback"), Some(0.5));
+        let points = [(0.3, 0.2), (-0.7, 0.1), (0.1, -0.6), (0.26, 0.0)];
+        for &(x, y) in &points {
+            assert_eq!(s.compute(x, y, 200), classic(x, y, 200));
+            assert_eq!(s.compute_full(x, y, 200).iterations, classic(x, y, 200));
+        }
+
+        s.set_parameter("feedback", 0.9);
+        assert!(points
+            .iter()
+            .any(|&(x, y)| s.compute(x, y, 200) != classic(x, y, 200)));
+
+        s.set_parameter("feedback", 5.0);
+        assert_eq!(s.get_parameter("feedback"), Some(1.0));
+    }
+
+    #[test]
+    fn test_orbits_match_compute() {
+        let registry = registry::FractalRegistry::default();
+        let points = [
+            (0.3, 0.5),
+            (-0.75, 0.1),
+            (0.1, -0.2),
+            (1.0, 1.0),
+            (-1.2, 0.3),
+        ];
+        for fractal_type in registry.all_types() {
+            let fractal = registry.create(fractal_type).unwrap();
+            for &(x, y) in &points {
+                let Some(orbit) = fractal.compute_orbit(x, y, 100) else {
+                    continue;
+                };
+                // An escaping orbit ends on the z the fractal escaped with;
+                // bounded orbits take every step
+                let result = fractal.compute_full(x, y, 100);
+                if result.escaped {
+                    assert_eq!(orbit.last().copied(), result.final_z, "{}", fractal.name());
+                } else {
+                    assert_eq!(orbit.len(), 101, "{}", fractal.name());
+                }
+            }
+        }
+        assert!(Newton::default().compute_orbit(0.5, 0.5, 100).is_none());
+    }
+
+    #[test]
+    fn test_hp_orbits_match_f64() {
+        let registry = registry::FractalRegistry::default();
+        // Binary fractions, so the decimal and f64 points are the same
+        let points = [(-0.75, 0.125), (0.25, 0.5), (-1.25, 0.0625), (0.5, -0.5)];
+        let mut checked = 0;
+        for fractal_type in registry.all_types() {
+            let fractal = registry.create(fractal_type).unwrap();
+            for &(x, y) in &points {
+                let hp = (
+                    HpDecimal::from_f64(x).unwrap(),
+                    HpDecimal::from_f64(y).unwrap(),
+                );
+                let Some(orbit) = fractal.compute_orbit_hp(&hp.0, &hp.1, 30) else {
+                    continue;
+                };
+                checked += 1;
+                let expected = fractal.compute_orbit(x, y, 30).unwrap();
+                assert_eq!(orbit.len(), expected.len(), "{}", fractal.name());
+                for (a, b) in orbit.iter().zip(&expected) {
+                    assert!((a - b).norm() < 1e-9, "{}: {} vs {}", fractal.name(), a, b);
+                }
+            }
+        }
+        // Mandelbrot, Julia, Burning Ship, Tricorn, Celtic
+        assert_eq!(checked, 5 * points.len());
+        assert!(Multibrot::default()
+            .compute_orbit_hp(&HpDecimal::default(), &HpDecimal::default(), 10)
+            .is_none());
+    }
+
+    #[test]
+    fn test_hp_orbit_keeps_digits_beyond_f64() {
+        // c = -2 - 1e-25 escapes, but rounds to -2 in f64, whose orbit
+        // settles on 2 forever
+        let m = Mandelbrot::default();
+        let re = HpDecimal::parse("-2.0000000000000000000000001").unwrap();
+        assert_eq!(re.to_f64(), -2.0);
+        let orbit = m.compute_orbit_hp(&re, &HpDecimal::default(), 200).unwrap();
+        assert!(orbit.len() < 100, "took {} steps", orbit.len());
+        assert!(orbit[orbit.len() - 1].norm_sqr() > BAILOUT_R2);
+        assert_eq!(m.compute_orbit(-2.0, 0.0, 200).unwrap().len(), 201);
+    }
+
+    #[test]
+    fn test_anti_spider_sign() {
+        let mut s = Spider::default();
+        assert_eq!(s.get_parameter("sign"), Some(1.0));
+        let points = [
+            (0.3, 0.2),
+            (-0.7, 0.1),
+            (0.1, -0.6),
+            (0.26, 0.0),
+            (-1.2, 0.3),
+            (-0.5, 0.55),
+            (0.1, 0.45),
+            (0.15, 0.3),
+        ];
+        let counts = |s: &Spider| -> Vec<u32> {
+            points.iter().map(|&(x, y)| s.compute(x, y, 200)).collect()
+        };
+        // Escape counts of the classic Spider (c ← c/2 + z) at these points
+        let classic = counts(&s);
+        assert_eq!(classic, [4, 200, 6, 5, 200, 17, 15, 7]);
+
+        s.set_parameter("sign", -1.0);
+        let anti = counts(&s);
+        assert_ne!(anti, classic);
+        for (&(x, y), &n) in points.iter().zip(&anti) {
+            assert_eq!(s.compute_full(x, y, 200).iterations, n);
+        }
+
+        // The sign is a continuous coefficient: no jump crossing zero
+        s.set_parameter("sign", -0.3);
+        assert_eq!(s.get_parameter("sign"), Some(-0.3));
+        s.set_parameter("sign", -1e-12);
+        let below = counts(&s);
+        s.set_parameter("sign", 1e-12);
+        assert_eq!(counts(&s), below);
+        s.set_parameter("sign", -5.0);
+        assert_eq!(s.get_parameter("sign"), Some(-1.0));
+
+        s.set_parameter("sign", 1.0);
+        assert_eq!(counts(&s), classic);
+    }
+
     #[test]
     fn test_orbit_trap_outside() {
         let ot = OrbitTrap::default();
@@ -1879,6 +3439,36 @@ mod tests {
         assert!(result.orbit_data.min_distance_to_origin < f64::INFINITY);
     }
 
+    #[test]
+    fn test_newton_reports_root() {
+        let n = Newton::default();
+        for (root, &(re, im)) in NEWTON_ROOTS.iter().enumerate() {
+            let convergence = n.converge(re * 1.05, im * 1.05, 100).unwrap();
+            assert_eq!(convergence.root, root);
+            assert_eq!(n.compute_full(re * 1.05, im * 1.05, 100).root, Some(root));
+        }
+        assert!(n.converge(0.0, 0.0, 100).is_none());
+        assert_eq!(n.compute_full(0.0, 0.0, 100).root, None);
+    }
+
+    #[test]
+    fn test_newton_tighter_tolerance_takes_longer() {
+        // Borderline point: a few steps away from root 1
+        let (cx, cy) = (2.0, 0.5);
+        let mut n = Newton::default();
+        n.set_parameter("tolerance", MAX_CONVERGENCE_TOLERANCE);
+        let loose = n.converge(cx, cy, 100).unwrap();
+        n.set_parameter("tolerance", MIN_CONVERGENCE_TOLERANCE);
+        let tight = n.converge(cx, cy, 100).unwrap();
+        assert_eq!(loose.root, tight.root);
+        assert!(
+            tight.iterations > loose.iterations,
+            "tight {} vs loose {}",
+            tight.iterations,
+            loose.iterations
+        );
+    }
+
     #[test]
     fn test_newton_compute_full_convergence() {
         let n = Newton::default();
@@ -1991,4 +3581,23 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_parameter_space_fractal() {
+        let mut julia = Julia::default();
+        julia.set_parameter("power", 3.0);
+        let space = julia.parameter_space_fractal().unwrap();
+        assert_eq!(space.name(), "Mandelbrot");
+        assert_eq!(space.get_parameter("power"), Some(3.0));
+
+        // Phoenix's c-plane keeps its memory term; c = 0 fixes z at 0
+        let phoenix = Phoenix::default();
+        let space = phoenix.parameter_space_fractal().unwrap();
+        assert_eq!(space.get_parameter("memory"), Some(phoenix.memory));
+        assert_eq!(space.get_parameter("c_real"), None);
+        assert_eq!(space.compute(0.0, 0.0, 200), 200);
+        assert!(space.compute(1.5, 1.5, 200) < 200);
+
+        assert!(Mandelbrot::default().parameter_space_fractal().is_none());
+    }
 }
@@ -1,6 +1,15 @@
 use crate::fractal::{Fractal, FractalType, Parameter};
 use std::collections::HashMap;
 
+/// Starting iteration count for most fractals
+pub const DEFAULT_ITERATIONS: u32 = 200;
+/// Lowest iteration count the controls accept
+pub const MIN_ITERATIONS: u32 = 16;
+/// Highest iteration count the controls accept
+pub const MAX_ITERATIONS_CAP: u32 = 2000;
+/// Phoenix shows its structure at fewer iterations
+const PHOENIX_ITERATIONS: u32 = 100;
+
 /// Metadata about a fractal type
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -10,7 +19,39 @@ pub struct FractalMetadata {
     pub description: Option<String>,
     pub default_center: (f64, f64),
     pub default_zoom: f64,
+    /// Recommended starting iteration count
+    pub default_iterations: u32,
     pub category: FractalCategory,
+    /// Named parameter settings worth visiting, in menu order
+    pub presets: Vec<ParameterPreset>,
+}
+
+/// A named set of parameter values, applied through `set_parameter`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterPreset {
+    pub name: String,
+    pub description: String,
+    pub params: Vec<(String, f64)>,
+}
+
+impl ParameterPreset {
+    fn new(name: &str, description: &str, params: &[(&str, f64)]) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            params: params
+                .iter()
+                .map(|&(param, value)| (param.to_string(), value))
+                .collect(),
+        }
+    }
+
+    /// Set every parameter of the preset on `fractal`
+    pub fn apply(&self, fractal: &mut dyn Fractal) {
+        for (name, value) in &self.params {
+            fractal.set_parameter(name, *value);
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -59,6 +100,8 @@ impl FractalRegistry {
         self.register(FractalType::Spider, SpiderFactory);
         self.register(FractalType::OrbitTrap, OrbitTrapFactory);
         self.register(FractalType::PickoverStalk, PickoverStalkFactory);
+        self.register(FractalType::PolynomialJulia, PolynomialJuliaFactory);
+        self.register(FractalType::MandelJuliaMorph, MandelJuliaMorphFactory);
     }
 
     /// Register a fractal factory
@@ -112,7 +155,9 @@ impl FractalFactory for MandelbrotFactory {
             description: Some("The classic Mandelbrot set".to_string()),
             default_center: (-0.5, 0.0),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::MandelbrotLike,
+            presets: Vec::new(),
         }
     }
 
@@ -134,7 +179,9 @@ impl FractalFactory for JuliaFactory {
             description: Some("Julia sets with variable c parameter".to_string()),
             default_center: (0.0, 0.0),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::JuliaLike,
+            presets: julia_presets(),
         }
     }
 
@@ -143,6 +190,49 @@ impl FractalFactory for JuliaFactory {
     }
 }
 
+/// Classic quadratic Julia sets, keyed by their c value
+fn julia_presets() -> Vec<ParameterPreset> {
+    let preset = |name, description, c_real, c_imag| {
+        ParameterPreset::new(
+            name,
+            description,
+            &[("c_real", c_real), ("c_imag", c_imag), ("power", 2.0)],
+        )
+    };
+    vec![
+        preset(
+            "Douady Rabbit",
+            "c = -0.123 + 0.745i, three-lobed period-3 bulb",
+            -0.123,
+            0.745,
+        ),
+        preset(
+            "San Marco",
+            "c = -0.75, where the period-2 bulb meets the main cardioid",
+            -0.75,
+            0.0,
+        ),
+        preset(
+            "Dendrite",
+            "c = i, a branching set with no interior",
+            0.0,
+            1.0,
+        ),
+        preset(
+            "Siegel Disk",
+            "c = -0.391 - 0.587i, rotation around an irrational fixed point",
+            -0.390541,
+            -0.586788,
+        ),
+        preset(
+            "Default Spiral",
+            "c = -0.7 + 0.27015i, the starting Julia set",
+            -0.7,
+            0.27015,
+        ),
+    ]
+}
+
 struct BurningShipFactory;
 impl FractalFactory for BurningShipFactory {
     fn create(&self) -> Box<dyn Fractal> {
@@ -156,7 +246,9 @@ impl FractalFactory for BurningShipFactory {
             description: Some("Burning Ship fractal with absolute values".to_string()),
             default_center: (-0.5, -0.5),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::MandelbrotLike,
+            presets: Vec::new(),
         }
     }
 
@@ -178,7 +270,9 @@ impl FractalFactory for TricornFactory {
             description: Some("Tricorn/Mandelbar fractal".to_string()),
             default_center: (0.0, 0.0),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::MandelbrotLike,
+            presets: Vec::new(),
         }
     }
 
@@ -200,7 +294,9 @@ impl FractalFactory for CelticFactory {
             description: Some("Celtic fractal variant".to_string()),
             default_center: (0.0, 0.0),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::MandelbrotLike,
+            presets: Vec::new(),
         }
     }
 
@@ -222,7 +318,9 @@ impl FractalFactory for NewtonFactory {
             description: Some("Newton's method fractal for z³ - 1 = 0".to_string()),
             default_center: (0.0, 0.0),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::Special,
+            presets: Vec::new(),
         }
     }
 
@@ -244,7 +342,9 @@ impl FractalFactory for BiomorphFactory {
             description: Some("Biomorph fractal with escape conditions".to_string()),
             default_center: (0.0, 0.0),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::Special,
+            presets: Vec::new(),
         }
     }
 
@@ -266,7 +366,9 @@ impl FractalFactory for PhoenixFactory {
             description: Some("Phoenix fractal with memory term".to_string()),
             default_center: (0.0, 0.0),
             default_zoom: 1.0,
+            default_iterations: PHOENIX_ITERATIONS,
             category: FractalCategory::Special,
+            presets: phoenix_presets(),
         }
     }
 
@@ -275,6 +377,28 @@ impl FractalFactory for PhoenixFactory {
     }
 }
 
+/// Ushiki's Phoenix constants and the conjugate variant
+fn phoenix_presets() -> Vec<ParameterPreset> {
+    let ushiki = [
+        ("c_real", 0.5667),
+        ("c_imag", 0.0),
+        ("memory", -0.5),
+        ("power", 2.0),
+    ];
+    vec![
+        ParameterPreset::new(
+            "Classic Phoenix",
+            "c = 0.5667, memory = -0.5 (Ushiki)",
+            &[&ushiki[..], &[("conjugate", 0.0)]].concat(),
+        ),
+        ParameterPreset::new(
+            "Conjugate Phoenix",
+            "The classic constants with z conjugated each step",
+            &[&ushiki[..], &[("conjugate", 1.0)]].concat(),
+        ),
+    ]
+}
+
 struct MultibrotFactory;
 impl FractalFactory for MultibrotFactory {
     fn create(&self) -> Box<dyn Fractal> {
@@ -288,7 +412,9 @@ impl FractalFactory for MultibrotFactory {
             description: Some("Generalized Mandelbrot with variable power".to_string()),
             default_center: (0.0, 0.0),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::MandelbrotLike,
+            presets: Vec::new(),
         }
     }
 
@@ -300,7 +426,7 @@ impl FractalFactory for MultibrotFactory {
 struct SpiderFactory;
 impl FractalFactory for SpiderFactory {
     fn create(&self) -> Box<dyn Fractal> {
-        Box::new(Spider)
+        Box::new(Spider::default())
     }
 
     fn metadata(&self) -> FractalMetadata {
@@ -310,12 +436,14 @@ impl FractalFactory for SpiderFactory {
             description: Some("Spider fractal with evolving c parameter".to_string()),
             default_center: (0.0, 0.0),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::Special,
+            presets: Vec::new(),
         }
     }
 
     fn default_parameters(&self) -> Vec<Parameter> {
-        Spider.parameters()
+        Spider::default().parameters()
     }
 }
 
@@ -332,7 +460,9 @@ impl FractalFactory for OrbitTrapFactory {
             description: Some("Mandelbrot with orbit trap coloring".to_string()),
             default_center: (-0.5, 0.0),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::Special,
+            presets: Vec::new(),
         }
     }
 
@@ -354,7 +484,9 @@ impl FractalFactory for PickoverStalkFactory {
             description: Some("Pickover stalk orbit trap".to_string()),
             default_center: (-0.5, 0.0),
             default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
             category: FractalCategory::Special,
+            presets: Vec::new(),
         }
     }
 
@@ -363,6 +495,56 @@ impl FractalFactory for PickoverStalkFactory {
     }
 }
 
+struct PolynomialJuliaFactory;
+impl FractalFactory for PolynomialJuliaFactory {
+    fn create(&self) -> Box<dyn Fractal> {
+        Box::new(PolynomialJulia::default())
+    }
+
+    fn metadata(&self) -> FractalMetadata {
+        FractalMetadata {
+            id: "polynomial_julia".to_string(),
+            display_name: "Polynomial Julia".to_string(),
+            description: Some("Julia sets of P(z) + c with editable coefficients".to_string()),
+            default_center: (0.0, 0.0),
+            default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
+            category: FractalCategory::JuliaLike,
+            presets: Vec::new(),
+        }
+    }
+
+    fn default_parameters(&self) -> Vec<Parameter> {
+        PolynomialJulia::default().parameters()
+    }
+}
+
+struct MandelJuliaMorphFactory;
+impl FractalFactory for MandelJuliaMorphFactory {
+    fn create(&self) -> Box<dyn Fractal> {
+        Box::new(MandelJuliaMorph::default())
+    }
+
+    fn metadata(&self) -> FractalMetadata {
+        FractalMetadata {
+            id: "mandel_julia_morph".to_string(),
+            display_name: "Mandelbrot-Julia Morph".to_string(),
+            description: Some(
+                "Blend from the Mandelbrot set (morph 0) to a Julia set (morph 1)".to_string(),
+            ),
+            default_center: (-0.5, 0.0),
+            default_zoom: 1.0,
+            default_iterations: DEFAULT_ITERATIONS,
+            category: FractalCategory::Special,
+            presets: Vec::new(),
+        }
+    }
+
+    fn default_parameters(&self) -> Vec<Parameter> {
+        MandelJuliaMorph::default().parameters()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,11 +552,52 @@ mod tests {
     #[test]
     fn test_registry_default() {
         let registry = FractalRegistry::default();
-        assert_eq!(registry.all_types().len(), 12);
+        assert_eq!(registry.all_types().len(), 14);
         assert!(registry.is_registered(FractalType::Mandelbrot));
         assert!(registry.is_registered(FractalType::Julia));
     }
 
+    #[test]
+    fn test_iteration_formulas() {
+        let registry = FractalRegistry::default();
+        for fractal_type in registry.all_types() {
+            let fractal = registry.create(fractal_type).unwrap();
+            assert!(
+                !fractal.iteration_formula().trim().is_empty(),
+                "{} has no formula",
+                fractal.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_julia_presets() {
+        let registry = FractalRegistry::default();
+        let presets = registry.metadata(FractalType::Julia).unwrap().presets;
+        let san_marco = presets.iter().find(|p| p.name == "San Marco").unwrap();
+
+        let mut julia = registry.create(FractalType::Julia).unwrap();
+        julia.set_parameter("power", 3.0);
+        san_marco.apply(julia.as_mut());
+        assert_eq!(julia.get_parameter("c_real"), Some(-0.75));
+        assert_eq!(julia.get_parameter("c_imag"), Some(0.0));
+        assert_eq!(julia.get_parameter("power"), Some(2.0));
+
+        // Every preset only names parameters its fractal has, within range
+        for ft in registry.all_types() {
+            let params = registry.default_parameters(ft).unwrap();
+            for preset in registry.metadata(ft).unwrap().presets {
+                for (name, value) in &preset.params {
+                    let param = params.iter().find(|p| &p.name == name);
+                    let param = param.unwrap_or_else(|| {
+                        panic!("{:?} preset {} sets unknown {}", ft, preset.name, name)
+                    });
+                    assert!((param.min..=param.max).contains(value));
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_registry_create() {
         let registry = FractalRegistry::default();
@@ -392,6 +615,47 @@ mod tests {
         assert_eq!(meta.default_center, (-0.5, 0.0));
     }
 
+    #[test]
+    fn test_registry_descriptions() {
+        let registry = FractalRegistry::default();
+        for ft in registry.all_types() {
+            let meta = registry.metadata(ft).unwrap();
+            let description = meta.description.unwrap_or_default();
+            assert!(!description.is_empty(), "{:?} has no description", ft);
+
+            for param in registry.default_parameters(ft).unwrap() {
+                assert!(
+                    !param.description().is_empty(),
+                    "{:?} parameter {} has no description",
+                    ft,
+                    param.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_registry_default_iterations() {
+        let registry = FractalRegistry::default();
+        for ft in registry.all_types() {
+            let iterations = registry.metadata(ft).unwrap().default_iterations;
+            // Within the iteration slider's 16..=2000 range
+            assert!(
+                (16..=2000).contains(&iterations),
+                "{:?} defaults to {} iterations",
+                ft,
+                iterations
+            );
+        }
+        assert_eq!(
+            registry
+                .metadata(FractalType::Phoenix)
+                .unwrap()
+                .default_iterations,
+            PHOENIX_ITERATIONS
+        );
+    }
+
     #[test]
     fn test_registry_parameters() {
         let registry = FractalRegistry::default();
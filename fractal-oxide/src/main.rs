@@ -1,44 +1,199 @@
 use eframe::egui;
-use image::{ImageBuffer, Rgb};
+use image::{ImageBuffer, Luma, Rgb, Rgba};
+use num_complex::Complex64;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
 use std::time::Instant;
 
+mod animation;
 mod color_pipeline;
 mod command;
+mod composite;
+mod explore;
 mod fractal;
 mod palette;
+mod precision;
 mod renderer;
+mod stats;
+mod svg;
 mod ui;
 mod viewport;
 
-use command::{AppState, CommandHistory, ViewCommand};
-use fractal::{registry::FractalRegistry, Fractal, FractalType};
+use command::{
+    AppState, CommandDescriptor, CommandHistory, FractalTypeCommand, PaletteCommand, ViewCommand,
+};
+use fractal::registry::{FractalRegistry, DEFAULT_ITERATIONS, MAX_ITERATIONS_CAP, MIN_ITERATIONS};
+use fractal::{Fractal, FractalType};
 use palette::PaletteType;
-use renderer::{RenderConfig, RenderEngine, RenderRegion};
+use precision::HpCenter;
+use renderer::{
+    ColorAdjustment, ColorLut, ExportControl, RenderConfig, RenderEngine, RenderRegion,
+};
 use ui::{FractalControls, RenderStatus};
 use viewport::Viewport;
 
 // Application-wide constants
 const DEFAULT_WINDOW_WIDTH: f32 = 1200.0;
 const DEFAULT_WINDOW_HEIGHT: f32 = 800.0;
-const DEFAULT_ITERATIONS: u32 = 200;
-const MAX_ITERATIONS_CAP: u32 = 2000;
+const MIN_WINDOW_SIZE: f32 = 200.0;
 const ADAPTIVE_ITER_COEFFICIENT: f64 = 50.0;
+/// Target duration of a full render when the render budget is on
+const RENDER_BUDGET_SECS: f64 = 0.5;
+/// Lowest adaptive iteration cap the render budget may set
+const MIN_BUDGET_ITERATIONS: u32 = 100;
+/// Iteration boost while the high-iteration preview key (H) is held
+const PREVIEW_ITERATION_MULTIPLIER: u32 = 8;
+const PREVIEW_MAX_ITERATIONS: u32 = 10_000;
+/// Default undo depth per fractal; configurable within the range below
 const UNDO_HISTORY_CAPACITY: usize = 50;
+const MIN_HISTORY_SIZE: usize = 1;
+const MAX_HISTORY_SIZE: usize = 1000;
 const STATUS_TIMEOUT_SECS: f64 = 3.0;
+/// Events kept in the log panel's scrollback
+const LOG_CAPACITY: usize = 200;
+const LOG_SCROLL_HEIGHT: f32 = 120.0;
 const DRAG_THRESHOLD_PX: f32 = 10.0;
+/// Default frames the zoom preview is shown before a zoom-box render starts
 const RENDER_DELAY_FRAMES: u32 = 2;
+const MAX_RENDER_DELAY_FRAMES: u32 = 30;
+/// Frames the canvas size must hold still after a resize before re-rendering
+const RESIZE_SETTLE_FRAMES: u32 = 5;
+/// Idle time after the last pan/zoom before adaptive quality renders the
+/// full-quality pass
+const QUALITY_IDLE_SECS: f64 = 0.3;
+/// Draft passes render at 1/N resolution per axis and 1/N iterations
+const DRAFT_QUALITY_DIVISOR: u32 = 2;
 const MINIMAP_SIZE: usize = 150;
 const MINIMAP_MAX_ITER: u32 = 50;
 const MINIMAP_MAP_RANGE: f64 = 4.0;
+/// Shortest time between minimap fractal passes while the view is changing
+const MINIMAP_MIN_INTERVAL_SECS: f64 = 0.25;
+const PARAMETER_MAP_SIZE: usize = 150;
+const PARAMETER_MAP_MAX_ITER: u32 = 50;
+/// Width of the square c range shown in the parameter map, centered on 0
+/// (matches the c_real/c_imag slider ranges)
+const PARAMETER_MAP_RANGE: f64 = 4.0;
 const CONTROL_PANEL_WIDTH: f32 = 280.0;
 const BOOKMARK_SCROLL_HEIGHT: f32 = 150.0;
 const ZOOM_KEYBOARD_FACTOR: f64 = 1.5;
 const PAN_AMOUNT_BASE: f64 = 0.5;
+/// Exact magnification steps on the 2 and 0 keys (Shift zooms out)
+const ZOOM_KEY_TWO_FACTOR: f64 = 2.0;
+const ZOOM_KEY_TEN_FACTOR: f64 = 10.0;
+const DEFAULT_EXACT_ZOOM_FACTOR: f64 = 10.0;
+const MAX_EXACT_ZOOM_FACTOR: f64 = 1000.0;
+/// Default zoom-out limit; at 0.1 the view spans 40 units, well past any set
+const DEFAULT_MIN_ZOOM: f64 = 0.1;
+/// Lower bound for the configurable zoom-out limit
+const MIN_ZOOM_LIMIT: f64 = 1e-3;
+/// Zoom-in limit: beyond this, neighbouring pixels near |c| ~ 1 are closer
+/// together than f64 can resolve
+const MAX_ZOOM: f64 = 1e13;
+/// Exports whose pixel spacing is below this many f64 ulps of the view's
+/// coordinates are refused (configurable; 0 disables the check)
+const DEFAULT_MIN_FEATURE_ULPS: f64 = 2.0;
+const MAX_MIN_FEATURE_ULPS: f64 = 64.0;
+/// Exports larger than this many megapixels are refused before anything is
+/// allocated (configurable); 250 MP is about 1.75 GB of pixel buffers
+const DEFAULT_EXPORT_MEGAPIXEL_CAP: f64 = 250.0;
+const MIN_EXPORT_MEGAPIXEL_CAP: f64 = 1.0;
+const MAX_EXPORT_MEGAPIXEL_CAP: f64 = 4000.0;
+/// Bytes held per exported pixel: the RGBA render buffer plus the encoded
+/// RGB image
+const EXPORT_BYTES_PER_PIXEL: f64 = 7.0;
 const SCROLL_ZOOM_SENSITIVITY: f64 = 0.01;
 const SCROLL_DEADZONE: f32 = 0.1;
+/// Scroll distance (about one wheel notch) per power-of-two step of snap zoom
+const SNAP_SCROLL_STEP: f32 = 40.0;
+const MIN_LOCKED_ASPECT: f32 = 0.25;
+const MAX_LOCKED_ASPECT: f32 = 4.0;
+/// Slider limits for the brightness/contrast adjustment
+const MAX_BRIGHTNESS: f32 = 128.0;
+const MAX_CONTRAST: f32 = 4.0;
+/// Palette offset change per point of Shift+scroll
+const PALETTE_OFFSET_SCROLL_STEP: f32 = 0.001;
+/// Pause after which Shift+scrolling counts as a new undo step
+const PALETTE_SCROLL_GESTURE_SECS: f64 = 0.3;
+/// Default number of frames for keyframe animation export
+const DEFAULT_ANIMATION_FRAMES: usize = 60;
+/// Width of the probe of the most zoomed-out frame that locked animation
+/// colors are measured from
+const COLOR_LOCK_PROBE_WIDTH: u32 = 160;
+const EXPORT_SUPERSAMPLE_FACTOR: u32 = 2;
+/// Export scales offered for every export, and the larger ones offered
+/// only for tiled exports, which stream to disk and so have no memory cap
+const EXPORT_SCALES: [u32; 3] = [1, 2, 4];
+const TILED_EXPORT_SCALES: [u32; 3] = [8, 16, 32];
+const GRID_TARGET_LINES: f64 = 8.0;
+const GRID_MAX_LINES: usize = 100;
+/// Samples per side when averaging the image under the zoom selection
+const CONTRAST_SAMPLES: usize = 32;
+/// Mean luma (0-255) above which overlays switch from white to black
+const CONTRAST_LUMA_THRESHOLD: f32 = 128.0;
+/// Lemniscates drawn by the overlay (n = 1 up to this)
+const LEMNISCATE_LEVELS: u32 = 6;
+/// Screen spacing of the samples the lemniscates are traced from
+const LEMNISCATE_CELL_PX: u32 = 4;
+/// Screen size of each cell of the iteration-label debug overlay
+const ITERATION_LABEL_CELL_PX: u32 = 48;
+/// The overlay stays off when the canvas would need more cells than this
+const ITERATION_LABEL_MAX_CELLS: usize = 1200;
+const GALLERY_DIR: &str = "gallery";
+/// Iteration bands traced in SVG exports
+const SVG_BAND_COUNT: u32 = 16;
+/// Renders and exports taking at least this long flash the canvas on
+/// completion (when enabled)
+const NOTIFY_MIN_RENDER_SECS: f64 = 5.0;
+const NOTIFY_FLASH_SECS: f32 = 0.6;
+/// Opacity of the white completion flash at its start
+const NOTIFY_FLASH_ALPHA: f32 = 160.0;
+/// Zoom applied when jumping to a spot from the boundary finder
+const EXPLORE_ZOOM: f64 = 200.0;
+/// Width of the iteration difference heatmap; height follows the canvas aspect
+const DIFFERENCE_WIDTH: u32 = 320;
+/// Width of the coarse probes behind the iteration suggestion
+const SUGGEST_PROBE_WIDTH: u32 = 160;
+/// Each probe raises the iteration count by this factor
+const SUGGEST_ITERATION_STEP: f64 = 1.5;
+/// An iteration count is enough once the next probe changes fewer than
+/// this fraction of pixels
+const SUGGEST_CHANGE_THRESHOLD: f64 = 0.001;
+/// Color stops extracted by "Palette from image"
+const IMAGE_PALETTE_COLORS: usize = 8;
+/// Stops kept from a hand-edited custom palette in the config
+const MAX_CUSTOM_PALETTE_STOPS: usize = 256;
+/// Initial path for bookmark export/import
+const DEFAULT_BOOKMARKS_FILE: &str = "bookmarks.json";
+/// Folder for the screenshots saved with bookmarks
+const BOOKMARK_IMAGE_DIR: &str = "bookmarks";
+/// Finished exports listed under the export queue
+const EXPORT_QUEUE_HISTORY: usize = 5;
+/// File the exploration path (undo history) is exported to and replayed from
+const VIEW_PATH_FILE: &str = "images/view_path.json";
+const GALLERY_WIDTH: u32 = 800;
+const GALLERY_HEIGHT: u32 = 600;
+const BENCH_WIDTH: u32 = 640;
+const BENCH_HEIGHT: u32 = 480;
+const BENCH_ITERATIONS: u32 = 500;
+/// Times the scripted zoom sequence is rendered
+const BENCH_RUNS: usize = 3;
+const BENCH_ZOOM_STEPS: usize = 8;
+const BENCH_ZOOM_FACTOR: f64 = 4.0;
+/// Seahorse valley: detailed at every zoom step
+const BENCH_CENTER: (f64, f64) = (-0.743_643_887, 0.131_825_904);
+const STATS_FILE: &str = "interior_stats.csv";
+/// Julia c values swept by `--interior-stats` (steps per axis)
+const STATS_C_REAL: (f64, f64) = (-2.0, 1.0);
+const STATS_C_IMAG: (f64, f64) = (-1.5, 1.5);
+const STATS_STEPS: u32 = 31;
+/// z plane sampled per c value, and its sample grid per side
+const STATS_REGION: (f64, f64, f64, f64) = (-2.0, 2.0, -2.0, 2.0);
+const STATS_SAMPLES: u32 = 48;
+const STATS_ITERATIONS: u32 = 200;
 const ABOUT_IMAGE_PATH: &str = "images/mandelbrot_grayscale_904x784.png";
 const ABOUT_IMAGE_DISPLAY_WIDTH: f32 = 452.0;
 const ABOUT_IMAGE_DISPLAY_HEIGHT: f32 = 392.0;
@@ -53,7 +208,66 @@ struct AppConfig {
     default_palette: PaletteType,
     supersampling_enabled: bool,
     adaptive_iterations: bool,
+    /// Draft renders while panning/zooming, full quality once idle
+    #[serde(default)]
+    adaptive_quality: bool,
+    /// Lower the adaptive iteration cap to keep renders within budget
+    #[serde(default)]
+    render_budget: bool,
     bookmarks: Vec<Bookmark>,
+    /// RGB color for points inside the set
+    #[serde(default)]
+    interior_color: [u8; 3],
+    /// Render a negative image
+    #[serde(default)]
+    invert_colors: bool,
+    /// RGB outline of the zoom selection rectangle
+    #[serde(default = "default_selection_color")]
+    selection_color: [u8; 3],
+    /// Outline the selection in black or white, whichever contrasts with
+    /// the image under it, instead of `selection_color`
+    #[serde(default)]
+    selection_auto_contrast: bool,
+    /// RGB border around the minimap
+    #[serde(default = "default_minimap_border_color")]
+    minimap_border_color: [u8; 3],
+    /// Brightness/contrast applied to every rendered pixel
+    #[serde(default)]
+    color_adjustment: ColorAdjustment,
+    /// Flash the canvas when a long render or export finishes
+    #[serde(default)]
+    notify_on_completion: bool,
+    /// Save a PNG of the view alongside each new bookmark
+    #[serde(default)]
+    bookmark_screenshots: bool,
+    /// Render at the display's physical pixel resolution on HiDPI screens
+    #[serde(default)]
+    native_dpi: bool,
+    /// Zooming out stops at this zoom level
+    #[serde(default = "default_min_zoom")]
+    min_zoom: f64,
+    /// Round scroll, keyboard and zoom-box zooms to powers of two
+    #[serde(default)]
+    snap_zoom: bool,
+    /// Frames to wait after a zoom-box selection before rendering
+    #[serde(default = "default_render_delay_frames")]
+    render_delay_frames: u32,
+    /// Refuse exports finer than this many f64 ulps per pixel
+    #[serde(default = "default_min_feature_ulps")]
+    min_feature_ulps: f64,
+    /// Refuse exports larger than this many megapixels
+    #[serde(default = "default_export_megapixel_cap")]
+    export_megapixel_cap: f64,
+    /// Undo steps kept per fractal
+    #[serde(default = "default_history_size")]
+    history_size: usize,
+    /// Last view of each fractal type. Only the coloring (palette, color
+    /// processor and its parameters) is restored on startup.
+    #[serde(default)]
+    views: HashMap<FractalType, FractalViewState>,
+    /// Color stops of the custom palette
+    #[serde(default)]
+    custom_palette: Vec<[u8; 3]>,
 }
 
 impl Default for AppConfig {
@@ -66,7 +280,26 @@ impl Default for AppConfig {
             default_palette: PaletteType::Classic,
             supersampling_enabled: false,
             adaptive_iterations: false,
+            adaptive_quality: false,
+            render_budget: false,
             bookmarks: Vec::new(),
+            interior_color: [0, 0, 0],
+            invert_colors: false,
+            selection_color: default_selection_color(),
+            selection_auto_contrast: false,
+            minimap_border_color: default_minimap_border_color(),
+            color_adjustment: ColorAdjustment::default(),
+            notify_on_completion: false,
+            bookmark_screenshots: false,
+            native_dpi: false,
+            min_zoom: DEFAULT_MIN_ZOOM,
+            snap_zoom: false,
+            render_delay_frames: RENDER_DELAY_FRAMES,
+            min_feature_ulps: DEFAULT_MIN_FEATURE_ULPS,
+            export_megapixel_cap: DEFAULT_EXPORT_MEGAPIXEL_CAP,
+            history_size: UNDO_HISTORY_CAPACITY,
+            views: HashMap::new(),
+            custom_palette: Vec::new(),
         }
     }
 }
@@ -76,15 +309,213 @@ impl AppConfig {
         dirs::config_dir().map(|dir| dir.join("fractal-oxide").join("config.json"))
     }
 
-    fn load() -> Self {
-        if let Some(path) = Self::config_path() {
-            if let Ok(contents) = std::fs::read_to_string(&path) {
-                if let Ok(config) = serde_json::from_str(&contents) {
-                    return config;
+    /// Load the config from disk, returning it along with any warnings about
+    /// values that had to be repaired or discarded.
+    fn load() -> (Self, Vec<String>) {
+        let Some(contents) = Self::config_path().and_then(|p| std::fs::read_to_string(p).ok())
+        else {
+            return (Self::default(), Vec::new());
+        };
+        Self::from_json(&contents)
+    }
+
+    /// Parse and validate config JSON.
+    ///
+    /// If the file does not deserialize cleanly (e.g. an unknown fractal or
+    /// palette name), each field and bookmark is salvaged individually so a
+    /// single bad entry does not wipe out the rest of the config.
+    fn from_json(contents: &str) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let mut config = match serde_json::from_str::<AppConfig>(contents) {
+            Ok(config) => config,
+            Err(e) => {
+                warnings.push(format!("Config partially invalid ({})", e));
+                Self::salvage(contents, &mut warnings)
+            }
+        };
+        warnings.extend(config.sanitize());
+        (config, warnings)
+    }
+
+    /// Recover whatever fields are still readable from a malformed config.
+    fn salvage(contents: &str, warnings: &mut Vec<String>) -> Self {
+        let mut config = Self::default();
+        let Ok(serde_json::Value::Object(obj)) = serde_json::from_str(contents) else {
+            return config;
+        };
+
+        fn field<T: serde::de::DeserializeOwned>(
+            obj: &serde_json::Map<String, serde_json::Value>,
+            key: &str,
+        ) -> Option<T> {
+            obj.get(key)
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+        }
+
+        if let Some(v) = field(&obj, "window_width") {
+            config.window_width = v;
+        }
+        if let Some(v) = field(&obj, "window_height") {
+            config.window_height = v;
+        }
+        if let Some(v) = field(&obj, "default_iterations") {
+            config.default_iterations = v;
+        }
+        if let Some(v) = field(&obj, "default_fractal") {
+            config.default_fractal = v;
+        }
+        if let Some(v) = field(&obj, "default_palette") {
+            config.default_palette = v;
+        }
+        if let Some(v) = field(&obj, "supersampling_enabled") {
+            config.supersampling_enabled = v;
+        }
+        if let Some(v) = field(&obj, "native_dpi") {
+            config.native_dpi = v;
+        }
+        if let Some(v) = field(&obj, "min_zoom") {
+            config.min_zoom = v;
+        }
+        if let Some(v) = field(&obj, "snap_zoom") {
+            config.snap_zoom = v;
+        }
+        if let Some(v) = field(&obj, "render_delay_frames") {
+            config.render_delay_frames = v;
+        }
+        if let Some(v) = field(&obj, "min_feature_ulps") {
+            config.min_feature_ulps = v;
+        }
+        if let Some(v) = field(&obj, "export_megapixel_cap") {
+            config.export_megapixel_cap = v;
+        }
+        if let Some(v) = field(&obj, "history_size") {
+            config.history_size = v;
+        }
+        if let Some(v) = field(&obj, "adaptive_iterations") {
+            config.adaptive_iterations = v;
+        }
+        if let Some(v) = field(&obj, "adaptive_quality") {
+            config.adaptive_quality = v;
+        }
+        if let Some(v) = field(&obj, "render_budget") {
+            config.render_budget = v;
+        }
+        if let Some(v) = field(&obj, "interior_color") {
+            config.interior_color = v;
+        }
+        if let Some(v) = field(&obj, "selection_color") {
+            config.selection_color = v;
+        }
+        if let Some(v) = field(&obj, "selection_auto_contrast") {
+            config.selection_auto_contrast = v;
+        }
+        if let Some(v) = field(&obj, "minimap_border_color") {
+            config.minimap_border_color = v;
+        }
+        if let Some(v) = field(&obj, "invert_colors") {
+            config.invert_colors = v;
+        }
+        if let Some(v) = field(&obj, "color_adjustment") {
+            config.color_adjustment = v;
+        }
+        if let Some(v) = field(&obj, "notify_on_completion") {
+            config.notify_on_completion = v;
+        }
+        if let Some(v) = field(&obj, "bookmark_screenshots") {
+            config.bookmark_screenshots = v;
+        }
+        if let Some(v) = field(&obj, "custom_palette") {
+            config.custom_palette = v;
+        }
+        if let Some(serde_json::Value::Object(entries)) = obj.get("views") {
+            for (key, value) in entries {
+                let fractal_type =
+                    serde_json::from_value::<FractalType>(serde_json::Value::String(key.clone()));
+                match (fractal_type, serde_json::from_value(value.clone())) {
+                    (Ok(ft), Ok(view)) => {
+                        config.views.insert(ft, view);
+                    }
+                    _ => warnings.push(format!("Dropped unreadable view for '{}'", key)),
+                }
+            }
+        }
+        if let Some(serde_json::Value::Array(entries)) = obj.get("bookmarks") {
+            for entry in entries {
+                match serde_json::from_value::<Bookmark>(entry.clone()) {
+                    Ok(bookmark) => config.bookmarks.push(bookmark),
+                    Err(e) => warnings.push(format!("Dropped unreadable bookmark ({})", e)),
                 }
             }
         }
-        Self::default()
+        config
+    }
+
+    /// Clamp out-of-range values to sane defaults. Returns a warning for
+    /// each value that was changed.
+    fn sanitize(&mut self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !self.window_width.is_finite() || self.window_width < MIN_WINDOW_SIZE {
+            self.window_width = DEFAULT_WINDOW_WIDTH;
+            warnings.push("Invalid window width reset".to_string());
+        }
+        if !self.window_height.is_finite() || self.window_height < MIN_WINDOW_SIZE {
+            self.window_height = DEFAULT_WINDOW_HEIGHT;
+            warnings.push("Invalid window height reset".to_string());
+        }
+        if !(MIN_ITERATIONS..=MAX_ITERATIONS_CAP).contains(&self.default_iterations) {
+            self.default_iterations = self
+                .default_iterations
+                .clamp(MIN_ITERATIONS, MAX_ITERATIONS_CAP);
+            warnings.push("Default iterations clamped".to_string());
+        }
+        if !(MIN_ZOOM_LIMIT..=1.0).contains(&self.min_zoom) {
+            self.min_zoom = DEFAULT_MIN_ZOOM;
+            warnings.push("Invalid minimum zoom reset".to_string());
+        }
+        if self.render_delay_frames > MAX_RENDER_DELAY_FRAMES {
+            self.render_delay_frames = MAX_RENDER_DELAY_FRAMES;
+            warnings.push("Render delay clamped".to_string());
+        }
+        if !(0.0..=MAX_MIN_FEATURE_ULPS).contains(&self.min_feature_ulps) {
+            self.min_feature_ulps = DEFAULT_MIN_FEATURE_ULPS;
+            warnings.push("Invalid export precision limit reset".to_string());
+        }
+        if !(MIN_EXPORT_MEGAPIXEL_CAP..=MAX_EXPORT_MEGAPIXEL_CAP)
+            .contains(&self.export_megapixel_cap)
+        {
+            self.export_megapixel_cap = DEFAULT_EXPORT_MEGAPIXEL_CAP;
+            warnings.push("Invalid export size limit reset".to_string());
+        }
+        if !(MIN_HISTORY_SIZE..=MAX_HISTORY_SIZE).contains(&self.history_size) {
+            self.history_size = self.history_size.clamp(MIN_HISTORY_SIZE, MAX_HISTORY_SIZE);
+            warnings.push("History size clamped".to_string());
+        }
+        let adjustment = &mut self.color_adjustment;
+        if !(-MAX_BRIGHTNESS..=MAX_BRIGHTNESS).contains(&adjustment.brightness) {
+            adjustment.brightness = 0.0;
+            warnings.push("Invalid brightness reset".to_string());
+        }
+        if !(0.0..=MAX_CONTRAST).contains(&adjustment.contrast) {
+            adjustment.contrast = 1.0;
+            warnings.push("Invalid contrast reset".to_string());
+        }
+        let curves = &mut adjustment.curves;
+        for curve in [&mut curves.red, &mut curves.green, &mut curves.blue] {
+            if !curve.values.iter().all(|v| (0.0..=1.0).contains(v)) {
+                *curve = Default::default();
+                warnings.push("Invalid color curve reset".to_string());
+            }
+        }
+        if self.custom_palette.len() > MAX_CUSTOM_PALETTE_STOPS {
+            self.custom_palette.truncate(MAX_CUSTOM_PALETTE_STOPS);
+            warnings.push("Custom palette truncated".to_string());
+        }
+        for bookmark in &mut self.bookmarks {
+            if bookmark.sanitize() {
+                warnings.push(format!("Bookmark '{}' repaired", bookmark.name));
+            }
+        }
+        warnings
     }
 
     fn save(&self) -> Result<(), String> {
@@ -99,7 +530,7 @@ impl AppConfig {
 }
 
 /// Bookmark for saving interesting locations
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 struct Bookmark {
     name: String,
     fractal_type: FractalType,
@@ -112,9 +543,178 @@ struct Bookmark {
     color_processor_type: color_pipeline::ColorProcessorType,
     #[serde(default)]
     fractal_params: HashMap<String, f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hp_center: Option<HpCenter>,
+    /// Parameters of the color processor (e.g. orbit trap threshold)
+    #[serde(default)]
+    processor_params: HashMap<String, f64>,
+}
+
+impl Bookmark {
+    /// Replace non-finite or out-of-range values with defaults so a
+    /// hand-edited bookmark cannot produce a degenerate view.
+    /// Returns true if anything was changed.
+    fn sanitize(&mut self) -> bool {
+        let mut repaired = false;
+        if !self.zoom.is_finite() || self.zoom <= 0.0 {
+            self.zoom = 1.0;
+            repaired = true;
+        }
+        if !self.center_x.is_finite() || !self.center_y.is_finite() {
+            let (x, y) = self.fractal_type.default_center();
+            self.center_x = x;
+            self.center_y = y;
+            // A high-precision center would disagree with the reset one
+            self.hp_center = None;
+            repaired = true;
+        }
+        if !(MIN_ITERATIONS..=MAX_ITERATIONS_CAP).contains(&self.max_iterations) {
+            self.max_iterations = self
+                .max_iterations
+                .clamp(MIN_ITERATIONS, MAX_ITERATIONS_CAP);
+            repaired = true;
+        }
+        let param_count = self.fractal_params.len() + self.processor_params.len();
+        self.fractal_params.retain(|_, v| v.is_finite());
+        self.processor_params.retain(|_, v| v.is_finite());
+        if self.fractal_params.len() + self.processor_params.len() != param_count {
+            repaired = true;
+        }
+        repaired
+    }
+}
+
+impl FractalViewState {
+    /// Same repairs as `Bookmark::sanitize`, for views read from a file.
+    /// Returns true if anything was changed.
+    fn sanitize(&mut self, fractal_type: FractalType) -> bool {
+        let mut repaired = false;
+        if !self.zoom.is_finite() || self.zoom <= 0.0 {
+            self.zoom = 1.0;
+            repaired = true;
+        }
+        if !self.center_x.is_finite() || !self.center_y.is_finite() {
+            (self.center_x, self.center_y) = fractal_type.default_center();
+            self.hp_center = None;
+            repaired = true;
+        }
+        if !(MIN_ITERATIONS..=MAX_ITERATIONS_CAP).contains(&self.max_iterations) {
+            self.max_iterations = self
+                .max_iterations
+                .clamp(MIN_ITERATIONS, MAX_ITERATIONS_CAP);
+            repaired = true;
+        }
+        let param_count = self.fractal_params.len() + self.processor_params.len();
+        self.fractal_params.retain(|_, v| v.is_finite());
+        self.processor_params.retain(|_, v| v.is_finite());
+        if self.fractal_params.len() + self.processor_params.len() != param_count {
+            repaired = true;
+        }
+        repaired
+    }
+}
+
+/// Repair the views carried by a replayed view path step, `fractal_type`
+/// being the fractal shown when it runs. Returns true if anything changed.
+fn sanitize_descriptor(descriptor: &mut CommandDescriptor, fractal_type: FractalType) -> bool {
+    match descriptor {
+        CommandDescriptor::View {
+            old_center_x,
+            old_center_y,
+            old_zoom,
+            new_center_x,
+            new_center_y,
+            new_zoom,
+        } => {
+            let mut repaired = false;
+            for (x, y, zoom) in [
+                (old_center_x, old_center_y, old_zoom),
+                (new_center_x, new_center_y, new_zoom),
+            ] {
+                let mut view = FractalViewState {
+                    center_x: *x,
+                    center_y: *y,
+                    zoom: *zoom,
+                    max_iterations: MIN_ITERATIONS,
+                    ..Default::default()
+                };
+                repaired |= view.sanitize(fractal_type);
+                (*x, *y, *zoom) = (view.center_x, view.center_y, view.zoom);
+            }
+            repaired
+        }
+        CommandDescriptor::FractalType {
+            old_type,
+            new_type,
+            old_view,
+            new_view,
+        } => old_view.sanitize(*old_type) | new_view.sanitize(*new_type),
+        CommandDescriptor::Iteration {
+            old_iterations,
+            new_iterations,
+        } => {
+            let mut repaired = false;
+            for iterations in [old_iterations, new_iterations] {
+                let clamped = (*iterations).clamp(MIN_ITERATIONS, MAX_ITERATIONS_CAP);
+                repaired |= clamped != *iterations;
+                *iterations = clamped;
+            }
+            repaired
+        }
+        CommandDescriptor::Parameter { .. } | CommandDescriptor::Palette { .. } => false,
+    }
+}
+
+/// Write bookmarks to a standalone JSON file for sharing
+fn write_bookmarks(path: &Path, bookmarks: &[Bookmark]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(bookmarks)
+        .map_err(|e| format!("Failed to serialize bookmarks: {}", e))?;
+    std::fs::write(path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
 }
 
-#[derive(Debug, Clone, Default)]
+/// File name (without extension) for a bookmark's screenshot: characters
+/// that aren't safe in file names become underscores
+fn bookmark_file_stem(name: &str) -> String {
+    let stem: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if stem.is_empty() {
+        "bookmark".to_string()
+    } else {
+        stem
+    }
+}
+
+/// First `<stem>.png`, `<stem>_2.png`, ... in `dir` that doesn't exist
+/// yet, so bookmarks whose names share a stem keep separate screenshots
+fn unused_png_path(dir: &Path, stem: &str) -> PathBuf {
+    let path = dir.join(format!("{}.png", stem));
+    if !path.exists() {
+        return path;
+    }
+    (2..)
+        .map(|index| dir.join(format!("{}_{}.png", stem, index)))
+        .find(|path| !path.exists())
+        .expect("some index is unused")
+}
+
+/// Read a bookmark file written by `write_bookmarks`
+fn read_bookmarks(path: &Path) -> Result<Vec<Bookmark>, String> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&json).map_err(|e| format!("Invalid bookmark file: {}", e))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FractalViewState {
     pub center_x: f64,
     pub center_y: f64,
@@ -123,6 +723,513 @@ pub struct FractalViewState {
     pub fractal_params: HashMap<String, f64>,
     pub palette_type: PaletteType,
     pub color_processor_type: color_pipeline::ColorProcessorType,
+    /// Optional high-precision center for deep zoom; center_x/center_y
+    /// remain the f64 values used for display and rendering
+    pub hp_center: Option<HpCenter>,
+    /// Color processor parameter overrides (processor defaults otherwise)
+    pub processor_params: HashMap<String, f64>,
+}
+
+/// Channel depth of exported PNGs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum ExportBitDepth {
+    /// 8-bit RGB(A) through the palette and color pipeline
+    #[default]
+    Eight,
+    /// 16-bit grayscale of the smooth iteration field
+    Sixteen,
+}
+
+/// Everything needed to render and save one export, captured when it is
+/// queued so later changes to the live view don't affect it
+struct ExportRequest {
+    fractal: Box<dyn Fractal>,
+    view: FractalViewState,
+    path: PathBuf,
+    /// Output size, iterations and coloring
+    config: RenderConfig,
+    supersample_factor: u32,
+    /// RGBA with a transparent interior (8-bit only)
+    transparent: bool,
+    /// Stream rows straight into the PNG file instead of holding the
+    /// image in memory (opaque 8-bit exports only)
+    tiled: bool,
+    /// Sixteen ignores the palette and coloring
+    bit_depth: ExportBitDepth,
+}
+
+impl ExportRequest {
+    /// Render and save the image. Ok(None) if cancelled via `control`.
+    fn run(self, control: &ExportControl) -> Result<Option<PathBuf>, String> {
+        let (width, height) = (self.config.width, self.config.height);
+        if self.bit_depth == ExportBitDepth::Sixteen {
+            let Some(levels) = renderer::render_high_res_gray16(
+                self.fractal.as_ref(),
+                &self.view,
+                &self.config,
+                self.supersample_factor,
+                control,
+            ) else {
+                return Ok(None);
+            };
+            let img: ImageBuffer<Luma<u16>, Vec<u16>> =
+                ImageBuffer::from_raw(width, height, levels)
+                    .ok_or("Failed to build 16-bit image buffer")?;
+            img.save(&self.path)
+                .map_err(|e| format!("Failed to save image: {}", e))?;
+        } else if self.transparent {
+            let Some(pixels) = renderer::render_high_res_rgba(
+                self.fractal.as_ref(),
+                &self.view,
+                &self.config,
+                self.supersample_factor,
+                control,
+            ) else {
+                return Ok(None);
+            };
+            let raw: Vec<u8> = pixels.into_iter().flatten().collect();
+            let img: ImageBuffer<Rgba<u8>, Vec<u8>> = ImageBuffer::from_raw(width, height, raw)
+                .ok_or("Failed to build RGBA image buffer")?;
+            img.save(&self.path)
+                .map_err(|e| format!("Failed to save image: {}", e))?;
+        } else if self.tiled {
+            return self.run_tiled(control);
+        } else {
+            let Some(pixels) = RenderEngine::default().render_high_res(
+                self.fractal.as_ref(),
+                &self.view,
+                &self.config,
+                self.supersample_factor,
+                control,
+            ) else {
+                return Ok(None);
+            };
+            pixels_to_rgb_image(&pixels, width, height)
+                .save(&self.path)
+                .map_err(|e| format!("Failed to save image: {}", e))?;
+        }
+        Ok(Some(self.path))
+    }
+
+    /// Render in row batches, writing each batch to the PNG encoder as it
+    /// completes, so memory use stays at one batch regardless of size. A
+    /// cancelled export removes its partial file.
+    fn run_tiled(self, control: &ExportControl) -> Result<Option<PathBuf>, String> {
+        use std::io::Write;
+
+        let file = std::fs::File::create(&self.path)
+            .map_err(|e| format!("Failed to create {}: {}", self.path.display(), e))?;
+        let mut encoder = png::Encoder::new(
+            std::io::BufWriter::new(file),
+            self.config.width,
+            self.config.height,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut stream = encoder
+            .write_header()
+            .and_then(|writer| writer.into_stream_writer())
+            .map_err(|e| format!("Failed to start PNG: {}", e))?;
+
+        let finished = RenderEngine::default().render_high_res_streamed(
+            self.fractal.as_ref(),
+            &self.view,
+            &self.config,
+            self.supersample_factor,
+            control,
+            |batch| {
+                let bytes: Vec<u8> = batch.iter().flat_map(|c| [c.r(), c.g(), c.b()]).collect();
+                stream
+                    .write_all(&bytes)
+                    .map_err(|e| format!("Failed to write image: {}", e))
+            },
+        )?;
+        if !finished {
+            drop(stream);
+            let _ = std::fs::remove_file(&self.path);
+            return Ok(None);
+        }
+        stream
+            .finish()
+            .map_err(|e| format!("Failed to finish image: {}", e))?;
+        Ok(Some(self.path))
+    }
+}
+
+/// A Julia view beside its parameter plane with the seed marked, captured
+/// when queued like `ExportRequest`
+struct JuliaSeedRequest {
+    fractal: Box<dyn Fractal>,
+    /// Parameter plane the seed is picked from (e.g. the Mandelbrot set)
+    space: Box<dyn Fractal>,
+    view: FractalViewState,
+    seed: (f64, f64),
+    path: PathBuf,
+    /// Julia panel settings; the parameter plane is a square of its height
+    config: RenderConfig,
+    supersample_factor: u32,
+}
+
+impl JuliaSeedRequest {
+    /// Render both panels and save the poster. Ok(None) if cancelled.
+    fn run(self, control: &ExportControl) -> Result<Option<PathBuf>, String> {
+        let height = self.config.height;
+        let seed_view = FractalViewState {
+            center_x: 0.0,
+            center_y: 0.0,
+            zoom: 4.0 / PARAMETER_MAP_RANGE,
+            hp_center: None,
+            ..self.view.clone()
+        };
+        let seed_config = RenderConfig {
+            width: height,
+            ..self.config.clone()
+        };
+        // Progress runs across both panels in proportion to their pixels
+        let seed_share = height as f32 / (self.config.width + height) as f32;
+        let engine = RenderEngine::default();
+        let render = |fractal: &dyn Fractal,
+                      view: &FractalViewState,
+                      config: &RenderConfig,
+                      start: f32,
+                      span: f32| {
+            let progress = |fraction: f32| (control.progress)(start + fraction * span);
+            let panel_control = ExportControl {
+                progress: &progress,
+                cancel: control.cancel,
+            };
+            engine
+                .render_high_res(
+                    fractal,
+                    view,
+                    config,
+                    self.supersample_factor,
+                    &panel_control,
+                )
+                .map(|pixels| pixels_to_rgb_image(&pixels, config.width, config.height))
+        };
+        let Some(mut seed_panel) = render(
+            self.space.as_ref(),
+            &seed_view,
+            &seed_config,
+            0.0,
+            seed_share,
+        ) else {
+            return Ok(None);
+        };
+        let Some(julia_panel) = render(
+            self.fractal.as_ref(),
+            &self.view,
+            &self.config,
+            seed_share,
+            1.0 - seed_share,
+        ) else {
+            return Ok(None);
+        };
+
+        let (c_re, c_im) = self.seed;
+        let (u, v) = seed_to_parameter_map(c_re, c_im);
+        composite::mark_point(
+            &mut seed_panel,
+            (u * height as f64).floor() as i64,
+            (v * height as f64).floor() as i64,
+        );
+        let caption = format!(
+            "c = {:.5} {} {:.5}i",
+            c_re,
+            if c_im < 0.0 { "-" } else { "+" },
+            c_im.abs()
+        );
+        composite::compose(&seed_panel, &julia_panel, &caption)
+            .save(&self.path)
+            .map_err(|e| format!("Failed to save image: {}", e))?;
+        Ok(Some(self.path))
+    }
+}
+
+/// Keyframe animation frames, captured when queued like `ExportRequest`
+struct AnimationRequest {
+    fractal: Box<dyn Fractal>,
+    /// Interpolated view of each frame
+    views: Vec<FractalViewState>,
+    dir: PathBuf,
+    /// Size and settings shared by every frame; iterations, palette and
+    /// coloring come from each frame's view
+    config: RenderConfig,
+    trap_image: Option<Arc<color_pipeline::TrapImage>>,
+    custom_palette: Option<Arc<palette::CustomPalette>>,
+    /// Pinned color range, used for every frame if set
+    color_range: Option<(u32, u32)>,
+    /// Without a pinned range, map every frame over the most zoomed-out
+    /// frame's iteration range so colors don't flicker
+    lock_colors: bool,
+    supersample_factor: u32,
+}
+
+impl AnimationRequest {
+    /// Render and save each frame. Ok(None) if cancelled, keeping the
+    /// frames already written.
+    fn run(mut self, control: &ExportControl) -> Result<Option<PathBuf>, String> {
+        let (width, height) = (self.config.width, self.config.height);
+        let color_range = self.color_range.or_else(|| {
+            self.lock_colors
+                .then(|| {
+                    animation_color_range(
+                        self.fractal.as_mut(),
+                        &self.views,
+                        width as f64 / height as f64,
+                    )
+                })
+                .flatten()
+        });
+
+        let frame_count = self.views.len().max(1) as f32;
+        for (frame, view) in self.views.iter().enumerate() {
+            for (name, value) in &view.fractal_params {
+                self.fractal.set_parameter(name, *value);
+            }
+            let config = RenderConfig {
+                max_iterations: view.max_iterations,
+                palette_type: view.palette_type,
+                color_pipeline: color_pipeline::ColorPipeline::from_type_with_params(
+                    view.color_processor_type,
+                    &view.processor_params,
+                )
+                .with_iteration_range(color_range)
+                .with_trap_image(self.trap_image.clone())
+                .with_custom_palette(self.custom_palette.clone()),
+                ..self.config.clone()
+            };
+            let progress =
+                |fraction: f32| (control.progress)((frame as f32 + fraction) / frame_count);
+            let frame_control = ExportControl {
+                progress: &progress,
+                cancel: control.cancel,
+            };
+            let Some(pixels) = RenderEngine::default().render_high_res(
+                self.fractal.as_ref(),
+                view,
+                &config,
+                self.supersample_factor,
+                &frame_control,
+            ) else {
+                return Ok(None);
+            };
+
+            pixels_to_rgb_image(&pixels, width, height)
+                .save(self.dir.join(format!("frame_{:04}.png", frame)))
+                .map_err(|e| format!("Failed to save frame {}: {}", frame, e))?;
+        }
+        Ok(Some(self.dir))
+    }
+}
+
+/// Iteration range of the most zoomed-out of `views`, probed at
+/// `aspect_ratio`, or None if no probed point escapes
+fn animation_color_range(
+    fractal: &mut dyn Fractal,
+    views: &[FractalViewState],
+    aspect_ratio: f64,
+) -> Option<(u32, u32)> {
+    let widest = views.iter().min_by(|a, b| a.zoom.total_cmp(&b.zoom))?;
+    for (name, value) in &widest.fractal_params {
+        fractal.set_parameter(name, *value);
+    }
+    let width = COLOR_LOCK_PROBE_WIDTH;
+    let height = ((width as f64 / aspect_ratio).round() as u32).max(1);
+    renderer::escape_iteration_range(fractal, widest, width, height, widest.max_iterations)
+        .map(|(lo, hi)| (lo, hi.max(lo + 1)))
+}
+
+/// Any job the export queue can run
+enum ExportTask {
+    Image(ExportRequest),
+    JuliaSeed(JuliaSeedRequest),
+    Animation(AnimationRequest),
+}
+
+impl ExportTask {
+    /// File (or, for animations, directory) the job writes
+    fn path(&self) -> &Path {
+        match self {
+            ExportTask::Image(request) => &request.path,
+            ExportTask::JuliaSeed(request) => &request.path,
+            ExportTask::Animation(request) => &request.dir,
+        }
+    }
+
+    fn run(self, control: &ExportControl) -> Result<Option<PathBuf>, String> {
+        match self {
+            ExportTask::Image(request) => request.run(control),
+            ExportTask::JuliaSeed(request) => request.run(control),
+            ExportTask::Animation(request) => request.run(control),
+        }
+    }
+}
+
+impl From<ExportRequest> for ExportTask {
+    fn from(request: ExportRequest) -> Self {
+        ExportTask::Image(request)
+    }
+}
+
+impl From<JuliaSeedRequest> for ExportTask {
+    fn from(request: JuliaSeedRequest) -> Self {
+        ExportTask::JuliaSeed(request)
+    }
+}
+
+impl From<AnimationRequest> for ExportTask {
+    fn from(request: AnimationRequest) -> Self {
+        ExportTask::Animation(request)
+    }
+}
+
+/// A high-resolution export running on a background thread
+struct ExportJob {
+    path: PathBuf,
+    /// Completed fraction, stored as f32 bits
+    progress: Arc<AtomicU32>,
+    cancel: Arc<AtomicBool>,
+    started: Instant,
+    /// Saved path, or None if the export was cancelled
+    handle: std::thread::JoinHandle<Result<Option<PathBuf>, String>>,
+}
+
+impl ExportJob {
+    fn spawn(task: ExportTask) -> Self {
+        let path = task.path().to_path_buf();
+        let progress = Arc::new(AtomicU32::new(0));
+        let cancel = Arc::new(AtomicBool::new(false));
+        let (job_progress, job_cancel) = (progress.clone(), cancel.clone());
+
+        let handle = std::thread::spawn(move || {
+            let report = |fraction: f32| job_progress.store(fraction.to_bits(), Ordering::Relaxed);
+            task.run(&ExportControl {
+                progress: &report,
+                cancel: &job_cancel,
+            })
+        });
+
+        Self {
+            path,
+            progress,
+            cancel,
+            started: Instant::now(),
+            handle,
+        }
+    }
+
+    fn progress(&self) -> f32 {
+        f32::from_bits(self.progress.load(Ordering::Relaxed))
+    }
+}
+
+/// Outcome of a finished export, kept for the queue's "recent" list
+struct FinishedExport {
+    path: PathBuf,
+    /// Status line describing the result
+    message: String,
+    elapsed_secs: f64,
+}
+
+/// Exports waiting for, or running on, the single background worker.
+/// Jobs run one at a time in the order they were queued.
+#[derive(Default)]
+struct ExportQueue {
+    pending: VecDeque<ExportTask>,
+    running: Option<ExportJob>,
+    /// Most recent last, capped at EXPORT_QUEUE_HISTORY
+    finished: Vec<FinishedExport>,
+}
+
+impl ExportQueue {
+    fn push(&mut self, task: impl Into<ExportTask>) {
+        self.pending.push_back(task.into());
+        self.start_next();
+    }
+
+    fn is_busy(&self) -> bool {
+        self.running.is_some() || !self.pending.is_empty()
+    }
+
+    fn start_next(&mut self) {
+        if self.running.is_none() {
+            self.running = self.pending.pop_front().map(ExportJob::spawn);
+        }
+    }
+
+    /// Collect the running job if it has finished and start the next one.
+    /// Returns the finished job's status message.
+    fn poll(&mut self) -> Option<String> {
+        if !self
+            .running
+            .as_ref()
+            .is_some_and(|job| job.handle.is_finished())
+        {
+            return None;
+        }
+        let job = self.running.take()?;
+        let elapsed_secs = job.started.elapsed().as_secs_f64();
+        let message = match job.handle.join() {
+            Ok(Ok(Some(path))) => format!("Saved: {}", path.display()),
+            Ok(Ok(None)) => "Export cancelled".to_string(),
+            Ok(Err(e)) => format!("Error: {}", e),
+            Err(_) => "Error: export failed unexpectedly".to_string(),
+        };
+        self.finished.push(FinishedExport {
+            path: job.path,
+            message: message.clone(),
+            elapsed_secs,
+        });
+        if self.finished.len() > EXPORT_QUEUE_HISTORY {
+            self.finished.remove(0);
+        }
+        self.start_next();
+        Some(message)
+    }
+
+    /// Cancel the running job; queued jobs still run afterwards
+    fn cancel_current(&self) {
+        if let Some(job) = &self.running {
+            job.cancel.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Drop every job that hasn't started yet
+    fn clear_pending(&mut self) {
+        self.pending.clear();
+    }
+}
+
+/// Waits for the canvas size to settle during a window resize so the view is
+/// re-rendered once rather than on every frame of the drag
+#[derive(Default)]
+struct ResizeDebounce {
+    size: (u32, u32),
+    stable_frames: u32,
+}
+
+impl ResizeDebounce {
+    /// Feed this frame's canvas size. True on the frame the size has held
+    /// for `RESIZE_SETTLE_FRAMES` while differing from the rendered size.
+    fn update(&mut self, size: (u32, u32), rendered: (u32, u32)) -> bool {
+        if size != self.size {
+            self.size = size;
+            self.stable_frames = 0;
+            return false;
+        }
+        if size == rendered || self.stable_frames >= RESIZE_SETTLE_FRAMES {
+            return false;
+        }
+        self.stable_frames += 1;
+        self.stable_frames == RESIZE_SETTLE_FRAMES
+    }
+
+    /// A resize is waiting for the size to settle
+    fn pending(&self, rendered: (u32, u32)) -> bool {
+        self.size != rendered && self.stable_frames < RESIZE_SETTLE_FRAMES
+    }
 }
 
 /// State related to fractal rendering (engine, config, progress, caches)
@@ -130,6 +1237,11 @@ struct RenderState {
     engine: RenderEngine,
     config: Option<RenderConfig>,
     needs_render: bool,
+    /// Re-render as soon as anything changes; when off, changes only set
+    /// `render_pending` until `render_now`
+    auto_render: bool,
+    /// A change is waiting for an explicit render (auto-render off)
+    render_pending: bool,
     is_rendering: bool,
     render_progress: f32,
     render_start_time: Option<Instant>,
@@ -140,6 +1252,12 @@ struct RenderState {
     current_region_index: usize,
     /// Delay rendering by N frames (for zoom preview)
     render_delay: u32,
+    /// Frames `render_delay` starts at after a zoom-box selection; 0
+    /// renders immediately
+    zoom_render_delay: u32,
+    /// Canvas render size the last render was started for
+    canvas_size: (u32, u32),
+    resize_debounce: ResizeDebounce,
     /// Cached fractal image pixels
     cached_image: Option<egui::ColorImage>,
     /// GPU texture handle (only recreated when dirty)
@@ -150,7 +1268,21 @@ struct RenderState {
     cached_width: u32,
     cached_height: u32,
     supersampling_enabled: bool,
+    /// Render at device pixels rather than logical points
+    native_dpi: bool,
     adaptive_iterations: bool,
+    /// Draft renders during interaction, full quality once idle
+    adaptive_quality: bool,
+    /// Cap adaptive iterations so full renders take about `RENDER_BUDGET_SECS`
+    render_budget: bool,
+    /// Adaptive iteration cap set by the render budget
+    iteration_cap: u32,
+    /// Quality of the render in progress or last completed
+    quality: RenderQuality,
+    /// A parameter slider is being dragged with live preview on
+    parameter_preview: bool,
+    /// Use the GPU backend for eligible renders (`gpu` feature builds only)
+    gpu_enabled: bool,
 }
 
 impl Default for RenderState {
@@ -159,6 +1291,8 @@ impl Default for RenderState {
             engine: RenderEngine::default(),
             config: None,
             needs_render: true,
+            auto_render: true,
+            render_pending: false,
             is_rendering: false,
             render_progress: 0.0,
             render_start_time: None,
@@ -167,6 +1301,9 @@ impl Default for RenderState {
             partial_render_regions: Vec::new(),
             current_region_index: 0,
             render_delay: 0,
+            zoom_render_delay: RENDER_DELAY_FRAMES,
+            canvas_size: (0, 0),
+            resize_debounce: ResizeDebounce::default(),
             cached_image: None,
             cached_texture: None,
             texture_dirty: false,
@@ -174,7 +1311,14 @@ impl Default for RenderState {
             cached_width: 0,
             cached_height: 0,
             supersampling_enabled: false,
+            native_dpi: false,
             adaptive_iterations: false,
+            adaptive_quality: false,
+            render_budget: false,
+            iteration_cap: MAX_ITERATIONS_CAP,
+            quality: RenderQuality::Full,
+            parameter_preview: false,
+            gpu_enabled: false,
         }
     }
 }
@@ -186,7 +1330,20 @@ struct InteractionState {
     drag_current: Option<egui::Pos2>,
     zoom_preview: Option<ZoomPreview>,
     mouse_fractal_pos: Option<(f64, f64)>,
+    /// Iteration readout for the point under the cursor
+    mouse_readout: Option<String>,
     status_message: Option<(String, Instant)>,
+    /// High-iteration preview key is held
+    preview_iterations_held: bool,
+    /// Last pan or zoom, for adaptive quality
+    last_interaction: Option<Instant>,
+    /// World points clicked with the measure tool (at most two)
+    measure_points: Vec<(f64, f64)>,
+    /// Palette offset before the current Shift+scroll gesture, and when
+    /// it last scrolled
+    palette_scroll: Option<(f32, Instant)>,
+    /// Scroll distance not yet turned into a snap-zoom step
+    snap_scroll: f32,
 }
 
 struct FractalApp {
@@ -200,10 +1357,114 @@ struct FractalApp {
     show_bookmark_dialog: bool,
     bookmark_name_input: String,
     minimap_enabled: bool,
+    /// Color the minimap with the main view's palette settings and color
+    /// processor instead of the plain palette
+    minimap_full_coloring: bool,
+    /// Draw world-coordinate gridlines over the fractal
+    show_grid: bool,
+    /// Canvas clicks place measure points instead of doing nothing
+    measure_mode: bool,
+    /// Debug overlay: print the iteration count at each cell center
+    show_iteration_labels: bool,
+    /// Overlay the first few Mandelbrot lemniscates |z_n(c)| = 2
+    show_lemniscates: bool,
+    /// Outlines last traced, reused until the view or canvas size changes
+    lemniscate_cache: Option<LemniscateCache>,
+    /// Labels last computed, reused until the view, fractal or iterations
+    /// change
+    iteration_label_cache: Option<IterationLabelCache>,
+    /// Letterbox the canvas to `locked_aspect_ratio` (width / height)
+    lock_aspect: bool,
+    locked_aspect_ratio: f32,
+    /// Zoom-out limit for scroll and keyboard zoom
+    min_zoom: f64,
+    /// Land every interactive zoom on a power of two
+    snap_zoom: bool,
+    /// Exports below this pixel spacing (in f64 ulps) are refused
+    min_feature_ulps: f64,
+    /// Exports above this many megapixels are refused
+    export_megapixel_cap: f64,
+    /// Capacity of each fractal's command history
+    history_size: usize,
+    /// Ignore pan/zoom input so the framing can't be disturbed
+    view_locked: bool,
+    /// Factor applied by the exact zoom in/out buttons
+    exact_zoom_factor: f64,
+    /// Seed for the next "Find interesting spot" search
+    explore_seed: u64,
+    /// Fractal type shown before the current one, for the Tab toggle
+    previous_fractal_type: Option<FractalType>,
     cached_minimap_texture: Option<egui::TextureHandle>,
     minimap_dirty: bool,
+    /// When the minimap fractal was last rendered, for throttling
+    last_minimap_render: Option<Instant>,
+    /// Show the parameter-space map for picking c (Julia, Phoenix)
+    parameter_map_enabled: bool,
+    cached_parameter_map_texture: Option<egui::TextureHandle>,
+    parameter_map_dirty: bool,
     export_scale: u32,
+    /// Export interior (non-escaped) pixels as fully transparent RGBA
+    transparent_interior: bool,
+    /// Stream scaled exports to disk in row bands (for very large images)
+    tiled_export: bool,
+    export_bit_depth: ExportBitDepth,
+    export_queue: ExportQueue,
+    /// Keyframes for parameter/view animation export
+    keyframes: Vec<animation::Keyframe>,
+    animation_frame_count: usize,
+    /// Path the center takes between keyframes
+    zoom_path: animation::ZoomPath,
+    /// Path typed into the image trap loader
+    trap_image_path: String,
+    /// Image sampled by image trap pipelines built for this app
+    trap_image: Option<Arc<color_pipeline::TrapImage>>,
+    /// Image the custom palette is extracted from
+    palette_image_path: String,
+    /// Palette extraction running off the UI thread
+    palette_image_job: Option<std::thread::JoinHandle<Result<palette::CustomPalette, String>>>,
+    /// File bookmarks are exported to and imported from
+    bookmarks_file_path: String,
+    /// Color for points inside the set
+    interior_color: egui::Color32,
+    invert_colors: bool,
+    /// Outline of the zoom selection rectangle
+    selection_color: egui::Color32,
+    /// Pick black or white for the selection by the brightness under it
+    selection_auto_contrast: bool,
+    minimap_border_color: egui::Color32,
+    /// Map colors over the fixed iteration window `color_range` instead of
+    /// the iteration limit, so colors hold still across zoom animations
+    pin_color_range: bool,
+    color_range: (u32, u32),
+    /// Pin animation exports to the iteration range of their most
+    /// zoomed-out frame (ignored while `pin_color_range` is set)
+    lock_animation_colors: bool,
+    color_adjustment: ColorAdjustment,
+    /// `color_adjustment` and `invert_colors` as a table applied to the
+    /// cached render when it is shown or saved
+    color_lut: ColorLut,
+    /// Channel shown in the tone-curve editor (0 = R, 1 = G, 2 = B)
+    curve_channel: usize,
+    /// Flash the canvas when a long render or export finishes
+    notify_on_completion: bool,
+    /// Save a PNG of the view alongside each new bookmark
+    bookmark_screenshots: bool,
+    /// Folder bookmark screenshots are written to
+    bookmark_image_dir: PathBuf,
+    /// Start of the current completion flash
+    completion_flash: Option<Instant>,
+    /// Recent render events and errors, oldest first
+    log: VecDeque<(Instant, String)>,
     show_about_dialog: bool,
+    /// Window comparing the view at two iteration counts
+    show_difference_window: bool,
+    /// Iteration count compared against the current one
+    difference_iterations: u32,
+    cached_difference_texture: Option<egui::TextureHandle>,
+    /// Pixels that changed in the last comparison, and the total
+    difference_changed: (usize, usize),
+    /// Iteration count suggested for the current view, until it changes
+    suggested_iterations: Option<u32>,
     cached_about_texture: Option<egui::TextureHandle>,
     fractal_registry: FractalRegistry,
     viewport: Viewport,
@@ -211,6 +1472,24 @@ struct FractalApp {
     actual_window_height: f32,
 }
 
+/// Lemniscate outlines traced for one Mandelbrot view and canvas size
+struct LemniscateCache {
+    viewport: Viewport,
+    size: (u32, u32),
+    params: HashMap<String, f64>,
+    levels: Vec<Vec<Vec<(f64, f64)>>>,
+}
+
+/// Iteration labels computed for one view, fractal and canvas size
+struct IterationLabelCache {
+    fractal_type: FractalType,
+    viewport: Viewport,
+    size: (u32, u32),
+    params: HashMap<String, f64>,
+    max_iter: u32,
+    labels: Vec<IterationLabel>,
+}
+
 struct ZoomPreview {
     sel_min: egui::Pos2,
     sel_max: egui::Pos2,
@@ -224,39 +1503,44 @@ impl FractalApp {
         // Initialize views from registry metadata
         for ft in registry.all_types() {
             if let Some(metadata) = registry.metadata(ft) {
-                // Phoenix has different default iterations
-                let max_iter = if ft == FractalType::Phoenix {
-                    100
-                } else {
+                // The saved iteration count belongs to the fractal that was
+                // open last; the rest start at their recommended count
+                let max_iter = if ft == config.default_fractal {
                     config.default_iterations
+                } else {
+                    metadata.default_iterations
                 };
-                views.insert(
-                    ft,
-                    FractalViewState {
-                        center_x: metadata.default_center.0,
-                        center_y: metadata.default_center.1,
-                        zoom: metadata.default_zoom,
-                        max_iterations: max_iter,
-                        fractal_params: HashMap::new(),
-                        palette_type: config.default_palette,
-                        color_processor_type: color_pipeline::ColorProcessorType::default(),
-                    },
-                );
+                let mut view = FractalViewState {
+                    center_x: metadata.default_center.0,
+                    center_y: metadata.default_center.1,
+                    zoom: metadata.default_zoom,
+                    max_iterations: max_iter,
+                    fractal_params: HashMap::new(),
+                    palette_type: config.default_palette,
+                    color_processor_type: color_pipeline::ColorProcessorType::default(),
+                    hp_center: None,
+                    processor_params: HashMap::new(),
+                };
+                // Restore the coloring this fractal had last session
+                if let Some(saved) = config.views.get(&ft) {
+                    view.palette_type = saved.palette_type;
+                    view.color_processor_type = saved.color_processor_type;
+                    view.processor_params = saved.processor_params.clone();
+                }
+                views.insert(ft, view);
             }
         }
 
-        // Phoenix has different default iterations
-        let initial_iterations = if config.default_fractal == FractalType::Phoenix {
-            100
-        } else {
-            config.default_iterations
-        };
-
+        let initial_view = views.get(&config.default_fractal);
         let controls = FractalControls {
             fractal_type: config.default_fractal,
-            max_iterations: initial_iterations,
-            pending_max_iterations: initial_iterations,
-            palette_type: config.default_palette,
+            max_iterations: config.default_iterations,
+            pending_max_iterations: config.default_iterations,
+            palette_type: initial_view.map_or(config.default_palette, |view| view.palette_type),
+            color_processor_type: initial_view
+                .map(|view| view.color_processor_type)
+                .unwrap_or_default(),
+            custom_palette: palette::CustomPalette::new(&config.custom_palette).map(Arc::new),
             ..Default::default()
         };
 
@@ -266,7 +1550,11 @@ impl FractalApp {
 
         let render = RenderState {
             supersampling_enabled: config.supersampling_enabled,
+            native_dpi: config.native_dpi,
+            zoom_render_delay: config.render_delay_frames,
             adaptive_iterations: config.adaptive_iterations,
+            adaptive_quality: config.adaptive_quality,
+            render_budget: config.render_budget,
             ..Default::default()
         };
 
@@ -277,7 +1565,7 @@ impl FractalApp {
             command_histories: registry
                 .all_types()
                 .into_iter()
-                .map(|ft| (ft, CommandHistory::new(UNDO_HISTORY_CAPACITY)))
+                .map(|ft| (ft, CommandHistory::new(config.history_size)))
                 .collect(),
             render,
             interaction: InteractionState::default(),
@@ -285,10 +1573,69 @@ impl FractalApp {
             show_bookmark_dialog: false,
             bookmark_name_input: String::new(),
             minimap_enabled: false,
+            minimap_full_coloring: false,
+            show_grid: false,
+            measure_mode: false,
+            show_iteration_labels: false,
+            show_lemniscates: false,
+            lemniscate_cache: None,
+            iteration_label_cache: None,
+            lock_aspect: false,
+            locked_aspect_ratio: 1.0,
+            min_zoom: config.min_zoom,
+            snap_zoom: config.snap_zoom,
+            min_feature_ulps: config.min_feature_ulps,
+            export_megapixel_cap: config.export_megapixel_cap,
+            history_size: config.history_size,
+            view_locked: false,
+            exact_zoom_factor: DEFAULT_EXACT_ZOOM_FACTOR,
+            explore_seed: 0,
+            previous_fractal_type: None,
             cached_minimap_texture: None,
             minimap_dirty: true,
+            last_minimap_render: None,
+            parameter_map_enabled: false,
+            cached_parameter_map_texture: None,
+            parameter_map_dirty: true,
             export_scale: 1,
+            transparent_interior: false,
+            tiled_export: false,
+            export_bit_depth: ExportBitDepth::default(),
+            export_queue: ExportQueue::default(),
+            keyframes: Vec::new(),
+            animation_frame_count: DEFAULT_ANIMATION_FRAMES,
+            zoom_path: animation::ZoomPath::Linear,
+            trap_image_path: String::new(),
+            trap_image: None,
+            palette_image_path: String::new(),
+            palette_image_job: None,
+            bookmarks_file_path: DEFAULT_BOOKMARKS_FILE.to_string(),
+            interior_color: egui::Color32::from_rgb(
+                config.interior_color[0],
+                config.interior_color[1],
+                config.interior_color[2],
+            ),
+            invert_colors: config.invert_colors,
+            selection_color: rgb_color(config.selection_color),
+            selection_auto_contrast: config.selection_auto_contrast,
+            minimap_border_color: rgb_color(config.minimap_border_color),
+            pin_color_range: false,
+            lock_animation_colors: false,
+            color_range: (0, config.default_iterations),
+            color_adjustment: config.color_adjustment,
+            color_lut: ColorLut::new(&config.color_adjustment, config.invert_colors),
+            curve_channel: 0,
+            notify_on_completion: config.notify_on_completion,
+            bookmark_screenshots: config.bookmark_screenshots,
+            bookmark_image_dir: PathBuf::from(BOOKMARK_IMAGE_DIR),
+            completion_flash: None,
+            log: VecDeque::new(),
             show_about_dialog: false,
+            show_difference_window: false,
+            difference_iterations: config.default_iterations.saturating_mul(2),
+            cached_difference_texture: None,
+            difference_changed: (0, 0),
+            suggested_iterations: None,
             cached_about_texture: None,
             fractal_registry: registry,
             viewport: Viewport::new(
@@ -315,13 +1662,44 @@ impl FractalApp {
             .unwrap_or_default()
     }
 
+    /// Color pipeline for the current view, including processor parameters
+    fn color_pipeline(&self) -> color_pipeline::ColorPipeline {
+        let view = self.get_view();
+        color_pipeline::ColorPipeline::from_type_with_params(
+            view.color_processor_type,
+            &view.processor_params,
+        )
+        .with_iteration_range(self.pinned_color_range())
+        .with_trap_image(self.trap_image.clone())
+        .with_custom_palette(self.controls.custom_palette.clone())
+    }
+
+    /// The absolute iteration window colors are pinned to, if any
+    fn pinned_color_range(&self) -> Option<(u32, u32)> {
+        self.pin_color_range.then_some(self.color_range)
+    }
+
     fn get_command_history(&mut self) -> &mut CommandHistory {
         self.command_histories
             .entry(self.controls.fractal_type)
-            .or_insert_with(|| CommandHistory::new(UNDO_HISTORY_CAPACITY))
+            .or_insert_with(|| CommandHistory::new(self.history_size))
     }
 
-    fn set_view(&mut self, view: FractalViewState) {
+    fn set_view(&mut self, mut view: FractalViewState) {
+        // Keep any high-precision center in step with f64 view changes
+        if let Some(hp) = &view.hp_center {
+            view.hp_center = Some(hp.follow(view.center_x, view.center_y));
+        }
+        // A pan or zoom ends the current measurement
+        let moved = self
+            .views
+            .get(&self.controls.fractal_type)
+            .is_none_or(|old| {
+                (old.center_x, old.center_y, old.zoom) != (view.center_x, view.center_y, view.zoom)
+            });
+        if moved {
+            self.interaction.measure_points.clear();
+        }
         self.views.insert(self.controls.fractal_type, view.clone());
         self.viewport = Viewport::from_view(
             view.center_x,
@@ -332,23 +1710,201 @@ impl FractalApp {
         );
     }
 
-    fn update_viewport_dimensions(&mut self, width: u32, height: u32) {
-        self.viewport.set_dimensions(width, height);
-    }
-
-    fn invalidate_cache(&mut self) {
-        self.render.needs_render = true;
+    /// Create the fractal for `controls.fractal_type` and restore the
+    /// controls from its saved view
+    fn activate_fractal(&mut self) {
+        self.fractal = self.create_fractal(self.controls.fractal_type);
+        if let Some(view) = self.views.get(&self.controls.fractal_type) {
+            let view = view.clone();
+            self.controls.max_iterations = view.max_iterations;
+            self.controls.pending_max_iterations = view.max_iterations;
+            self.controls.pending_fractal_params = view.fractal_params.clone();
+            self.controls.palette_type = view.palette_type;
+            self.controls.color_processor_type = view.color_processor_type;
+            self.controls.pending_palette_offset = self.controls.palette_offset;
+            for (name, value) in &view.fractal_params {
+                self.fractal.set_parameter(name, *value);
+            }
+            // Update viewport to match the restored view
+            self.set_view(view);
+        }
+    }
+
+    /// Note that the fractal type is changing away from `old_type`
+    fn remember_fractal_type(&mut self, old_type: FractalType) {
+        if old_type != self.controls.fractal_type {
+            self.previous_fractal_type = Some(old_type);
+        }
+    }
+
+    /// Switch back to the previously shown fractal type with its saved
+    /// view, recording the switch for undo in the history of the fractal
+    /// being left
+    fn toggle_previous_fractal(&mut self) {
+        let Some(target) = self.previous_fractal_type else {
+            self.set_status("No previous fractal".to_string());
+            return;
+        };
+        let old_type = self.controls.fractal_type;
+        let old_view = self.get_view();
+        let new_view = self.views.get(&target).cloned().unwrap_or_default();
+
+        let command = Box::new(FractalTypeCommand::new(
+            old_type, target, old_view, new_view,
+        ));
+        let mut state = self.to_app_state();
+        self.get_command_history().execute(command, &mut state);
+        self.apply_app_state(&state);
+        self.remember_fractal_type(old_type);
+        self.activate_fractal();
+        self.invalidate_cache();
+        let name = self
+            .fractal_registry
+            .metadata(target)
+            .map(|m| m.display_name)
+            .unwrap_or_default();
+        self.set_status(format!("Switched to {}", name));
+    }
+
+    /// Copy the current framing (center, zoom, iterations) into another
+    /// fractal's view and switch to it. Its parameters and coloring are kept.
+    fn duplicate_view_to(&mut self, target: FractalType) {
+        if target == self.controls.fractal_type {
+            return;
+        }
+        let source = self.get_view();
+        let view = self.views.entry(target).or_default();
+        view.center_x = source.center_x;
+        view.center_y = source.center_y;
+        view.zoom = source.zoom;
+        view.max_iterations = source.max_iterations;
+        view.hp_center = source.hp_center;
+
+        let old_type = self.controls.fractal_type;
+        self.controls.fractal_type = target;
+        self.remember_fractal_type(old_type);
+        self.activate_fractal();
+        self.invalidate_cache();
+        let name = self
+            .fractal_registry
+            .metadata(target)
+            .map(|m| m.display_name)
+            .unwrap_or_default();
+        self.set_status(format!("Copied view to {}", name));
+    }
+
+    fn update_viewport_dimensions(&mut self, width: u32, height: u32) {
+        self.viewport.set_dimensions(width, height);
+    }
+
+    /// Recolor the cached render after an adjustment or inversion change,
+    /// without recomputing it
+    fn refresh_colors(&mut self) {
+        self.color_lut = ColorLut::new(&self.color_adjustment, self.invert_colors);
+        self.render.texture_dirty = true;
+        self.minimap_dirty = true;
+    }
+
+    fn invalidate_cache(&mut self) {
+        self.request_render();
+        self.suggested_iterations = None;
         self.render.texture_dirty = true;
         self.minimap_dirty = true;
+        self.parameter_map_dirty = true;
         self.render.partial_render_regions.clear();
         self.render.current_region_index = 0;
     }
 
+    /// Start a render now with auto-render on (or with nothing rendered
+    /// yet); otherwise mark one pending for `render_now`
+    fn request_render(&mut self) {
+        if self.render.auto_render || self.render.cached_image.is_none() {
+            self.render.needs_render = true;
+        } else {
+            self.render.render_pending = true;
+        }
+    }
+
+    /// Render after a zoom-box selection: right away with no delay set,
+    /// otherwise once the zoom preview has shown for that many frames
+    fn schedule_zoom_render(&mut self) {
+        if self.render.zoom_render_delay == 0 {
+            self.render.render_delay = 0;
+            self.invalidate_cache();
+        } else {
+            self.render.render_delay = self.render.zoom_render_delay;
+        }
+    }
+
+    /// Render the changes held back while auto-render is off
+    fn render_now(&mut self) {
+        if self.render.render_pending {
+            self.render.render_pending = false;
+            self.render.needs_render = true;
+        }
+    }
+
+    /// Whether pan/zoom input is locked out; says so in the status bar
+    fn navigation_locked(&mut self) -> bool {
+        if self.view_locked {
+            self.set_status("View is locked".to_string());
+        }
+        self.view_locked
+    }
+
+    /// Quality for a render started now under the adaptive quality setting
+    fn current_render_quality(&self, dragging: bool) -> RenderQuality {
+        if !self.render.adaptive_quality {
+            return RenderQuality::Full;
+        }
+        let idle_secs = self
+            .interaction
+            .last_interaction
+            .map_or(f64::INFINITY, |t| t.elapsed().as_secs_f64());
+        select_render_quality(dragging, idle_secs)
+    }
+
+    /// Iteration limit for the current view: the adaptive count when
+    /// adaptive iterations are on, the slider value otherwise
+    fn view_max_iterations(&self) -> u32 {
+        if self.render.adaptive_iterations {
+            self.calculate_adaptive_iterations(self.get_view().zoom)
+        } else {
+            self.controls.max_iterations
+        }
+    }
+
+    /// Iteration limit the canvas is rendered with, including the raised
+    /// limit while the preview key is held
+    fn rendered_iterations(&self) -> u32 {
+        effective_iterations(
+            self.view_max_iterations(),
+            self.interaction.preview_iterations_held,
+        )
+    }
+
     fn calculate_adaptive_iterations(&self, zoom: f64) -> u32 {
         let base_iter = self.controls.max_iterations;
         let zoom_factor = if zoom > 1.0 { zoom.log2() } else { 0.0 };
         let additional = (ADAPTIVE_ITER_COEFFICIENT * zoom_factor) as u32;
-        (base_iter + additional).min(MAX_ITERATIONS_CAP)
+        let cap = if self.render.render_budget {
+            self.render.iteration_cap
+        } else {
+            MAX_ITERATIONS_CAP
+        };
+        (base_iter + additional).min(cap)
+    }
+
+    /// Retune the render budget's iteration cap from the full-quality
+    /// render that just finished at `iterations`
+    fn update_iteration_budget(&mut self, iterations: u32) {
+        if self.render.quality != RenderQuality::Full {
+            return;
+        }
+        if let Some(elapsed) = self.render.last_render_time {
+            self.render.iteration_cap =
+                budget_iteration_cap(iterations, elapsed, RENDER_BUDGET_SECS);
+        }
     }
 
     fn execute_view_command(&mut self, old_view: &FractalViewState, new_view: &FractalViewState) {
@@ -365,6 +1921,22 @@ impl FractalApp {
         self.apply_app_state(&state);
     }
 
+    /// Record a palette offset change (already applied to the controls)
+    /// so it can be undone
+    fn execute_palette_offset_command(&mut self, old_offset: f32) {
+        let new_offset = self.controls.palette_offset;
+        if old_offset == new_offset {
+            return;
+        }
+        let palette = self.controls.palette_type;
+        let command = Box::new(PaletteCommand::new(
+            palette, palette, old_offset, new_offset,
+        ));
+        let mut state = self.to_app_state();
+        self.get_command_history().execute(command, &mut state);
+        self.apply_app_state(&state);
+    }
+
     fn to_app_state(&self) -> AppState {
         AppState {
             fractal_type: self.controls.fractal_type,
@@ -379,19 +1951,16 @@ impl FractalApp {
         self.controls.pending_max_iterations = state.view.max_iterations;
         self.controls.palette_type = state.view.palette_type;
         self.controls.palette_offset = state.palette_offset;
+        self.controls.pending_palette_offset = state.palette_offset;
         self.controls.color_processor_type = state.view.color_processor_type;
 
         // Update the view and viewport in one place
         self.set_view(state.view.clone());
     }
 
-    fn save_image(&self, scale_factor: u32) -> Result<PathBuf, String> {
-        let image = self
-            .render
-            .cached_image
-            .as_ref()
-            .ok_or("No image to save - wait for render to complete")?;
-
+    /// Export path prefix naming the fractal and palette, e.g.
+    /// "images/mandelbrot_classic"
+    fn export_basename(&self) -> String {
         let fractal_name = match self.controls.fractal_type {
             FractalType::Mandelbrot => "mandelbrot",
             FractalType::Julia => "julia",
@@ -405,6 +1974,8 @@ impl FractalApp {
             FractalType::Spider => "spider",
             FractalType::OrbitTrap => "orbit_trap",
             FractalType::PickoverStalk => "pickover_stalk",
+            FractalType::PolynomialJulia => "polynomial_julia",
+            FractalType::MandelJuliaMorph => "mandel_julia_morph",
         };
         let palette_name = match self.controls.palette_type {
             PaletteType::Classic => "classic",
@@ -412,70 +1983,387 @@ impl FractalApp {
             PaletteType::Ice => "ice",
             PaletteType::Grayscale => "grayscale",
             PaletteType::Psychedelic => "psychedelic",
+            PaletteType::Custom => "custom",
         };
 
-        let base_width = image.width() as u32;
-        let base_height = image.height() as u32;
-        let width = base_width * scale_factor;
-        let height = base_height * scale_factor;
+        format!("images/{}_{}", fractal_name, palette_name)
+    }
 
-        let mut img: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(width, height);
+    /// Queue the current Julia view beside its parameter plane (e.g. the
+    /// Mandelbrot set) with the seed marked, captioned with the seed value.
+    /// Panels are the display size times the export scale; the parameter
+    /// plane is square and frames the same c range as the parameter map.
+    /// Returns the path the poster will be written to.
+    fn export_julia_seed(&mut self) -> Result<PathBuf, String> {
+        let space = self
+            .fractal
+            .parameter_space_fractal()
+            .ok_or("The current fractal has no seed parameter")?;
+        let (re_name, im_name) = fractal::SEED_PARAMETERS;
+        let (Some(c_re), Some(c_im)) = (
+            self.fractal.get_parameter(re_name),
+            self.fractal.get_parameter(im_name),
+        ) else {
+            return Err("The current fractal has no seed parameter".to_string());
+        };
+        let (width, height) = scaled_export_size(
+            self.render.cached_width,
+            self.render.cached_height,
+            self.export_scale,
+            self.export_megapixel_cap,
+        )?;
+        if width == 0 || height == 0 {
+            return Err("No image to export".to_string());
+        }
 
-        // If scale_factor is 1, use cached image directly
-        if scale_factor == 1 {
-            for (i, color) in image.pixels.iter().enumerate() {
-                let x = (i % base_width as usize) as u32;
-                let y = (i / base_width as usize) as u32;
-                img.put_pixel(x, y, Rgb([color.r(), color.g(), color.b()]));
-            }
+        let view = self.get_view();
+        check_export_precision(&view, width, height, self.min_feature_ulps)?;
+        let max_iter = if self.render.adaptive_iterations {
+            self.calculate_adaptive_iterations(view.zoom)
         } else {
-            // Render at higher resolution
-            self.render_high_res(&mut img, width, height)?;
+            self.controls.max_iterations
+        };
+        let mut fractal = self.create_fractal(self.controls.fractal_type);
+        for param in self.fractal.parameters() {
+            fractal.set_parameter(&param.name, param.value);
         }
 
-        let filename = format!(
-            "images/{}_{}_{}x{}.png",
-            fractal_name, palette_name, width, height
-        );
         std::fs::create_dir_all("images")
             .map_err(|e| format!("Failed to create images directory: {}", e))?;
-        let path = PathBuf::from(&filename);
-        img.save(&path)
-            .map_err(|e| format!("Failed to save image: {}", e))?;
+        let path = PathBuf::from(format!(
+            "{}_seed_{}x{}.png",
+            self.export_basename(),
+            width + height,
+            height + composite::CAPTION_HEIGHT
+        ));
+        self.export_queue.push(JuliaSeedRequest {
+            fractal,
+            space,
+            view,
+            seed: (c_re, c_im),
+            path: path.clone(),
+            config: self.export_config(width, height, max_iter),
+            supersample_factor: self.export_supersample_factor(),
+        });
+        Ok(path)
+    }
+
+    /// Trace the current view's iteration bands into an SVG at display size
+    fn save_svg(&self) -> Result<PathBuf, String> {
+        let (width, height) = (self.render.cached_width, self.render.cached_height);
+        if width == 0 || height == 0 {
+            return Err("No image to export".to_string());
+        }
+        let view = self.get_view();
+        let max_iter = if self.render.adaptive_iterations {
+            self.calculate_adaptive_iterations(view.zoom)
+        } else {
+            self.controls.max_iterations
+        };
+
+        let fractal = self.fractal.as_ref();
+        let bands: Vec<u32> = (0..height)
+            .into_par_iter()
+            .flat_map(|y| {
+                let view = &view;
+                (0..width)
+                    .map(|x| {
+                        let (px, py) = renderer::screen_to_fractal(x, y, width, height, view);
+                        let result = fractal.compute_full(px, py, max_iter);
+                        svg::band_of(result.iterations, result.escaped, max_iter, SVG_BAND_COUNT)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let band_color = |band: u32| {
+            let color = if band >= SVG_BAND_COUNT {
+                self.interior_color
+            } else {
+                palette::get_custom_banded_color(
+                    self.controls.palette_type,
+                    self.controls.custom_palette.as_deref(),
+                    (band as f32 + 0.5) / SVG_BAND_COUNT as f32,
+                    self.controls.palette_offset,
+                    self.controls.palette_frequency,
+                )
+            };
+            let color = self.color_adjustment.apply(color);
+            if self.invert_colors {
+                renderer::invert_color(color)
+            } else {
+                color
+            }
+        };
+        let document = svg::bands_to_svg(&bands, width, height, band_color);
+
+        std::fs::create_dir_all("images")
+            .map_err(|e| format!("Failed to create images directory: {}", e))?;
+        let path = PathBuf::from(format!(
+            "{}_{}x{}.svg",
+            self.export_basename(),
+            width,
+            height
+        ));
+        std::fs::write(&path, document).map_err(|e| format!("Failed to write SVG: {}", e))?;
+        Ok(path)
+    }
+
+    /// CSV (`n,re,im`) of the orbit of the view center at the current
+    /// iteration count. A high-precision center with digits f64 cannot
+    /// hold is iterated in decimal arithmetic.
+    fn center_orbit_csv(&self) -> Result<String, String> {
+        let view = self.get_view();
+        let max_iter = self.view_max_iterations();
+        let orbit = match &view.hp_center {
+            Some(hp) if HpCenter::from_f64(view.center_x, view.center_y).as_ref() != Some(hp) => {
+                self.fractal
+                    .compute_orbit_hp(&hp.re, &hp.im, max_iter)
+                    .ok_or_else(|| {
+                        format!(
+                            "{} has no high-precision orbit for this deep center",
+                            self.fractal.name()
+                        )
+                    })?
+            }
+            _ => self
+                .fractal
+                .compute_orbit(view.center_x, view.center_y, max_iter)
+                .ok_or_else(|| format!("{} does not expose its orbit", self.fractal.name()))?,
+        };
+        Ok(orbit_to_csv(&orbit))
+    }
+
+    /// Write the orbit of the view center next to the image exports
+    fn export_orbit(&self) -> Result<PathBuf, String> {
+        let csv = self.center_orbit_csv()?;
+        std::fs::create_dir_all("images")
+            .map_err(|e| format!("Failed to create images directory: {}", e))?;
+        let path = PathBuf::from(format!("{}_orbit.csv", self.export_basename()));
+        std::fs::write(&path, csv).map_err(|e| format!("Failed to write orbit: {}", e))?;
         Ok(path)
     }
 
-    fn render_high_res(
-        &self,
-        buffer: &mut ImageBuffer<Rgb<u8>, Vec<u8>>,
+    /// Save the current view. 1x exports are written immediately from the
+    /// cached image (Ok(Some(path))); anything needing a re-render is added
+    /// to the background export queue (Ok(None)) polled by `poll_export_queue`.
+    fn save_image(&mut self, scale_factor: u32) -> Result<Option<PathBuf>, String> {
+        let (width, height) = self.export_size(scale_factor)?;
+
+        let filename = format!("{}_{}x{}.png", self.export_basename(), width, height);
+        std::fs::create_dir_all("images")
+            .map_err(|e| format!("Failed to create images directory: {}", e))?;
+        self.save_sized_image(PathBuf::from(&filename), scale_factor, width, height)
+    }
+
+    /// Save the current view to `path` at `scale_factor` times the display
+    /// size. Returns the path when written now, or None when queued.
+    fn save_image_to(
+        &mut self,
+        path: PathBuf,
+        scale_factor: u32,
+    ) -> Result<Option<PathBuf>, String> {
+        let (width, height) = self.export_size(scale_factor)?;
+        self.save_sized_image(path, scale_factor, width, height)
+    }
+
+    /// `save_image_to` with the size `export_size` already gave
+    fn save_sized_image(
+        &mut self,
+        path: PathBuf,
+        scale_factor: u32,
         width: u32,
         height: u32,
-    ) -> Result<(), String> {
+    ) -> Result<Option<PathBuf>, String> {
+        let image = self
+            .render
+            .cached_image
+            .as_ref()
+            .ok_or("No image to save - wait for render to complete")?;
+
+        // If scale_factor is 1, use cached image directly. Transparency and
+        // 16-bit output need per-pixel escape data, which the cached image
+        // doesn't carry, so always re-render in those modes
+        if scale_factor == 1
+            && !self.transparent_interior
+            && self.export_bit_depth == ExportBitDepth::Eight
+        {
+            let mut pixels = image.pixels.clone();
+            self.color_lut.apply_all(&mut pixels);
+            let img = pixels_to_rgb_image(&pixels, width, height);
+            img.save(&path)
+                .map_err(|e| format!("Failed to save image: {}", e))?;
+            return Ok(Some(path));
+        }
+
+        // The on-screen image is saved as shown; only re-renders can go
+        // past what f64 resolves
+        check_export_precision(&self.get_view(), width, height, self.min_feature_ulps)?;
+        self.queue_export(path, width, height);
+        Ok(None)
+    }
+
+    /// Size of an export at `scale_factor` times the display size. Only
+    /// exports held in memory are limited by `export_megapixel_cap`.
+    fn export_size(&self, scale_factor: u32) -> Result<(u32, u32), String> {
+        let image = self
+            .render
+            .cached_image
+            .as_ref()
+            .ok_or("No image to save - wait for render to complete")?;
+        let cap = if self.streams_export() {
+            f64::INFINITY
+        } else {
+            self.export_megapixel_cap
+        };
+        scaled_export_size(
+            image.width() as u32,
+            image.height() as u32,
+            scale_factor,
+            cap,
+        )
+    }
+
+    /// Whether re-rendered exports stream to disk in row bands rather than
+    /// being held in memory
+    fn streams_export(&self) -> bool {
+        self.tiled_export
+            && !self.transparent_interior
+            && self.export_bit_depth == ExportBitDepth::Eight
+    }
+
+    /// Queue an export of the current view. Settings are captured now and
+    /// rendered on the background worker, which reports progress and can be
+    /// cancelled.
+    fn queue_export(&mut self, path: PathBuf, width: u32, height: u32) {
+        let mut fractal = self.create_fractal(self.controls.fractal_type);
+        for param in self.fractal.parameters() {
+            fractal.set_parameter(&param.name, param.value);
+        }
         let view = self.get_view();
         let max_iter = if self.render.adaptive_iterations {
             self.calculate_adaptive_iterations(view.zoom)
         } else {
             self.controls.max_iterations
         };
+        let eight_bit = self.export_bit_depth == ExportBitDepth::Eight;
+        self.export_queue.push(ExportRequest {
+            fractal,
+            view,
+            path,
+            config: self.export_config(width, height, max_iter),
+            supersample_factor: self.export_supersample_factor(),
+            // The checkboxes are greyed out for 16-bit but keep their state
+            transparent: eight_bit && self.transparent_interior,
+            tiled: eight_bit && self.tiled_export,
+            bit_depth: self.export_bit_depth,
+        });
+    }
+
+    /// Store the finished render's duration, flashing if it was long
+    fn record_render_time(&mut self) {
+        if let Some(start_time) = self.render.render_start_time.take() {
+            let elapsed = start_time.elapsed().as_secs_f64();
+            self.render.last_render_time = Some(elapsed);
+            self.append_log(format!("Render finished in {:.2}s", elapsed));
+            self.notify_completion(elapsed);
+        }
+    }
+
+    fn notify_completion(&mut self, elapsed_secs: f64) {
+        if should_notify(self.notify_on_completion, elapsed_secs) {
+            self.completion_flash = Some(Instant::now());
+        }
+    }
+
+    /// Render the view at the current and the comparison iteration count
+    /// and show which pixels differ as a heatmap
+    fn render_iteration_difference(&mut self, ctx: &egui::Context) {
+        let view = self.get_view();
+        let width = DIFFERENCE_WIDTH;
+        let height = ((width as f64 / self.viewport.aspect_ratio()).round() as u32).max(1);
+        let render =
+            |max_iter: u32| iteration_probe(self.fractal.as_ref(), &view, width, height, max_iter);
+        let (Some(current), Some(compared)) = (
+            render(self.controls.max_iterations),
+            render(self.difference_iterations),
+        ) else {
+            return;
+        };
 
-        let pixels = self.render.engine.render_high_res(
-            self.fractal.as_ref(),
-            &view,
-            width,
-            height,
-            max_iter,
-            self.controls.palette_type,
-            self.controls.palette_offset,
-            color_pipeline::ColorPipeline::from_type(self.controls.color_processor_type),
-        );
+        let (pixels, changed) = difference_heatmap(&current, &compared);
+        self.difference_changed = (changed, pixels.len());
+        let image = egui::ColorImage {
+            size: [width as usize, height as usize],
+            pixels,
+        };
+        self.cached_difference_texture = Some(ctx.load_texture(
+            "iteration_difference",
+            image,
+            egui::TextureOptions::default(),
+        ));
+    }
 
-        for (i, color) in pixels.iter().enumerate() {
-            let x = (i % width as usize) as u32;
-            let y = (i / width as usize) as u32;
-            buffer.put_pixel(x, y, Rgb([color.r(), color.g(), color.b()]));
+    /// Report the outcome of each finished background export
+    fn poll_export_queue(&mut self) {
+        if let Some(message) = self.export_queue.poll() {
+            self.push_log(message);
+            if let Some(elapsed) = self.export_queue.finished.last().map(|f| f.elapsed_secs) {
+                self.notify_completion(elapsed);
+            }
         }
+    }
 
-        Ok(())
+    /// Install the palette extracted from an image once its job finishes
+    fn poll_palette_image_job(&mut self) {
+        if !self
+            .palette_image_job
+            .as_ref()
+            .is_some_and(|job| job.is_finished())
+        {
+            return;
+        }
+        let Some(job) = self.palette_image_job.take() else {
+            return;
+        };
+        match job.join() {
+            Ok(Ok(custom)) => {
+                let count = custom.stops().len();
+                self.controls.custom_palette = Some(Arc::new(custom));
+                self.controls.palette_type = PaletteType::Custom;
+                if let Some(view) = self.views.get_mut(&self.controls.fractal_type) {
+                    view.palette_type = PaletteType::Custom;
+                }
+                self.push_log(format!("Palette from image: {} colors", count));
+                self.invalidate_cache();
+            }
+            Ok(Err(e)) => self.push_log(format!("Error: {}", e)),
+            Err(_) => self.push_log("Error: palette extraction panicked".to_string()),
+        }
+    }
+
+    /// Render settings for a `width` x `height` export of the current
+    /// coloring at `max_iter` iterations
+    fn export_config(&self, width: u32, height: u32, max_iter: u32) -> RenderConfig {
+        RenderConfig {
+            palette_type: self.controls.palette_type,
+            palette_offset: self.controls.palette_offset,
+            palette_frequency: self.controls.palette_frequency,
+            color_pipeline: self.color_pipeline(),
+            interior_color: self.interior_color,
+            invert: self.invert_colors,
+            adjustment: self.color_adjustment,
+            ..RenderConfig::new(width, height, max_iter)
+        }
+    }
+
+    /// Samples per axis for exported images (NxN per pixel)
+    fn export_supersample_factor(&self) -> u32 {
+        if self.render.supersampling_enabled {
+            EXPORT_SUPERSAMPLE_FACTOR
+        } else {
+            1
+        }
     }
 
     fn reset_view(&mut self) {
@@ -495,6 +2383,8 @@ impl FractalApp {
             fractal_params: current_params,
             palette_type: current_palette,
             color_processor_type: self.controls.color_processor_type,
+            hp_center: None,
+            processor_params: self.get_view().processor_params,
         };
         self.set_view(default_view);
     }
@@ -502,23 +2392,31 @@ impl FractalApp {
     fn reset_settings(&mut self) {
         // Reset everything for current fractal to factory defaults
         let (center_x, center_y) = self.controls.fractal_type.default_center();
+        let default_iterations = self
+            .fractal_registry
+            .metadata(self.controls.fractal_type)
+            .map_or(DEFAULT_ITERATIONS, |metadata| metadata.default_iterations);
         let default_view = FractalViewState {
             center_x,
             center_y,
             zoom: 1.0,
-            max_iterations: DEFAULT_ITERATIONS,
+            max_iterations: default_iterations,
             fractal_params: HashMap::new(),
             palette_type: PaletteType::Classic,
             color_processor_type: color_pipeline::ColorProcessorType::default(),
+            hp_center: None,
+            processor_params: HashMap::new(),
         };
         self.set_view(default_view);
 
         // Reset controls
-        self.controls.max_iterations = DEFAULT_ITERATIONS;
-        self.controls.pending_max_iterations = DEFAULT_ITERATIONS;
+        self.controls.max_iterations = default_iterations;
+        self.controls.pending_max_iterations = default_iterations;
         self.controls.palette_type = PaletteType::Classic;
         self.controls.pending_palette_offset = 0.0;
         self.controls.palette_offset = 0.0;
+        self.controls.pending_palette_frequency = 1.0;
+        self.controls.palette_frequency = 1.0;
 
         // Reset fractal parameters to defaults
         self.fractal = self.create_fractal(self.controls.fractal_type);
@@ -528,6 +2426,69 @@ impl FractalApp {
         self.set_status("Settings reset".to_string());
     }
 
+    /// Restore the default palette, color processor (with its parameters)
+    /// and palette offset/frequency, keeping the view and fractal parameters
+    fn reset_coloring(&mut self) {
+        let view = FractalViewState {
+            palette_type: PaletteType::Classic,
+            color_processor_type: color_pipeline::ColorProcessorType::default(),
+            processor_params: HashMap::new(),
+            ..self.get_view()
+        };
+        self.set_view(view);
+
+        self.controls.palette_type = PaletteType::Classic;
+        self.controls.color_processor_type = color_pipeline::ColorProcessorType::default();
+        self.controls.pending_palette_offset = 0.0;
+        self.controls.palette_offset = 0.0;
+        self.controls.pending_palette_frequency = 1.0;
+        self.controls.palette_frequency = 1.0;
+
+        self.invalidate_cache();
+        self.set_status("Coloring reset".to_string());
+    }
+
+    /// Zoom an interactive zoom from `current` toward `requested` ends on:
+    /// `requested` itself, or the power of two it snaps to
+    fn target_zoom(&self, current: f64, requested: f64) -> f64 {
+        if self.snap_zoom {
+            snap_zoom_to_power_of_two(current, requested)
+        } else {
+            requested
+        }
+    }
+
+    /// Scroll-wheel zoom by `scroll_delta` points at a screen point. Smooth
+    /// scrolling spreads one notch over many small deltas, so with snap zoom
+    /// they add up and each `SNAP_SCROLL_STEP` zooms by one power of two.
+    fn scroll_zoom(
+        &mut self,
+        scroll_delta: f32,
+        screen_x: u32,
+        screen_y: u32,
+        width: u32,
+        height: u32,
+    ) {
+        let factor = if self.snap_zoom {
+            let pending = &mut self.interaction.snap_scroll;
+            if pending.signum() != scroll_delta.signum() {
+                *pending = 0.0;
+            }
+            *pending += scroll_delta;
+            let steps = (*pending / SNAP_SCROLL_STEP).trunc();
+            if steps == 0.0 {
+                return;
+            }
+            *pending -= steps * SNAP_SCROLL_STEP;
+            2f64.powi(steps as i32)
+        } else if scroll_delta > 0.0 {
+            1.0 + scroll_delta as f64 * SCROLL_ZOOM_SENSITIVITY
+        } else {
+            1.0 / (1.0 + (-scroll_delta) as f64 * SCROLL_ZOOM_SENSITIVITY)
+        };
+        self.zoom_at_point(factor, screen_x, screen_y, width, height);
+    }
+
     /// Zoom centered on a specific screen point (for scroll-wheel zoom)
     fn zoom_at_point(
         &mut self,
@@ -537,6 +2498,10 @@ impl FractalApp {
         width: u32,
         height: u32,
     ) {
+        if self.navigation_locked() {
+            return;
+        }
+        self.interaction.last_interaction = Some(Instant::now());
         let old_view = self.get_view();
 
         // Convert the focus point to fractal coordinates before zoom
@@ -545,13 +2510,16 @@ impl FractalApp {
             .screen_to_world(screen_x, screen_y, width, height);
 
         let mut view = old_view.clone();
-        view.zoom *= factor;
+        view.zoom = clamp_zoom(
+            self.target_zoom(view.zoom, view.zoom * factor),
+            self.min_zoom,
+        );
 
         // Adjust center so the focus point stays under the cursor
         // Before zoom: focus_world = center + offset/zoom_old
         // After zoom: we want focus_world at the same screen position
         // new_center = focus_world - offset/zoom_new = focus_world - (focus_world - old_center)*(zoom_old/zoom_new)
-        let ratio = 1.0 / factor;
+        let ratio = old_view.zoom / view.zoom;
         view.center_x = focus.re - (focus.re - old_view.center_x) * ratio;
         view.center_y = focus.im - (focus.im - old_view.center_y) * ratio;
 
@@ -568,9 +2536,16 @@ impl FractalApp {
     }
 
     fn zoom_view(&mut self, factor: f64) {
+        if self.navigation_locked() {
+            return;
+        }
+        self.interaction.last_interaction = Some(Instant::now());
         let old_view = self.get_view();
         let mut view = old_view.clone();
-        view.zoom *= factor;
+        view.zoom = clamp_zoom(
+            self.target_zoom(view.zoom, view.zoom * factor),
+            self.min_zoom,
+        );
 
         if self.render.adaptive_iterations {
             let new_iter = self.calculate_adaptive_iterations(view.zoom);
@@ -588,11 +2563,25 @@ impl FractalApp {
     }
 
     fn pan_view(&mut self, dx: f64, dy: f64) {
+        if self.navigation_locked() {
+            return;
+        }
+        self.interaction.last_interaction = Some(Instant::now());
         let old_view = self.get_view();
         let mut view = old_view.clone();
-        let pan_amount = PAN_AMOUNT_BASE / view.zoom;
-        view.center_x += dx * pan_amount;
-        view.center_y += dy * pan_amount;
+        if let Some(cached) = &self.render.cached_image {
+            // Snap the pan to whole pixels so the shifted image stays aligned
+            let (width, height) = (cached.width() as u32, cached.height() as u32);
+            let viewport = Viewport::from_view(0.0, 0.0, view.zoom, width, height);
+            let (shift_x, shift_y) = viewport.calculate_pixel_shift(dx, dy, width, height);
+            let (world_dx, world_dy) = viewport.pixel_shift_to_world(shift_x, shift_y, height);
+            view.center_x += world_dx;
+            view.center_y += world_dy;
+        } else {
+            let pan_amount = PAN_AMOUNT_BASE / view.zoom;
+            view.center_x += dx * pan_amount;
+            view.center_y += dy * pan_amount;
+        }
         self.set_view(view.clone());
 
         // Execute command for history
@@ -608,21 +2597,86 @@ impl FractalApp {
             if !regions.is_empty() {
                 self.render.partial_render_regions = regions;
                 self.render.current_region_index = 0;
-                self.render.needs_render = true;
+                self.request_render();
                 return;
             }
         }
 
-        self.render.needs_render = true;
+        self.request_render();
     }
 
-    fn undo(&mut self) {
-        let mut state = self.to_app_state();
-        if let Some(description) = self.get_command_history().undo(&mut state) {
-            self.apply_app_state(&state);
-            self.fractal = self.create_fractal(state.fractal_type);
-            self.invalidate_cache();
-            self.set_status(format!("Undo: {}", description));
+    /// Zoom to a seeded random point near the boundary, recording the move
+    /// for undo. Each call advances the seed so repeated clicks find new spots.
+    fn find_interesting_spot(&mut self) {
+        if self.navigation_locked() {
+            return;
+        }
+        let seed = self.explore_seed;
+        self.explore_seed = self.explore_seed.wrapping_add(1);
+        let Some((re, im)) = explore::find_boundary_point(self.fractal.as_ref(), seed) else {
+            self.set_status("No boundary point found".to_string());
+            return;
+        };
+        let old_view = self.get_view();
+        let mut view = old_view.clone();
+        view.center_x = re;
+        view.center_y = im;
+        view.hp_center = None;
+        view.zoom = clamp_zoom(EXPLORE_ZOOM, self.min_zoom);
+        if self.render.adaptive_iterations {
+            let new_iter = self.calculate_adaptive_iterations(view.zoom);
+            view.max_iterations = new_iter;
+            self.controls.max_iterations = new_iter;
+            self.controls.pending_max_iterations = new_iter;
+        }
+
+        self.set_view(view.clone());
+        self.execute_view_command(&old_view, &view);
+        self.invalidate_cache();
+        self.set_status(format!("Found spot {:.6}, {:.6} (seed {})", re, im, seed));
+    }
+
+    /// Navigate to coordinates pasted as text, recording the move for undo
+    fn go_to_pasted_location(&mut self, text: &str) {
+        if self.navigation_locked() {
+            return;
+        }
+        let Some(location) = parse_location(text) else {
+            self.set_status("Clipboard does not contain coordinates".to_string());
+            return;
+        };
+        let old_view = self.get_view();
+        let mut view = old_view.clone();
+        view.center_x = location.re;
+        view.center_y = location.im;
+        view.hp_center = location.hp_center;
+        if let Some(zoom) = location.zoom {
+            let zoom = clamp_zoom(zoom, self.min_zoom);
+            view.zoom = zoom;
+            if self.render.adaptive_iterations {
+                let new_iter = self.calculate_adaptive_iterations(zoom);
+                view.max_iterations = new_iter;
+                self.controls.max_iterations = new_iter;
+                self.controls.pending_max_iterations = new_iter;
+            }
+        }
+
+        self.set_view(view.clone());
+        self.execute_view_command(&old_view, &view);
+        self.invalidate_cache();
+        self.set_status(format!(
+            "Jumped to ({}, {}) zoom {:.3e}",
+            location.re, location.im, view.zoom
+        ));
+    }
+
+    fn undo(&mut self) {
+        let mut state = self.to_app_state();
+        if let Some(description) = self.get_command_history().undo(&mut state) {
+            self.apply_app_state(&state);
+            self.restore_fractal(&state);
+            self.invalidate_cache();
+            self.set_status(format!("Undo: {}", description));
         }
     }
 
@@ -630,12 +2684,107 @@ impl FractalApp {
         let mut state = self.to_app_state();
         if let Some(description) = self.get_command_history().redo(&mut state) {
             self.apply_app_state(&state);
-            self.fractal = self.create_fractal(state.fractal_type);
+            self.restore_fractal(&state);
             self.invalidate_cache();
             self.set_status(format!("Redo: {}", description));
         }
     }
 
+    /// Resize every fractal's command history, trimming the oldest steps
+    fn set_history_size(&mut self, size: usize) {
+        self.history_size = size.clamp(MIN_HISTORY_SIZE, MAX_HISTORY_SIZE);
+        for history in self.command_histories.values_mut() {
+            history.set_max_size(self.history_size);
+        }
+    }
+
+    /// Undo or redo until `index` commands of the history are applied
+    fn seek_history(&mut self, index: usize) {
+        let mut state = self.to_app_state();
+        let history = self.get_command_history();
+        if history.seek_to(index, &mut state) == 0 {
+            return;
+        }
+        let (position, len) = (history.position(), history.len());
+        self.apply_app_state(&state);
+        self.restore_fractal(&state);
+        self.invalidate_cache();
+        self.set_status(format!("History: step {} of {}", position, len));
+    }
+
+    /// Recreate the fractal for a state restored from the history, with
+    /// the parameters its view stores
+    fn restore_fractal(&mut self, state: &AppState) {
+        self.fractal = self.create_fractal(state.fractal_type);
+        for (name, &value) in &state.view.fractal_params {
+            self.fractal.set_parameter(name, value);
+        }
+        self.controls.pending_fractal_params = state.view.fractal_params.clone();
+    }
+
+    /// Write the commands that led to the current view to `VIEW_PATH_FILE`
+    fn export_view_path(&mut self) -> Result<PathBuf, String> {
+        let script = self.get_command_history().export_script();
+        if script.is_empty() {
+            return Err("No view path to export".to_string());
+        }
+        let json = serde_json::to_string_pretty(&script)
+            .map_err(|e| format!("Failed to serialize view path: {}", e))?;
+        let path = PathBuf::from(VIEW_PATH_FILE);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+        std::fs::write(&path, json).map_err(|e| format!("Failed to write view path: {}", e))?;
+        Ok(path)
+    }
+
+    /// Replay a view path from `VIEW_PATH_FILE`, starting from the default
+    /// framing of the current fractal
+    fn replay_view_path(&mut self) -> Result<usize, String> {
+        let json = std::fs::read_to_string(VIEW_PATH_FILE)
+            .map_err(|e| format!("Failed to read {}: {}", VIEW_PATH_FILE, e))?;
+        let script: Vec<CommandDescriptor> =
+            serde_json::from_str(&json).map_err(|e| format!("Invalid view path: {}", e))?;
+        self.replay_script(&script);
+        Ok(script.len())
+    }
+
+    /// Re-execute a view path script with each view repaired like a loaded
+    /// bookmark. Every step is recorded in the history of the fractal shown
+    /// when it runs, so a fractal switch lands in the one being left.
+    fn replay_script(&mut self, script: &[CommandDescriptor]) {
+        let mut state = self.to_app_state();
+        let (center_x, center_y) = state.fractal_type.default_center();
+        state.view.center_x = center_x;
+        state.view.center_y = center_y;
+        state.view.zoom = 1.0;
+        state.view.hp_center = None;
+
+        let mut cleared = HashSet::new();
+        let mut repaired = false;
+        for descriptor in script {
+            let mut descriptor = descriptor.clone();
+            repaired |= sanitize_descriptor(&mut descriptor, state.fractal_type);
+            let history = self
+                .command_histories
+                .entry(state.fractal_type)
+                .or_insert_with(|| CommandHistory::new(self.history_size));
+            if cleared.insert(state.fractal_type) {
+                history.clear();
+            }
+            history.execute(descriptor.to_command(), &mut state);
+        }
+        // Parameter steps can still leave non-finite values behind
+        repaired |= state.view.sanitize(state.fractal_type);
+        if repaired {
+            self.append_log("View path: invalid values reset".to_string());
+        }
+        self.apply_app_state(&state);
+        self.restore_fractal(&state);
+        self.invalidate_cache();
+    }
+
     fn add_bookmark(&mut self, name: String) {
         let view = self.get_view();
         let bookmark = Bookmark {
@@ -648,11 +2797,111 @@ impl FractalApp {
             palette_type: view.palette_type,
             color_processor_type: view.color_processor_type,
             fractal_params: view.fractal_params.clone(),
+            hp_center: view.hp_center.clone(),
+            processor_params: self.color_pipeline().parameter_values(),
         };
+        if self.bookmark_screenshots {
+            if let Err(e) = self.save_bookmark_image(&bookmark.name) {
+                self.append_log(format!("Error: {}", e));
+            }
+        }
         self.bookmarks.push(bookmark);
         self.set_status("Bookmark saved".to_string());
     }
 
+    /// Save the current view as `<bookmark_image_dir>/<name>.png`, adding
+    /// an index suffix if that file already exists
+    fn save_bookmark_image(&mut self, name: &str) -> Result<Option<PathBuf>, String> {
+        std::fs::create_dir_all(&self.bookmark_image_dir)
+            .map_err(|e| format!("Failed to create bookmark image directory: {}", e))?;
+        let path = unused_png_path(&self.bookmark_image_dir, &bookmark_file_stem(name));
+        self.save_image_to(path, 1)
+    }
+
+    fn add_keyframe(&mut self) {
+        let mut view = self.get_view();
+        // Capture every parameter, not just ones the user has touched
+        view.fractal_params = self
+            .fractal
+            .parameters()
+            .into_iter()
+            .map(|p| (p.name, p.value))
+            .collect();
+        let time = self.keyframes.last().map_or(0.0, |k| k.time + 1.0);
+        self.keyframes.push(animation::Keyframe::new(time, view));
+        self.set_status(format!("Keyframe {} added", self.keyframes.len()));
+    }
+
+    /// Queue the keyframe animation as a numbered PNG sequence at the
+    /// current view size. Returns the output directory.
+    fn export_animation(&mut self) -> Result<PathBuf, String> {
+        if self.keyframes.len() < 2 {
+            return Err("Add at least two keyframes".to_string());
+        }
+        let image = self
+            .render
+            .cached_image
+            .as_ref()
+            .ok_or("No image to save - wait for render to complete")?;
+        let width = image.width() as u32;
+        let height = image.height() as u32;
+
+        let views = self.animation_frames()?;
+
+        let dir = PathBuf::from("images/animation");
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create animation directory: {}", e))?;
+
+        self.export_queue.push(AnimationRequest {
+            fractal: self.create_fractal(self.controls.fractal_type),
+            views,
+            dir: dir.clone(),
+            config: self.export_config(width, height, self.controls.max_iterations),
+            trap_image: self.trap_image.clone(),
+            custom_palette: self.controls.custom_palette.clone(),
+            color_range: self.pinned_color_range(),
+            lock_colors: self.lock_animation_colors,
+            supersample_factor: self.export_supersample_factor(),
+        });
+        Ok(dir)
+    }
+
+    /// The interpolated view of each animation frame
+    fn animation_frames(&self) -> Result<Vec<FractalViewState>, String> {
+        let mut keyframes = self.keyframes.clone();
+        keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        animation::frame_times(&keyframes, self.animation_frame_count)
+            .into_iter()
+            .map(|time| animation::interpolate(&keyframes, time, self.zoom_path))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| "Failed to interpolate keyframes".to_string())
+    }
+
+    /// Write all bookmarks to `path`. Returns how many were written.
+    fn export_bookmarks(&self, path: &Path) -> Result<usize, String> {
+        write_bookmarks(path, &self.bookmarks)?;
+        Ok(self.bookmarks.len())
+    }
+
+    /// Load bookmarks from `path`, replacing the current list or appending
+    /// to it. Entries are sanitized like saved ones, logging each repair.
+    /// Returns how many were read.
+    fn import_bookmarks(&mut self, path: &Path, replace: bool) -> Result<usize, String> {
+        let mut imported = read_bookmarks(path)?;
+        for bookmark in &mut imported {
+            if bookmark.sanitize() {
+                self.append_log(format!("Bookmark '{}' repaired", bookmark.name));
+            }
+        }
+        let count = imported.len();
+        if replace {
+            self.bookmarks = imported;
+        } else {
+            self.bookmarks.extend(imported);
+        }
+        Ok(count)
+    }
+
     fn delete_bookmark(&mut self, index: usize) {
         if index < self.bookmarks.len() {
             self.bookmarks.remove(index);
@@ -660,9 +2909,37 @@ impl FractalApp {
         }
     }
 
-    fn load_bookmark(&mut self, index: usize) {
-        if let Some(bookmark) = self.bookmarks.get(index).cloned() {
+    /// Load a bookmark. With `geometry_only`, only its center, zoom and
+    /// iterations are applied to the current fractal (recorded for undo);
+    /// otherwise its fractal type, parameters and coloring are restored too.
+    fn load_bookmark(&mut self, index: usize, geometry_only: bool) {
+        if let Some(mut bookmark) = self.bookmarks.get(index).cloned() {
+            let repaired = bookmark.sanitize();
+            if geometry_only {
+                if self.navigation_locked() {
+                    return;
+                }
+                let old_view = self.get_view();
+                let view = FractalViewState {
+                    center_x: bookmark.center_x,
+                    center_y: bookmark.center_y,
+                    zoom: clamp_zoom(bookmark.zoom, self.min_zoom),
+                    max_iterations: bookmark.max_iterations,
+                    hp_center: bookmark.hp_center.clone(),
+                    ..old_view.clone()
+                };
+                self.set_view(view.clone());
+                self.execute_view_command(&old_view, &view);
+                self.controls.max_iterations = bookmark.max_iterations;
+                self.controls.pending_max_iterations = bookmark.max_iterations;
+                self.invalidate_cache();
+                self.set_status(format!("Applied framing of: {}", bookmark.name));
+                return;
+            }
+
+            let old_type = self.controls.fractal_type;
             self.controls.fractal_type = bookmark.fractal_type;
+            self.remember_fractal_type(old_type);
             self.fractal = self.create_fractal(bookmark.fractal_type);
 
             // Restore fractal parameters
@@ -678,6 +2955,8 @@ impl FractalApp {
                 fractal_params: bookmark.fractal_params.clone(),
                 palette_type: bookmark.palette_type,
                 color_processor_type: bookmark.color_processor_type,
+                hp_center: bookmark.hp_center.clone(),
+                processor_params: bookmark.processor_params.clone(),
             };
             self.set_view(view);
 
@@ -688,7 +2967,11 @@ impl FractalApp {
             self.controls.pending_fractal_params = bookmark.fractal_params.clone();
 
             self.invalidate_cache();
-            self.set_status(format!("Loaded: {}", bookmark.name));
+            if repaired {
+                self.set_status(format!("Loaded: {} (invalid values reset)", bookmark.name));
+            } else {
+                self.set_status(format!("Loaded: {}", bookmark.name));
+            }
         }
     }
 
@@ -696,6 +2979,21 @@ impl FractalApp {
         self.interaction.status_message = Some((message, Instant::now()));
     }
 
+    /// Show a status message and keep it in the log panel
+    fn push_log(&mut self, message: String) {
+        self.append_log(message.clone());
+        self.set_status(message);
+    }
+
+    /// Add a log entry without a status message, evicting the oldest past
+    /// `LOG_CAPACITY`
+    fn append_log(&mut self, message: String) {
+        if self.log.len() >= LOG_CAPACITY {
+            self.log.pop_front();
+        }
+        self.log.push_back((Instant::now(), message));
+    }
+
     fn check_status_timeout(&mut self) {
         if let Some((_, timestamp)) = self.interaction.status_message {
             if timestamp.elapsed().as_secs_f64() > STATUS_TIMEOUT_SECS {
@@ -714,96 +3012,505 @@ impl FractalApp {
         if x < width && y < height {
             let world = self.viewport.screen_to_world(x, y, width, height);
             self.interaction.mouse_fractal_pos = Some((world.re, world.im));
+            self.interaction.mouse_readout = Some(cursor_readout(
+                self.fractal.as_ref(),
+                world.re,
+                world.im,
+                self.rendered_iterations(),
+            ));
         } else {
             self.interaction.mouse_fractal_pos = None;
+            self.interaction.mouse_readout = None;
+        }
+    }
+
+    /// Selection outline color: the configured color, or black/white by the
+    /// brightness of the image under `sel_rect` with auto-contrast on
+    fn selection_stroke_color(&self, sel_rect: egui::Rect, canvas: egui::Rect) -> egui::Color32 {
+        if !self.selection_auto_contrast {
+            return self.selection_color;
+        }
+        let Some(image) = &self.render.cached_image else {
+            return self.selection_color;
+        };
+        // The cached image may be a draft or device-pixel render, so scale
+        // canvas points to its pixels
+        let scale_x = image.size[0] as f32 / canvas.width().max(1.0);
+        let scale_y = image.size[1] as f32 / canvas.height().max(1.0);
+        let to_pixel = |p: egui::Pos2| {
+            [
+                ((p.x - canvas.min.x) * scale_x).max(0.0) as usize,
+                ((p.y - canvas.min.y) * scale_y).max(0.0) as usize,
+            ]
+        };
+        mean_luma(
+            image,
+            &self.color_lut,
+            to_pixel(sel_rect.min),
+            to_pixel(sel_rect.max),
+        )
+        .map_or(self.selection_color, contrasting_overlay)
+    }
+
+    /// Print each label cell's iteration count at its center, skipped when
+    /// the cells would be too many to read
+    fn draw_iteration_labels(&mut self, painter: &egui::Painter, rect: egui::Rect) {
+        let (width, height) = (rect.width() as u32, rect.height() as u32);
+        let fractal_type = self.controls.fractal_type;
+        let max_iter = self.rendered_iterations();
+        let params: HashMap<String, f64> = self
+            .fractal
+            .parameters()
+            .into_iter()
+            .map(|p| (p.name, p.value))
+            .collect();
+        let stale = self.iteration_label_cache.as_ref().is_none_or(|cache| {
+            cache.fractal_type != fractal_type
+                || cache.viewport != self.viewport
+                || cache.size != (width, height)
+                || cache.params != params
+                || cache.max_iter != max_iter
+        });
+        if stale {
+            let labels = iteration_label_cells(
+                self.fractal.as_ref(),
+                &self.viewport,
+                width,
+                height,
+                max_iter,
+            );
+            self.iteration_label_cache = Some(IterationLabelCache {
+                fractal_type,
+                viewport: self.viewport,
+                size: (width, height),
+                params,
+                max_iter,
+                labels,
+            });
+        }
+        let Some(cache) = &self.iteration_label_cache else {
+            return;
+        };
+        let font = egui::FontId::monospace(10.0);
+        for &cell in &cache.labels {
+            let pos = rect.min + egui::vec2(cell.x as f32, cell.y as f32);
+            painter.text(
+                pos,
+                egui::Align2::CENTER_CENTER,
+                cell.iterations.to_string(),
+                font.clone(),
+                egui::Color32::WHITE,
+            );
+        }
+    }
+
+    /// Outline each lemniscate, from red (n = 1) through the hues to n =
+    /// `LEMNISCATE_LEVELS`. Only the Mandelbrot set has them.
+    fn draw_lemniscates(&mut self, painter: &egui::Painter, rect: egui::Rect) {
+        let (width, height) = (rect.width() as u32, rect.height() as u32);
+        if width == 0 || height == 0 || self.controls.fractal_type != FractalType::Mandelbrot {
+            return;
+        }
+        let params = self.get_view().fractal_params;
+        let stale = self.lemniscate_cache.as_ref().is_none_or(|cache| {
+            cache.viewport != self.viewport
+                || cache.size != (width, height)
+                || cache.params != params
+        });
+        if stale {
+            let levels = lemniscate_contours(
+                self.fractal.as_ref(),
+                &self.viewport,
+                width,
+                height,
+                LEMNISCATE_LEVELS,
+            );
+            self.lemniscate_cache = Some(LemniscateCache {
+                viewport: self.viewport,
+                size: (width, height),
+                params,
+                levels,
+            });
+        }
+        let Some(cache) = &self.lemniscate_cache else {
+            return;
+        };
+        for (n, outlines) in cache.levels.iter().enumerate() {
+            let hue = n as f32 / LEMNISCATE_LEVELS as f32;
+            let (r, g, b) = palette::hsv_to_rgb(hue, 0.8, 1.0);
+            let color =
+                egui::Color32::from_rgb((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8);
+            for outline in outlines {
+                let points = outline
+                    .iter()
+                    .map(|&(re, im)| {
+                        let (x, y) =
+                            self.viewport
+                                .world_to_screen(Complex64::new(re, im), width, height);
+                        rect.min + egui::vec2(x as f32, y as f32)
+                    })
+                    .collect();
+                painter.add(egui::Shape::closed_line(
+                    points,
+                    egui::Stroke::new(1.5, color),
+                ));
+            }
+        }
+    }
+
+    /// Draw gridlines at round world coordinates with labels along the
+    /// top and left edges
+    fn draw_grid(&self, painter: &egui::Painter, rect: egui::Rect) {
+        let (width, height) = (rect.width() as u32, rect.height() as u32);
+        if width == 0 || height == 0 {
+            return;
+        }
+        let ((min_x, min_y), (max_x, max_y)) = self.viewport.visible_rect();
+        let interval = nice_grid_interval(max_x - min_x, GRID_TARGET_LINES);
+        if interval <= 0.0 {
+            return;
+        }
+        let decimals = (-interval.log10().floor()).max(0.0) as usize;
+        let (center_x, center_y) = self.viewport.center();
+
+        let line_color = egui::Color32::from_white_alpha(60);
+        let stroke = egui::Stroke::new(1.0, line_color);
+        let font = egui::FontId::monospace(10.0);
+        let text_color = egui::Color32::from_white_alpha(200);
+
+        let mut x = (min_x / interval).ceil() * interval;
+        for _ in 0..GRID_MAX_LINES {
+            if x > max_x {
+                break;
+            }
+            let (sx, _) = self
+                .viewport
+                .world_to_screen(Complex64::new(x, center_y), width, height);
+            let sx = rect.min.x + sx as f32;
+            painter.line_segment(
+                [egui::pos2(sx, rect.min.y), egui::pos2(sx, rect.max.y)],
+                stroke,
+            );
+            painter.text(
+                egui::pos2(sx + 2.0, rect.min.y + 2.0),
+                egui::Align2::LEFT_TOP,
+                format!("{:.*}", decimals, x),
+                font.clone(),
+                text_color,
+            );
+            x += interval;
+        }
+
+        let mut y = (min_y / interval).ceil() * interval;
+        for _ in 0..GRID_MAX_LINES {
+            if y > max_y {
+                break;
+            }
+            let (_, sy) = self
+                .viewport
+                .world_to_screen(Complex64::new(center_x, y), width, height);
+            let sy = rect.min.y + sy as f32;
+            painter.line_segment(
+                [egui::pos2(rect.min.x, sy), egui::pos2(rect.max.x, sy)],
+                stroke,
+            );
+            painter.text(
+                egui::pos2(rect.min.x + 2.0, sy - 2.0),
+                egui::Align2::LEFT_BOTTOM,
+                format!("{:.*}", decimals, y),
+                font.clone(),
+                text_color,
+            );
+            y += interval;
         }
     }
 
+    /// Add a measure point; a click after a finished measurement starts a
+    /// new one
+    fn add_measure_point(&mut self, point: (f64, f64)) {
+        let points = &mut self.interaction.measure_points;
+        if points.len() >= 2 {
+            points.clear();
+        }
+        points.push(point);
+    }
+
+    /// Draw the measure points, the line between them and its length
+    fn draw_measurement(&self, painter: &egui::Painter, rect: egui::Rect) {
+        let (width, height) = (rect.width() as u32, rect.height() as u32);
+        let to_screen = |&(re, im): &(f64, f64)| {
+            let (sx, sy) = self
+                .viewport
+                .world_to_screen(Complex64::new(re, im), width, height);
+            egui::pos2(rect.min.x + sx as f32, rect.min.y + sy as f32)
+        };
+        let points: Vec<egui::Pos2> = self
+            .interaction
+            .measure_points
+            .iter()
+            .map(to_screen)
+            .collect();
+        let stroke = egui::Stroke::new(2.0, egui::Color32::YELLOW);
+        for &point in &points {
+            painter.circle_stroke(point, 4.0, stroke);
+        }
+        if let [a, b] = self.interaction.measure_points[..] {
+            painter.line_segment([points[0], points[1]], stroke);
+            painter.text(
+                points[0].lerp(points[1], 0.5) + egui::vec2(6.0, -6.0),
+                egui::Align2::LEFT_BOTTOM,
+                format!("{:.6e}", world_distance(a, b)),
+                egui::FontId::monospace(12.0),
+                egui::Color32::YELLOW,
+            );
+        }
+    }
+
+    /// Viewport covering the area shown in the minimap
+    fn minimap_viewport(&self) -> Viewport {
+        let (center_x, center_y) = self.controls.fractal_type.default_center();
+        Viewport::from_view(
+            center_x,
+            center_y,
+            4.0 / MINIMAP_MAP_RANGE,
+            MINIMAP_SIZE as u32,
+            MINIMAP_SIZE as u32,
+        )
+    }
+
+    /// Re-render the minimap fractal when it is stale, at most once per
+    /// `MINIMAP_MIN_INTERVAL_SECS`. The view rectangle is drawn over it
+    /// every frame by `draw_minimap_view_rect`, so it never lags.
     fn render_minimap(&mut self, ctx: &egui::Context) {
         if !self.minimap_enabled {
             return;
         }
 
         if !self.minimap_dirty && self.cached_minimap_texture.is_some() {
-            // Just update the view rectangle overlay - reuse the cached fractal rendering
-            // We re-render only the view rectangle (cheap) on top of the cached fractal minimap
+            return;
+        }
+        let now = Instant::now();
+        if self.cached_minimap_texture.is_some()
+            && !throttle_elapsed(self.last_minimap_render, now, MINIMAP_MIN_INTERVAL_SECS)
+        {
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(
+                MINIMAP_MIN_INTERVAL_SECS,
+            ));
             return;
         }
 
-        let minimap_size = MINIMAP_SIZE;
-        let mut pixels = vec![egui::Color32::BLACK; minimap_size * minimap_size];
+        let image = egui::ColorImage {
+            size: [MINIMAP_SIZE, MINIMAP_SIZE],
+            pixels: self.minimap_pixels(),
+        };
+
+        self.cached_minimap_texture =
+            Some(ctx.load_texture("minimap", image, egui::TextureOptions::default()));
+        self.minimap_dirty = false;
+        self.last_minimap_render = Some(now);
+    }
 
+    /// Minimap pixels, row-major: the plain palette by iteration count, or
+    /// with `minimap_full_coloring` the main view's color pipeline (palette
+    /// offset/frequency, processor, interior color and adjustments)
+    fn minimap_pixels(&self) -> Vec<egui::Color32> {
+        let size = MINIMAP_SIZE as u32;
         let max_iter = MINIMAP_MAX_ITER;
+        let minimap_viewport = self.minimap_viewport();
+        let points = (0..size).flat_map(|y| (0..size).map(move |x| (x, y)));
+
+        if !self.minimap_full_coloring {
+            return points
+                .map(|(x, y)| {
+                    let world = minimap_viewport.screen_to_world(x, y, size, size);
+                    let iterations = self.fractal.compute(world.re, world.im, max_iter);
+                    if iterations >= max_iter {
+                        egui::Color32::BLACK
+                    } else {
+                        let t = iterations as f32 / max_iter as f32;
+                        palette::get_custom_banded_color(
+                            self.controls.palette_type,
+                            self.controls.custom_palette.as_deref(),
+                            t,
+                            0.0,
+                            1.0,
+                        )
+                    }
+                })
+                .collect();
+        }
+
+        let (center_x, center_y) = minimap_viewport.center();
+        let view = FractalViewState {
+            center_x,
+            center_y,
+            zoom: 4.0 / MINIMAP_MAP_RANGE,
+            max_iterations: max_iter,
+            hp_center: None,
+            ..self.get_view()
+        };
+        let config = self.export_config(size, size, max_iter);
+        let context = config.color_context(&view);
+        let lut = &self.color_lut;
+        points
+            .map(|(x, y)| {
+                let world = minimap_viewport.screen_to_world(x, y, size, size);
+                let result = config.color_pipeline.compute(
+                    self.fractal.as_ref(),
+                    world.re,
+                    world.im,
+                    max_iter,
+                );
+                lut.apply(config.color_for(&result, &context))
+            })
+            .collect()
+    }
 
-        let minimap_viewport = Viewport::from_view(
-            self.controls.fractal_type.default_center().0,
-            self.controls.fractal_type.default_center().1,
-            1.0,
-            minimap_size as u32,
-            minimap_size as u32,
+    /// Outline the current view on the minimap drawn at `minimap_rect`
+    fn draw_minimap_view_rect(&self, painter: &egui::Painter, minimap_rect: egui::Rect) {
+        let minimap_viewport = self.minimap_viewport();
+        let size = MINIMAP_SIZE as u32;
+        let ((min_x, min_y), (max_x, max_y)) = self.viewport.visible_rect();
+        let corner = |re: f64, im: f64| {
+            let (x, y) = minimap_viewport.world_to_screen(Complex64::new(re, im), size, size);
+            minimap_rect.min + egui::vec2(x as f32, y as f32)
+        };
+        let view_rect = egui::Rect::from_two_pos(corner(min_x, min_y), corner(max_x, max_y));
+        painter.with_clip_rect(minimap_rect).rect_stroke(
+            view_rect,
+            0.0,
+            egui::Stroke::new(1.0, egui::Color32::YELLOW),
         );
+    }
 
-        for y in 0..minimap_size {
-            for x in 0..minimap_size {
-                let world = minimap_viewport.screen_to_world(
-                    x as u32,
-                    y as u32,
-                    minimap_size as u32,
-                    minimap_size as u32,
-                );
-                let iterations = self.fractal.compute(world.re, world.im, max_iter);
-                let color = if iterations >= max_iter {
+    /// Render the current fractal's parameter space (e.g. the Mandelbrot set
+    /// for Julia) into a cached texture. The current c is drawn as a marker
+    /// on top each frame, so only parameter or palette changes re-render it.
+    fn render_parameter_map(&mut self, ctx: &egui::Context) {
+        let space = self.fractal.parameter_space_fractal();
+        let Some(space) = space.filter(|_| self.parameter_map_enabled) else {
+            self.cached_parameter_map_texture = None;
+            return;
+        };
+        if !self.parameter_map_dirty && self.cached_parameter_map_texture.is_some() {
+            return;
+        }
+
+        let size = PARAMETER_MAP_SIZE;
+        let max_iter = PARAMETER_MAP_MAX_ITER;
+        let palette_type = self.controls.palette_type;
+        let custom = self.controls.custom_palette.as_deref();
+        let pixels: Vec<egui::Color32> = (0..size * size)
+            .into_par_iter()
+            .map(|i| {
+                let u = ((i % size) as f64 + 0.5) / size as f64;
+                let v = ((i / size) as f64 + 0.5) / size as f64;
+                let (c_re, c_im) = parameter_map_to_seed(u, v);
+                let iterations = space.compute(c_re, c_im, max_iter);
+                if iterations >= max_iter {
                     egui::Color32::BLACK
                 } else {
-                    let t = iterations as f32 / max_iter as f32;
-                    palette::get_color(self.controls.palette_type, t, 0.0)
-                };
-                pixels[y * minimap_size + x] = color;
-            }
-        }
+                    palette::get_custom_banded_color(
+                        palette_type,
+                        custom,
+                        iterations as f32 / max_iter as f32,
+                        0.0,
+                        1.0,
+                    )
+                }
+            })
+            .collect();
 
-        // Draw view rectangle
-        let default_center = self.controls.fractal_type.default_center();
-        let (view_center_x, view_center_y) = self.viewport.center();
-        let view_zoom = self.viewport.zoom();
-        let view_width = 4.0 / view_zoom;
-        let view_height = view_width;
+        let image = egui::ColorImage {
+            size: [size, size],
+            pixels,
+        };
+        self.cached_parameter_map_texture =
+            Some(ctx.load_texture("parameter_map", image, egui::TextureOptions::default()));
+        self.parameter_map_dirty = false;
+    }
 
-        let map_range = MINIMAP_MAP_RANGE;
-        let rel_x = (view_center_x - default_center.0 + map_range / 2.0) / map_range;
-        let rel_y = (view_center_y - default_center.1 + map_range / 2.0) / map_range;
+    /// Hint for a frame with no visible structure, which otherwise looks
+    /// like a failed render. The frame is uniform, so the view center tells
+    /// whether it is all interior or all exterior.
+    fn warn_featureless_view(&mut self, max_iter: u32) {
+        let (center_x, center_y) = self.viewport.center();
+        let inside = self.fractal.compute(center_x, center_y, max_iter) >= max_iter;
+        let region = if inside { "inside" } else { "outside" };
+        self.set_status(format!(
+            "View is entirely {} the set - try zooming out or adjusting iterations",
+            region
+        ));
+    }
 
-        let rect_x = (rel_x * minimap_size as f64) as i32;
-        let rect_y = (rel_y * minimap_size as f64) as i32;
-        let rect_w = ((view_width / map_range) * minimap_size as f64) as i32;
-        let rect_h = ((view_height / map_range) * minimap_size as f64) as i32;
+    /// Shift the palette offset by `delta`, wrapping around the cycle
+    fn adjust_palette_offset(&mut self, delta: f32) {
+        let offset = wrap_palette_offset(self.controls.palette_offset + delta);
+        self.controls.palette_offset = offset;
+        self.controls.pending_palette_offset = offset;
+        self.set_status(format!("Palette offset: {:.3}", offset));
+        self.invalidate_cache();
+    }
 
-        for dy in -rect_h / 2..=rect_h / 2 {
-            for dx in -rect_w / 2..=rect_w / 2 {
-                if dx == -rect_w / 2 || dx == rect_w / 2 || dy == -rect_h / 2 || dy == rect_h / 2 {
-                    let px = rect_x + dx;
-                    let py = rect_y + dy;
-                    if px >= 0 && px < minimap_size as i32 && py >= 0 && py < minimap_size as i32 {
-                        pixels[py as usize * minimap_size + px as usize] = egui::Color32::YELLOW;
-                    }
-                }
-            }
-        }
+    /// Shift the palette offset by one Shift+scroll step; the steps of one
+    /// gesture become a single undo entry in `finish_palette_scroll`
+    fn scroll_palette_offset(&mut self, delta: f32, now: Instant) {
+        let start = self
+            .interaction
+            .palette_scroll
+            .map_or(self.controls.palette_offset, |(start, _)| start);
+        self.interaction.palette_scroll = Some((start, now));
+        self.adjust_palette_offset(delta);
+    }
 
-        let image = egui::ColorImage {
-            size: [minimap_size, minimap_size],
-            pixels,
+    /// Record the Shift+scroll gesture once it has paused for
+    /// `PALETTE_SCROLL_GESTURE_SECS`. Returns whether one is still open.
+    fn finish_palette_scroll(&mut self, now: Instant) -> bool {
+        let Some((start, last)) = self.interaction.palette_scroll else {
+            return false;
         };
+        if now.saturating_duration_since(last).as_secs_f64() < PALETTE_SCROLL_GESTURE_SECS {
+            return true;
+        }
+        self.interaction.palette_scroll = None;
+        self.execute_palette_offset_command(start);
+        false
+    }
 
-        self.cached_minimap_texture =
-            Some(ctx.load_texture("minimap", image, egui::TextureOptions::default()));
-        self.minimap_dirty = false;
+    /// Set the fractal's seed (c) parameters, e.g. from a parameter map click
+    fn set_seed(&mut self, c_re: f64, c_im: f64) {
+        let (re_name, im_name) = fractal::SEED_PARAMETERS;
+        for (name, value) in [(re_name, c_re), (im_name, c_im)] {
+            self.fractal.set_parameter(name, value);
+            let stored = self.fractal.get_parameter(name).unwrap_or(value);
+            self.controls
+                .pending_fractal_params
+                .insert(name.to_string(), stored);
+        }
+        if let Some(view) = self.views.get_mut(&self.controls.fractal_type) {
+            view.fractal_params = self.controls.pending_fractal_params.clone();
+        }
+        self.invalidate_cache();
+    }
+
+    /// The current seed (c) as a fraction of the parameter map, if the
+    /// fractal has one
+    fn seed_on_parameter_map(&self) -> Option<(f64, f64)> {
+        let (re_name, im_name) = fractal::SEED_PARAMETERS;
+        let c_re = self.fractal.get_parameter(re_name)?;
+        let c_im = self.fractal.get_parameter(im_name)?;
+        Some(seed_to_parameter_map(c_re, c_im))
     }
 }
 
 impl eframe::App for FractalApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.check_status_timeout();
+        self.poll_export_queue();
+        self.poll_palette_image_job();
+        if self.export_queue.is_busy() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
 
         // Track actual window size for saving on exit
         ctx.input(|i| {
@@ -813,8 +3520,17 @@ impl eframe::App for FractalApp {
             }
         });
 
+        // Hold H: preview at high iterations without changing the setting
+        let preview_held = !self.show_bookmark_dialog
+            && !ctx.wants_keyboard_input()
+            && ctx.input(|i| i.key_down(egui::Key::H));
+        if preview_held != self.interaction.preview_iterations_held {
+            self.interaction.preview_iterations_held = preview_held;
+            self.invalidate_cache();
+        }
+
         // Handle keyboard input (disable when bookmark dialog is open)
-        if !self.show_bookmark_dialog {
+        if !self.show_bookmark_dialog && !ctx.wants_keyboard_input() {
             ctx.input(|i| {
                 // Zoom controls: +/- keys
                 if i.key_pressed(egui::Key::Plus) || i.key_pressed(egui::Key::Equals) {
@@ -824,6 +3540,20 @@ impl eframe::App for FractalApp {
                     self.zoom_view(1.0 / ZOOM_KEYBOARD_FACTOR);
                 }
 
+                // Exact magnification: 2 / 0 keys zoom x2 / x10, Shift zooms out
+                for (key, factor) in [
+                    (egui::Key::Num2, ZOOM_KEY_TWO_FACTOR),
+                    (egui::Key::Num0, ZOOM_KEY_TEN_FACTOR),
+                ] {
+                    if i.key_pressed(key) {
+                        self.zoom_view(if i.modifiers.shift {
+                            1.0 / factor
+                        } else {
+                            factor
+                        });
+                    }
+                }
+
                 // Pan controls: arrow keys
                 if i.key_pressed(egui::Key::ArrowLeft) {
                     self.pan_view(-1.0, 0.0);
@@ -838,6 +3568,23 @@ impl eframe::App for FractalApp {
                     self.pan_view(0.0, -1.0);
                 }
 
+                // Paste coordinates: Ctrl+V
+                for event in &i.events {
+                    if let egui::Event::Paste(text) = event {
+                        self.go_to_pasted_location(text);
+                    }
+                }
+
+                // Render held-back changes: Enter
+                if i.key_pressed(egui::Key::Enter) {
+                    self.render_now();
+                }
+
+                // Previous fractal type: Tab
+                if i.key_pressed(egui::Key::Tab) {
+                    self.toggle_previous_fractal();
+                }
+
                 // Reset view: R key
                 if i.key_pressed(egui::Key::R) && !i.modifiers.shift {
                     self.reset_view();
@@ -855,11 +3602,14 @@ impl eframe::App for FractalApp {
                 // Save: S key
                 if i.key_pressed(egui::Key::S) {
                     match self.save_image(1) {
-                        Ok(path) => {
-                            self.set_status(format!("Saved: {}", path.display()));
+                        Ok(Some(path)) => {
+                            self.push_log(format!("Saved: {}", path.display()));
+                        }
+                        Ok(None) => {
+                            self.set_status("Exporting...".to_string());
                         }
                         Err(e) => {
-                            self.set_status(format!("Error: {}", e));
+                            self.push_log(format!("Error: {}", e));
                         }
                     }
                 }
@@ -870,37 +3620,131 @@ impl eframe::App for FractalApp {
             .default_width(CONTROL_PANEL_WIDTH)
             .show(ctx, |ui| {
                 let prev_fractal = self.controls.fractal_type;
+                let prev_palette_offset = self.controls.palette_offset;
                 let mut changed = false;
+                let mut preview = false;
                 let render_status = RenderStatus::new(
                     self.render.is_rendering || self.render.needs_render,
                     self.render.render_progress,
                     self.render.last_render_time,
                     rayon::current_num_threads(),
                 );
-                self.controls
-                    .ui(ui, &mut self.fractal, &mut changed, &render_status);
+                let metadata = self.fractal_registry.metadata(self.controls.fractal_type);
+                self.controls.ui(
+                    ui,
+                    &mut self.fractal,
+                    &mut changed,
+                    &mut preview,
+                    &render_status,
+                    metadata.as_ref(),
+                );
+                self.execute_palette_offset_command(prev_palette_offset);
+                match parameter_render_quality(preview, changed) {
+                    Some(RenderQuality::Draft) => {
+                        self.render.parameter_preview = true;
+                        self.invalidate_cache();
+                    }
+                    // The change itself invalidates the cache below
+                    Some(RenderQuality::Full) => self.render.parameter_preview = false,
+                    None => {}
+                }
 
                 if prev_fractal != self.controls.fractal_type {
-                    self.fractal = self.create_fractal(self.controls.fractal_type);
-                    if let Some(view) = self.views.get(&self.controls.fractal_type) {
-                        let view = view.clone();
-                        self.controls.max_iterations = view.max_iterations;
-                        self.controls.pending_max_iterations = view.max_iterations;
-                        self.controls.pending_fractal_params = view.fractal_params.clone();
-                        self.controls.palette_type = view.palette_type;
-                        self.controls.color_processor_type = view.color_processor_type;
-                        self.controls.pending_palette_offset = self.controls.palette_offset;
-                        for (name, value) in &view.fractal_params {
-                            self.fractal.set_parameter(name, *value);
+                    self.remember_fractal_type(prev_fractal);
+                    self.activate_fractal();
+                    self.invalidate_cache();
+                }
+
+                ui.horizontal(|ui| {
+                    if ui
+                        .button("Suggest iterations")
+                        .on_hover_text(
+                            "Probe the view at rising iteration counts and suggest the \
+                             lowest one past which the image barely changes (not applied)",
+                        )
+                        .clicked()
+                    {
+                        self.suggested_iterations = suggest_iterations(
+                            self.fractal.as_ref(),
+                            &self.get_view(),
+                            SUGGEST_PROBE_WIDTH,
+                            self.viewport.aspect_ratio(),
+                        );
+                    }
+                    if let Some(suggested) = self.suggested_iterations {
+                        ui.label(format!("Suggested: {}", suggested));
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Palette image:");
+                    ui.text_edit_singleline(&mut self.palette_image_path);
+                });
+                let extracting = self.palette_image_job.is_some();
+                let label = if extracting {
+                    "Extracting palette..."
+                } else {
+                    "Palette from image"
+                };
+                if ui
+                    .add_enabled(!extracting, egui::Button::new(label))
+                    .clicked()
+                {
+                    let path = PathBuf::from(self.palette_image_path.trim());
+                    self.palette_image_job = Some(std::thread::spawn(move || {
+                        palette::CustomPalette::from_image(&path, IMAGE_PALETTE_COLORS)
+                    }));
+                }
+
+                if self.controls.color_processor_type
+                    == color_pipeline::ColorProcessorType::ImageTrap
+                {
+                    ui.horizontal(|ui| {
+                        ui.label("Image:");
+                        ui.text_edit_singleline(&mut self.trap_image_path);
+                        if ui.button("Load").clicked() {
+                            match color_pipeline::TrapImage::load(std::path::Path::new(
+                                self.trap_image_path.trim(),
+                            )) {
+                                Ok(image) => {
+                                    self.trap_image = Some(Arc::new(image));
+                                    self.set_status("Trap image loaded".to_string());
+                                    changed = true;
+                                }
+                                Err(e) => self.push_log(format!("Error: {}", e)),
+                            }
                         }
-                        // Update viewport to match the restored view
-                        self.set_view(view);
+                    });
+                }
+
+                // Processor parameters (e.g. orbit trap threshold)
+                let mut processor_params = HashMap::new();
+                for param in self.color_pipeline().parameters() {
+                    let mut value = param.value;
+                    let response = ui.add(
+                        egui::Slider::new(&mut value, param.min..=param.max)
+                            .logarithmic(param.min > 0.0)
+                            .text(&param.name),
+                    );
+                    // Keyboard, click and typed edits commit at once, drags
+                    // when released
+                    if response.drag_stopped() || (response.changed() && !response.dragged()) {
+                        changed = true;
                     }
-                    self.invalidate_cache();
+                    processor_params.insert(param.name, value);
                 }
 
                 if changed {
                     if let Some(view) = self.views.get_mut(&self.controls.fractal_type) {
+                        // Parameters carry over while the processor kind is
+                        // unchanged (e.g. picking other dual palettes)
+                        if std::mem::discriminant(&view.color_processor_type)
+                            == std::mem::discriminant(&self.controls.color_processor_type)
+                        {
+                            view.processor_params = processor_params;
+                        } else {
+                            view.processor_params.clear();
+                        }
                         view.max_iterations = self.controls.max_iterations;
                         view.fractal_params = self.controls.pending_fractal_params.clone();
                         view.palette_type = self.controls.palette_type;
@@ -917,6 +3761,13 @@ impl eframe::App for FractalApp {
                         self.reset_view();
                         self.invalidate_cache();
                     }
+                    if ui
+                        .button("Reset Coloring")
+                        .on_hover_text("Reset palette and color processor, keeping the view")
+                        .clicked()
+                    {
+                        self.reset_coloring();
+                    }
                     if ui
                         .button("Reset All")
                         .on_hover_text("Reset view, palette, and parameters")
@@ -925,8 +3776,49 @@ impl eframe::App for FractalApp {
                         self.reset_settings();
                     }
                 });
-
-                let can_undo = self.get_command_history().can_undo();
+                ui.horizontal(|ui| {
+                    ui.label("Zoom ×");
+                    ui.add(
+                        egui::DragValue::new(&mut self.exact_zoom_factor)
+                            .range(1.0..=MAX_EXACT_ZOOM_FACTOR)
+                            .speed(0.1),
+                    );
+                    if ui.button("In").clicked() {
+                        self.zoom_view(self.exact_zoom_factor);
+                    }
+                    if ui.button("Out").clicked() {
+                        self.zoom_view(1.0 / self.exact_zoom_factor);
+                    }
+                });
+                let mut duplicate_target = None;
+                egui::ComboBox::from_id_salt("duplicate_view")
+                    .selected_text("Copy view to...")
+                    .show_ui(ui, |ui| {
+                        for ft in self.fractal_registry.all_types() {
+                            if ft == self.controls.fractal_type {
+                                continue;
+                            }
+                            if let Some(metadata) = self.fractal_registry.metadata(ft) {
+                                if ui.selectable_label(false, metadata.display_name).clicked() {
+                                    duplicate_target = Some(ft);
+                                }
+                            }
+                        }
+                    })
+                    .response
+                    .on_hover_text("Render the same region with another fractal");
+                if let Some(target) = duplicate_target {
+                    self.duplicate_view_to(target);
+                }
+                if ui
+                    .button("Find interesting spot")
+                    .on_hover_text("Zoom to a random point near the set boundary")
+                    .clicked()
+                {
+                    self.find_interesting_spot();
+                }
+
+                let can_undo = self.get_command_history().can_undo();
                 let can_redo = self.get_command_history().can_redo();
                 ui.horizontal(|ui| {
                     if ui
@@ -942,23 +3834,268 @@ impl eframe::App for FractalApp {
                         self.redo();
                     }
                 });
+                let history_len = self.get_command_history().len();
+                if history_len > 0 {
+                    let mut position = self.get_command_history().position();
+                    if ui
+                        .add(egui::Slider::new(&mut position, 0..=history_len).text("History"))
+                        .on_hover_text("Drag to scrub through undo/redo history")
+                        .changed()
+                    {
+                        self.seek_history(position);
+                    }
+                }
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(can_undo, egui::Button::new("Export Path"))
+                        .on_hover_text(format!("Save the steps to this view to {}", VIEW_PATH_FILE))
+                        .clicked()
+                    {
+                        match self.export_view_path() {
+                            Ok(path) => self.push_log(format!("Saved path: {}", path.display())),
+                            Err(e) => self.push_log(format!("Error: {}", e)),
+                        }
+                    }
+                    if ui
+                        .button("Replay Path")
+                        .on_hover_text(format!("Replay the steps saved in {}", VIEW_PATH_FILE))
+                        .clicked()
+                    {
+                        match self.replay_view_path() {
+                            Ok(count) => self.push_log(format!("Replayed {} steps", count)),
+                            Err(e) => self.push_log(format!("Error: {}", e)),
+                        }
+                    }
+                });
 
                 ui.separator();
                 ui.horizontal(|ui| {
                     if ui.button("Save (S)").clicked() {
                         match self.save_image(self.export_scale) {
-                            Ok(path) => self.set_status(format!(
+                            Ok(Some(path)) => self.push_log(format!(
                                 "Saved {}x: {}",
                                 self.export_scale,
                                 path.display()
                             )),
-                            Err(e) => self.set_status(format!("Error: {}", e)),
+                            Ok(None) if !self.export_queue.pending.is_empty() => {
+                                self.set_status(format!(
+                                    "Queued {}x export ({} pending)",
+                                    self.export_scale,
+                                    self.export_queue.pending.len()
+                                ))
+                            }
+                            Ok(None) => {
+                                self.set_status(format!("Exporting {}x...", self.export_scale))
+                            }
+                            Err(e) => self.push_log(format!("Error: {}", e)),
                         }
                     }
-                    ui.radio_value(&mut self.export_scale, 1, "1x");
-                    ui.radio_value(&mut self.export_scale, 2, "2x");
-                    ui.radio_value(&mut self.export_scale, 4, "4x");
+                    for scale in EXPORT_SCALES {
+                        ui.radio_value(&mut self.export_scale, scale, format!("{}x", scale));
+                    }
+                    let streams = self.streams_export();
+                    for scale in TILED_EXPORT_SCALES {
+                        let radio = egui::RadioButton::new(
+                            self.export_scale == scale,
+                            format!("{}x", scale),
+                        );
+                        if ui
+                            .add_enabled(streams, radio)
+                            .on_disabled_hover_text("Turn on Tiled export for gigapixel scales")
+                            .clicked()
+                        {
+                            self.export_scale = scale;
+                        }
+                    }
+                });
+                if self.fractal.parameter_space_fractal().is_some()
+                    && ui
+                        .button("Export Julia+Seed")
+                        .on_hover_text(
+                            "Save this view beside its parameter plane with the seed marked",
+                        )
+                        .clicked()
+                {
+                    match self.export_julia_seed() {
+                        Ok(path) => self.set_status(format!("Queued: {}", path.display())),
+                        Err(e) => self.push_log(format!("Error: {}", e)),
+                    }
+                }
+                if ui
+                    .button("Save SVG")
+                    .on_hover_text("Vector export of iteration bands (slow, large files)")
+                    .clicked()
+                {
+                    match self.save_svg() {
+                        Ok(path) => self.push_log(format!("Saved: {}", path.display())),
+                        Err(e) => self.push_log(format!("Error: {}", e)),
+                    }
+                }
+                if ui
+                    .button("Export Orbit")
+                    .on_hover_text("Write the orbit of the view center to CSV (n, re, im)")
+                    .clicked()
+                {
+                    match self.export_orbit() {
+                        Ok(path) => self.push_log(format!("Saved: {}", path.display())),
+                        Err(e) => self.push_log(format!("Error: {}", e)),
+                    }
+                }
+                if let Some(job) = &self.export_queue.running {
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::ProgressBar::new(job.progress())
+                                .desired_width(160.0)
+                                .show_percentage(),
+                        );
+                        if ui.button("Cancel").clicked() {
+                            self.export_queue.cancel_current();
+                        }
+                    });
+                }
+                if !self.export_queue.pending.is_empty() || !self.export_queue.finished.is_empty() {
+                    ui.collapsing(
+                        format!("Export queue ({} pending)", self.export_queue.pending.len()),
+                        |ui| {
+                            for task in &self.export_queue.pending {
+                                ui.label(format!("Pending: {}", task.path().display()));
+                            }
+                            for finished in self.export_queue.finished.iter().rev() {
+                                ui.label(&finished.message)
+                                    .on_hover_text(finished.path.display().to_string());
+                            }
+                            if !self.export_queue.pending.is_empty()
+                                && ui.button("Clear pending").clicked()
+                            {
+                                self.export_queue.clear_pending();
+                            }
+                        },
+                    );
+                }
+                ui.horizontal(|ui| {
+                    ui.label("Bit depth:");
+                    ui.selectable_value(&mut self.export_bit_depth, ExportBitDepth::Eight, "8-bit")
+                        .on_hover_text("RGB through the current palette and coloring");
+                    ui.selectable_value(
+                        &mut self.export_bit_depth,
+                        ExportBitDepth::Sixteen,
+                        "16-bit gray",
+                    )
+                    .on_hover_text(
+                        "16-bit grayscale of the smooth iteration count (0 to max \
+                         iterations), free of banding; ignores palette and coloring",
+                    );
                 });
+                let eight_bit = self.export_bit_depth == ExportBitDepth::Eight;
+                ui.add_enabled(
+                    eight_bit,
+                    egui::Checkbox::new(&mut self.transparent_interior, "Transparent interior"),
+                )
+                .on_hover_text("Export the set's interior as transparent (RGBA PNG)");
+                ui.add_enabled(
+                    eight_bit && !self.transparent_interior,
+                    egui::Checkbox::new(&mut self.tiled_export, "Tiled export"),
+                )
+                .on_hover_text(
+                    "Write scaled exports to disk in row bands as they render instead of \
+                     holding the whole image in memory (for gigapixel renders)",
+                );
+
+                egui::CollapsingHeader::new(format!("Keyframes ({})", self.keyframes.len()))
+                    .id_salt("keyframes")
+                    .show(ui, |ui| {
+                        if ui
+                            .button("Add Keyframe")
+                            .on_hover_text("Capture the current view and parameters")
+                            .clicked()
+                        {
+                            self.add_keyframe();
+                        }
+                        let mut delete_index = None;
+                        for (i, keyframe) in self.keyframes.iter_mut().enumerate() {
+                            ui.horizontal(|ui| {
+                                ui.label(format!("#{}", i + 1));
+                                ui.add(
+                                    egui::DragValue::new(&mut keyframe.time)
+                                        .speed(0.1)
+                                        .range(0.0..=f64::MAX)
+                                        .suffix("s"),
+                                );
+                                ui.label(format!("zoom {:.2e}", keyframe.view.zoom));
+                                if ui.small_button("X").clicked() {
+                                    delete_index = Some(i);
+                                }
+                            });
+                        }
+                        if let Some(i) = delete_index {
+                            self.keyframes.remove(i);
+                        }
+                        ui.horizontal(|ui| {
+                            ui.label("Path:");
+                            let spiral =
+                                matches!(self.zoom_path, animation::ZoomPath::Spiral { .. });
+                            if ui.selectable_label(!spiral, "Linear").clicked() {
+                                self.zoom_path = animation::ZoomPath::Linear;
+                            }
+                            if ui
+                                .selectable_label(spiral, "Spiral")
+                                .on_hover_text(
+                                    "Spiral in toward each keyframe's center while zooming",
+                                )
+                                .clicked()
+                                && !spiral
+                            {
+                                self.zoom_path = animation::ZoomPath::spiral();
+                            }
+                        });
+                        if let animation::ZoomPath::Spiral { turns, tightness } =
+                            &mut self.zoom_path
+                        {
+                            ui.horizontal(|ui| {
+                                ui.label("Turns:");
+                                ui.add(egui::DragValue::new(turns).speed(0.1).range(-20.0..=20.0));
+                                ui.label("Tightness:");
+                                ui.add(
+                                    egui::DragValue::new(tightness).speed(0.1).range(0.0..=20.0),
+                                )
+                                .on_hover_text(
+                                    "How fast the spiral closes in: 0 shrinks evenly, \
+                                     higher values rush in early",
+                                );
+                            });
+                        }
+                        ui.checkbox(
+                            &mut self.lock_animation_colors,
+                            "Lock colors to first frame",
+                        )
+                        .on_hover_text(
+                            "Map every frame's colors over the iteration range of the \
+                                 most zoomed-out frame, so they don't flicker",
+                        );
+                        ui.horizontal(|ui| {
+                            ui.label("Frames:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.animation_frame_count)
+                                    .range(2..=10000),
+                            );
+                            if ui
+                                .add_enabled(
+                                    self.keyframes.len() >= 2,
+                                    egui::Button::new("Export Frames"),
+                                )
+                                .clicked()
+                            {
+                                match self.export_animation() {
+                                    Ok(dir) => self.set_status(format!(
+                                        "Queued {} frames for {}",
+                                        self.animation_frame_count,
+                                        dir.display()
+                                    )),
+                                    Err(e) => self.push_log(format!("Error: {}", e)),
+                                }
+                            }
+                        });
+                    });
 
                 ui.separator();
 
@@ -969,6 +4106,31 @@ impl eframe::App for FractalApp {
                     self.invalidate_cache();
                 }
 
+                if ui
+                    .checkbox(&mut self.render.native_dpi, "Native DPI")
+                    .on_hover_text(
+                        "Render at the display's physical resolution on HiDPI screens \
+                         (more pixels to compute)",
+                    )
+                    .changed()
+                {
+                    self.invalidate_cache();
+                }
+
+                #[cfg(feature = "gpu")]
+                if ui
+                    .checkbox(&mut self.render.gpu_enabled, "GPU Rendering")
+                    .on_hover_text(
+                        "Power-2 Mandelbrot/Julia on the GPU; falls back to CPU for deep zooms",
+                    )
+                    .changed()
+                {
+                    if self.render.gpu_enabled && !self.render.engine.gpu_available() {
+                        self.push_log("No GPU adapter found, rendering on CPU".to_string());
+                    }
+                    self.invalidate_cache();
+                }
+
                 let prev_adaptive = self.render.adaptive_iterations;
                 ui.checkbox(&mut self.render.adaptive_iterations, "Adaptive Iterations");
                 if self.render.adaptive_iterations != prev_adaptive {
@@ -979,13 +4141,314 @@ impl eframe::App for FractalApp {
                         "Current: {}",
                         self.calculate_adaptive_iterations(self.get_view().zoom)
                     ));
+                    if ui
+                        .checkbox(&mut self.render.render_budget, "Render Budget")
+                        .on_hover_text(format!(
+                            "Lower the iteration cap to keep renders under {} ms",
+                            RENDER_BUDGET_SECS * 1000.0
+                        ))
+                        .changed()
+                    {
+                        self.invalidate_cache();
+                    }
+                    if self.render.render_budget {
+                        ui.label(format!("Cap: {}", self.render.iteration_cap));
+                    }
+                }
+
+                ui.horizontal(|ui| {
+                    if ui
+                        .checkbox(&mut self.render.auto_render, "Auto-render")
+                        .on_hover_text(
+                            "Re-render after every change; turn off to batch expensive \
+                             changes and render them with the button or Enter",
+                        )
+                        .changed()
+                        && self.render.auto_render
+                    {
+                        self.render_now();
+                    }
+                    let label = if self.render.render_pending {
+                        "Render (pending)"
+                    } else {
+                        "Render"
+                    };
+                    if ui
+                        .add_enabled(self.render.render_pending, egui::Button::new(label))
+                        .clicked()
+                    {
+                        self.render_now();
+                    }
+                });
+
+                if ui
+                    .checkbox(&mut self.render.adaptive_quality, "Adaptive Quality")
+                    .on_hover_text(
+                        "Quick low-resolution drafts while panning and zooming, \
+                         full quality once idle",
+                    )
+                    .changed()
+                {
+                    self.invalidate_cache();
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("Interior color:");
+                    if ui
+                        .color_edit_button_srgba(&mut self.interior_color)
+                        .changed()
+                    {
+                        self.invalidate_cache();
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Selection:");
+                    ui.add_enabled_ui(!self.selection_auto_contrast, |ui| {
+                        ui.color_edit_button_srgba(&mut self.selection_color);
+                    });
+                    ui.checkbox(&mut self.selection_auto_contrast, "Auto-contrast")
+                        .on_hover_text(
+                            "Outline the zoom selection in black or white, whichever \
+                             stands out against the image under it",
+                        );
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Minimap border:");
+                    ui.color_edit_button_srgba(&mut self.minimap_border_color);
+                });
+                if ui
+                    .checkbox(&mut self.invert_colors, "Invert colors")
+                    .changed()
+                {
+                    self.refresh_colors();
+                }
+                ui.horizontal(|ui| {
+                    let mut changed = ui
+                        .checkbox(&mut self.pin_color_range, "Pin colors to")
+                        .on_hover_text(
+                            "Map colors over a fixed iteration range instead of the \
+                             iteration limit, so they stay put while animating",
+                        )
+                        .changed();
+                    ui.add_enabled_ui(self.pin_color_range, |ui| {
+                        let (lo, hi) = &mut self.color_range;
+                        changed |= ui
+                            .add(egui::DragValue::new(lo).range(0..=hi.saturating_sub(1)))
+                            .changed();
+                        ui.label("-");
+                        changed |= ui
+                            .add(egui::DragValue::new(hi).range(lo.saturating_add(1)..=u32::MAX))
+                            .changed();
+                    });
+                    if changed {
+                        self.invalidate_cache();
+                    }
+                });
+                let brightness = ui.add(
+                    egui::Slider::new(
+                        &mut self.color_adjustment.brightness,
+                        -MAX_BRIGHTNESS..=MAX_BRIGHTNESS,
+                    )
+                    .text("brightness"),
+                );
+                let contrast = ui.add(
+                    egui::Slider::new(&mut self.color_adjustment.contrast, 0.0..=MAX_CONTRAST)
+                        .text("contrast"),
+                );
+                if brightness.changed() || contrast.changed() {
+                    self.refresh_colors();
+                }
+                if ui
+                    .add_enabled(
+                        self.render.supersampling_enabled,
+                        egui::Checkbox::new(&mut self.color_adjustment.dither, "Dither"),
+                    )
+                    .on_hover_text(
+                        "Ordered-dither the averaged supersamples instead of truncating \
+                         them, breaking up 8-bit banding in smooth gradients (needs \
+                         Supersampling)",
+                    )
+                    .changed()
+                {
+                    self.invalidate_cache();
+                }
+
+                egui::CollapsingHeader::new("Color Curves")
+                    .id_salt("color_curves")
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.radio_value(&mut self.curve_channel, 0, "R");
+                            ui.radio_value(&mut self.curve_channel, 1, "G");
+                            ui.radio_value(&mut self.curve_channel, 2, "B");
+                        });
+                        let curves = &mut self.color_adjustment.curves;
+                        let (curve, color) = match self.curve_channel {
+                            0 => (&mut curves.red, egui::Color32::RED),
+                            1 => (&mut curves.green, egui::Color32::GREEN),
+                            _ => (&mut curves.blue, egui::Color32::LIGHT_BLUE),
+                        };
+                        let response = ui::curve_editor(ui, curve, color);
+                        let mut edited = response.dragged() || response.drag_stopped();
+                        if ui
+                            .add_enabled(!curve.is_identity(), egui::Button::new("Reset channel"))
+                            .clicked()
+                        {
+                            *curve = Default::default();
+                            edited = true;
+                        }
+                        if edited {
+                            self.refresh_colors();
+                        }
+                    });
+
+                ui.checkbox(&mut self.bookmark_screenshots, "Bookmark Screenshots")
+                    .on_hover_text(format!(
+                        "Save a PNG of the view to {}/ with each new bookmark",
+                        BOOKMARK_IMAGE_DIR
+                    ));
+
+                ui.checkbox(&mut self.notify_on_completion, "Flash When Done")
+                    .on_hover_text(format!(
+                        "Flash the canvas when a render or export takes over {}s",
+                        NOTIFY_MIN_RENDER_SECS
+                    ));
+
+                ui.checkbox(&mut self.view_locked, "Lock View")
+                    .on_hover_text("Ignore pan and zoom input (mouse and keyboard)");
+
+                ui.checkbox(&mut self.show_grid, "Show Grid");
+
+                ui.checkbox(&mut self.show_iteration_labels, "Iteration Labels (debug)")
+                    .on_hover_text(format!(
+                        "Print the iteration count at the center of each {}px cell",
+                        ITERATION_LABEL_CELL_PX
+                    ));
+
+                ui.add_enabled(
+                    self.controls.fractal_type == FractalType::Mandelbrot,
+                    egui::Checkbox::new(&mut self.show_lemniscates, "Lemniscates"),
+                )
+                .on_hover_text(format!(
+                    "Outline where z_n first escapes for n = 1 to {}: the successive \
+                     curves approximating the set's boundary",
+                    LEMNISCATE_LEVELS
+                ))
+                .on_disabled_hover_text("Lemniscates are drawn for the Mandelbrot set only");
+
+                if ui
+                    .checkbox(&mut self.measure_mode, "Measure")
+                    .on_hover_text("Click two points to measure the distance between them")
+                    .changed()
+                {
+                    self.interaction.measure_points.clear();
                 }
 
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.lock_aspect, "Lock Aspect")
+                        .on_hover_text("Letterbox the view to a fixed width:height ratio");
+                    if self.lock_aspect {
+                        ui.add(
+                            egui::DragValue::new(&mut self.locked_aspect_ratio)
+                                .range(MIN_LOCKED_ASPECT..=MAX_LOCKED_ASPECT)
+                                .speed(0.01)
+                                .suffix(" : 1"),
+                        );
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Zoom render delay:");
+                    ui.add(
+                        egui::DragValue::new(&mut self.render.zoom_render_delay)
+                            .range(0..=MAX_RENDER_DELAY_FRAMES)
+                            .suffix(" frames"),
+                    )
+                    .on_hover_text(
+                        "Frames the zoom preview shows before a zoom-box render starts \
+                         (0 renders immediately)",
+                    );
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Min zoom:");
+                    ui.add(
+                        egui::DragValue::new(&mut self.min_zoom)
+                            .range(MIN_ZOOM_LIMIT..=1.0)
+                            .speed(0.001),
+                    )
+                    .on_hover_text("Zooming out stops at this level");
+                });
+                ui.checkbox(&mut self.snap_zoom, "Snap zoom to powers of two")
+                    .on_hover_text(
+                        "Scroll, keyboard and zoom-box zooms land on exact 2x/4x/... scales",
+                    );
+
+                ui.horizontal(|ui| {
+                    ui.label("Undo history:");
+                    let response = ui
+                        .add(
+                            egui::DragValue::new(&mut self.history_size)
+                                .range(MIN_HISTORY_SIZE..=MAX_HISTORY_SIZE)
+                                .suffix(" steps"),
+                        )
+                        .on_hover_text("Undo steps kept per fractal");
+                    // Trim only once the edit is finished, not at every
+                    // intermediate value while dragging or typing
+                    if response.drag_stopped() || response.lost_focus() {
+                        self.set_history_size(self.history_size);
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Export precision:");
+                    ui.add(
+                        egui::DragValue::new(&mut self.min_feature_ulps)
+                            .range(0.0..=MAX_MIN_FEATURE_ULPS)
+                            .speed(0.1)
+                            .suffix(" ulps"),
+                    )
+                    .on_hover_text(
+                        "Refuse exports whose pixels are closer together than this many \
+                         units of f64 precision (0 disables)",
+                    );
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Export size limit:");
+                    ui.add(
+                        egui::DragValue::new(&mut self.export_megapixel_cap)
+                            .range(MIN_EXPORT_MEGAPIXEL_CAP..=MAX_EXPORT_MEGAPIXEL_CAP)
+                            .speed(1.0)
+                            .suffix(" MP"),
+                    )
+                    .on_hover_text(
+                        "Refuse exports larger than this many megapixels instead of \
+                         running out of memory",
+                    );
+                });
+
                 let prev_minimap = self.minimap_enabled;
                 ui.checkbox(&mut self.minimap_enabled, "Show Minimap");
                 if self.minimap_enabled != prev_minimap {
                     self.invalidate_cache();
                 }
+                if self.minimap_enabled
+                    && ui
+                        .checkbox(&mut self.minimap_full_coloring, "Minimap full coloring")
+                        .on_hover_text(
+                            "Color the minimap like the main view (palette offset and \
+                             color processor) instead of the plain palette",
+                        )
+                        .changed()
+                {
+                    self.minimap_dirty = true;
+                }
+                if self.fractal.parameter_space_fractal().is_some() {
+                    ui.checkbox(&mut self.parameter_map_enabled, "Show Parameter Map")
+                        .on_hover_text(
+                            "Map of c values (bounded = connected set); click to pick c",
+                        );
+                }
 
                 // Bookmark dialog
                 if self.show_bookmark_dialog {
@@ -1013,6 +4476,43 @@ impl eframe::App for FractalApp {
                     }
                 });
 
+                ui.horizontal(|ui| {
+                    ui.label("File:");
+                    ui.text_edit_singleline(&mut self.bookmarks_file_path);
+                });
+                ui.horizontal(|ui| {
+                    let path = PathBuf::from(self.bookmarks_file_path.trim());
+                    if ui
+                        .add_enabled(!self.bookmarks.is_empty(), egui::Button::new("Export"))
+                        .clicked()
+                    {
+                        match self.export_bookmarks(&path) {
+                            Ok(count) => self.push_log(format!(
+                                "Exported {} bookmarks to {}",
+                                count,
+                                path.display()
+                            )),
+                            Err(e) => self.push_log(format!("Error: {}", e)),
+                        }
+                    }
+                    let merge = ui
+                        .button("Import (merge)")
+                        .on_hover_text("Append the file's bookmarks to this list");
+                    let replace = ui
+                        .button("Import (replace)")
+                        .on_hover_text("Replace this list with the file's bookmarks");
+                    if merge.clicked() || replace.clicked() {
+                        match self.import_bookmarks(&path, replace.clicked()) {
+                            Ok(count) => self.push_log(format!(
+                                "Imported {} bookmarks from {}",
+                                count,
+                                path.display()
+                            )),
+                            Err(e) => self.push_log(format!("Error: {}", e)),
+                        }
+                    }
+                });
+
                 // Show bookmark status message if present
                 if let Some((msg, _)) = &self.interaction.status_message {
                     ui.label(egui::RichText::new(msg).color(egui::Color32::YELLOW));
@@ -1026,8 +4526,16 @@ impl eframe::App for FractalApp {
                         .show(ui, |ui| {
                             for i in 0..self.bookmarks.len() {
                                 ui.horizontal(|ui| {
-                                    if ui.button(&self.bookmarks[i].name).clicked() {
-                                        load_index = Some(i);
+                                    if ui
+                                        .button(&self.bookmarks[i].name)
+                                        .on_hover_text(
+                                            "Ctrl+click: apply only its position, zoom \
+                                             and iterations to this fractal",
+                                        )
+                                        .clicked()
+                                    {
+                                        load_index =
+                                            Some((i, ui.input(|input| input.modifiers.ctrl)));
                                     }
                                     if ui.button("×").clicked() {
                                         delete_index = Some(i);
@@ -1035,8 +4543,8 @@ impl eframe::App for FractalApp {
                                 });
                             }
                         });
-                    if let Some(i) = load_index {
-                        self.load_bookmark(i);
+                    if let Some((i, geometry_only)) = load_index {
+                        self.load_bookmark(i, geometry_only);
                     }
                     if let Some(i) = delete_index {
                         self.delete_bookmark(i);
@@ -1050,38 +4558,112 @@ impl eframe::App for FractalApp {
 
                 ui.separator();
                 let view = self.get_view();
-                ui.label(format!(
+                let center_label = ui.label(format!(
                     "Center: ({:.6}, {:.6}) x {:.2e}",
                     view.center_x, view.center_y, view.zoom
                 ));
+                if let Some(hp) = &view.hp_center {
+                    center_label.on_hover_text(format!("re: {}\nim: {}", hp.re, hp.im));
+                }
 
                 // Mouse coordinates display
                 if let Some((fx, fy)) = self.interaction.mouse_fractal_pos {
                     ui.separator();
                     ui.label(format!("Cursor: ({:.6}, {:.6})", fx, fy));
+                    if let Some(readout) = &self.interaction.mouse_readout {
+                        ui.label(readout);
+                    }
                 }
 
                 ui.separator();
                 ui.label("Mouse:");
                 ui.label("Click + Drag: Select zoom region");
                 ui.label("Wheel: Zoom in/out at cursor");
+                ui.label("Shift+Wheel: Cycle palette offset");
+
+                egui::CollapsingHeader::new(format!("Log ({})", self.log.len()))
+                    .id_salt("event_log")
+                    .show(ui, |ui| {
+                        egui::ScrollArea::vertical()
+                            .max_height(LOG_SCROLL_HEIGHT)
+                            .stick_to_bottom(true)
+                            .show(ui, |ui| {
+                                for (time, message) in &self.log {
+                                    ui.label(format!(
+                                        "{:>5.0}s ago  {}",
+                                        time.elapsed().as_secs_f64(),
+                                        message
+                                    ));
+                                }
+                            });
+                        if ui.button("Clear").clicked() {
+                            self.log.clear();
+                        }
+                    });
 
                 ui.separator();
                 ui.label("Keyboard:");
                 ui.label("+/- : Zoom in/out");
+                ui.label("2 / 0 : Zoom x2 / x10 (Shift: out)");
                 ui.label("Arrows : Pan");
                 ui.label("R : Reset view");
                 ui.label("Shift+R : Reset all");
                 ui.label("Ctrl+Z : Undo");
                 ui.label("Ctrl+Y : Redo");
+                ui.label("Tab : Previous fractal");
+                ui.label("Enter : Render (auto-render off)");
                 ui.label("S : Save image");
+                ui.label("Hold H : Preview high iterations");
 
                 ui.separator();
+                if ui
+                    .button("Iteration Difference")
+                    .on_hover_text("Show which pixels change at another iteration count")
+                    .clicked()
+                {
+                    self.show_difference_window = true;
+                }
                 if ui.button("About").clicked() {
                     self.show_about_dialog = true;
                 }
             });
 
+        if self.show_difference_window {
+            let mut open = true;
+            let mut compare = false;
+            egui::Window::new("Iteration Difference")
+                .open(&mut open)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{} vs", self.controls.max_iterations));
+                        ui.add(
+                            egui::DragValue::new(&mut self.difference_iterations)
+                                .range(MIN_ITERATIONS..=PREVIEW_MAX_ITERATIONS),
+                        );
+                        ui.label("iterations");
+                        compare = ui.button("Compare").clicked();
+                    });
+                    if let Some(ref texture) = self.cached_difference_texture {
+                        ui.image((texture.id(), texture.size_vec2()));
+                        let (changed, total) = self.difference_changed;
+                        ui.label(format!(
+                            "{} of {} pixels changed ({:.1}%)",
+                            changed,
+                            total,
+                            100.0 * changed as f64 / total.max(1) as f64
+                        ));
+                    }
+                });
+            if compare {
+                self.render_iteration_difference(ctx);
+            }
+            if !open {
+                self.show_difference_window = false;
+                self.cached_difference_texture = None;
+            }
+        }
+
         // About dialog
         if self.show_about_dialog {
             // Load about image once and cache it
@@ -1128,7 +4710,20 @@ impl eframe::App for FractalApp {
         }
 
         egui::CentralPanel::default().show(ctx, |ui| {
-            let rect = ui.max_rect();
+            let canvas_rect = ui.max_rect();
+            let rect = if self.lock_aspect {
+                let (w, h) = letterbox_size(
+                    canvas_rect.width(),
+                    canvas_rect.height(),
+                    self.locked_aspect_ratio,
+                );
+                let rect = egui::Rect::from_center_size(canvas_rect.center(), egui::vec2(w, h));
+                ui.painter()
+                    .rect_filled(canvas_rect, 0.0, egui::Color32::BLACK);
+                rect
+            } else {
+                canvas_rect
+            };
             let width = rect.width() as u32;
             let height = rect.height() as u32;
 
@@ -1136,9 +4731,32 @@ impl eframe::App for FractalApp {
                 return;
             }
 
+            // Interaction stays in logical points; only the render is scaled
+            let (render_width, render_height) = canvas_render_size(
+                rect.width(),
+                rect.height(),
+                ctx.pixels_per_point(),
+                self.render.native_dpi,
+            );
+
             // Update viewport dimensions if changed
-            if width != self.render.cached_width || height != self.render.cached_height {
-                self.update_viewport_dimensions(width, height);
+            if render_width != self.render.cached_width
+                || render_height != self.render.cached_height
+            {
+                self.update_viewport_dimensions(render_width, render_height);
+            }
+
+            // Re-render once a resize settles; meanwhile the last image is
+            // stretched over the canvas
+            let canvas_size = (render_width, render_height);
+            if self
+                .render
+                .resize_debounce
+                .update(canvas_size, self.render.canvas_size)
+            {
+                self.invalidate_cache();
+            } else if self.render.resize_debounce.pending(self.render.canvas_size) {
+                ctx.request_repaint();
             }
 
             let response =
@@ -1154,6 +4772,29 @@ impl eframe::App for FractalApp {
                 self.update_mouse_position(pos, &rect);
             } else {
                 self.interaction.mouse_fractal_pos = None;
+                self.interaction.mouse_readout = None;
+            }
+
+            // Shift+scroll cycles the palette offset. egui reports
+            // shift-scrolling as horizontal, so it never reaches the zoom below
+            let offset_applies = self
+                .controls
+                .color_processor_type
+                .uses_palette_offset(self.controls.palette_type);
+            if response.hovered() && offset_applies {
+                let (shift, scroll_delta) =
+                    ctx.input(|i| (i.modifiers.shift, i.smooth_scroll_delta.x));
+                if shift && scroll_delta.abs() > SCROLL_DEADZONE {
+                    self.scroll_palette_offset(
+                        scroll_delta * PALETTE_OFFSET_SCROLL_STEP,
+                        Instant::now(),
+                    );
+                }
+            }
+            if self.finish_palette_scroll(Instant::now()) {
+                ctx.request_repaint_after(std::time::Duration::from_secs_f64(
+                    PALETTE_SCROLL_GESTURE_SECS,
+                ));
             }
 
             // Scroll-wheel zoom at cursor position
@@ -1164,24 +4805,20 @@ impl eframe::App for FractalApp {
                         let sx = (pos.x - rect.min.x) as u32;
                         let sy = (pos.y - rect.min.y) as u32;
                         if sx < width && sy < height {
-                            let factor = if scroll_delta > 0.0 {
-                                1.0 + scroll_delta as f64 * SCROLL_ZOOM_SENSITIVITY
-                            } else {
-                                1.0 / (1.0 + (-scroll_delta) as f64 * SCROLL_ZOOM_SENSITIVITY)
-                            };
-                            self.zoom_at_point(factor, sx, sy, width, height);
+                            self.scroll_zoom(scroll_delta, sx, sy, width, height);
                         }
                     }
                 }
             }
 
-            if response.drag_started() {
+            if response.drag_started() && !self.navigation_locked() {
                 self.interaction.drag_start = pointer_pos;
                 self.interaction.drag_current = pointer_pos;
                 self.interaction.zoom_preview = None;
             }
 
             if response.dragged() {
+                self.interaction.last_interaction = Some(Instant::now());
                 if let Some(pos) = pointer_pos {
                     self.interaction.drag_current = Some(pos);
                 }
@@ -1203,9 +4840,17 @@ impl eframe::App for FractalApp {
 
                         self.render.prev_image = self.render.cached_image.clone();
 
+                        // Fit the whole selection, letterboxing when its
+                        // aspect differs from the canvas
+                        let (fit_min, fit_max) = fit_selection_to_canvas(
+                            egui::pos2(min_x, min_y),
+                            egui::pos2(max_x, max_y),
+                            rect.width(),
+                            rect.height(),
+                        );
                         self.interaction.zoom_preview = Some(ZoomPreview {
-                            sel_min: egui::pos2(min_x, min_y),
-                            sel_max: egui::pos2(max_x, max_y),
+                            sel_min: fit_min,
+                            sel_max: fit_max,
                         });
 
                         let view = self.get_view();
@@ -1226,8 +4871,11 @@ impl eframe::App for FractalApp {
                         let new_center_x = (tl.re + br.re) / 2.0;
                         let new_center_y = (tl.im + br.im) / 2.0;
 
-                        let sel_height_px = max_y - min_y;
-                        let new_zoom = view.zoom * (height as f64 / sel_height_px as f64);
+                        let fit_height_px = fit_max.y - fit_min.y;
+                        let new_zoom = self.target_zoom(
+                            view.zoom,
+                            view.zoom * (rect.height() as f64 / fit_height_px as f64),
+                        );
 
                         // Calculate adaptive iterations if enabled
                         let new_max_iter = if self.render.adaptive_iterations {
@@ -1246,6 +4894,8 @@ impl eframe::App for FractalApp {
                             fractal_params: view.fractal_params.clone(),
                             palette_type: self.controls.palette_type,
                             color_processor_type: self.controls.color_processor_type,
+                            hp_center: view.hp_center.clone(),
+                            processor_params: view.processor_params.clone(),
                         };
                         self.set_view(new_view.clone());
 
@@ -1258,7 +4908,7 @@ impl eframe::App for FractalApp {
                             self.controls.pending_max_iterations = new_max_iter;
                         }
 
-                        self.render.render_delay = RENDER_DELAY_FRAMES;
+                        self.schedule_zoom_render();
                     }
                 }
 
@@ -1267,6 +4917,17 @@ impl eframe::App for FractalApp {
                 ctx.request_repaint();
             }
 
+            if self.measure_mode && response.clicked() {
+                if let Some(pos) = pointer_pos {
+                    let sx = (pos.x - rect.min.x) as u32;
+                    let sy = (pos.y - rect.min.y) as u32;
+                    if sx < width && sy < height {
+                        let world = self.viewport.screen_to_world(sx, sy, width, height);
+                        self.add_measure_point((world.re, world.im));
+                    }
+                }
+            }
+
             // Initial render check (pause when bookmark dialog is open)
             if !self.show_bookmark_dialog {
                 if self.render.cached_width == 0 || self.render.cached_height == 0 {
@@ -1276,17 +4937,32 @@ impl eframe::App for FractalApp {
                     if self.render.render_delay == 0 {
                         self.invalidate_cache();
                     }
+                } else if self.render.quality == RenderQuality::Draft
+                    && !self.render.is_rendering
+                    && !self.render.needs_render
+                    && !self.render.parameter_preview
+                {
+                    // Replace the draft once the view has settled. This
+                    // finishes a render already started, so it doesn't wait
+                    // for an explicit render when auto-render is off
+                    if self.current_render_quality(response.dragged()) == RenderQuality::Full {
+                        self.invalidate_cache();
+                        self.render_now();
+                    } else {
+                        ctx.request_repaint_after(std::time::Duration::from_secs_f64(
+                            QUALITY_IDLE_SECS,
+                        ));
+                    }
                 }
             }
 
             // Main fractal display - update texture only when image changes
             if self.render.texture_dirty {
                 if let Some(ref image) = self.render.cached_image {
-                    self.render.cached_texture = Some(ctx.load_texture(
-                        "fractal",
-                        image.clone(),
-                        egui::TextureOptions::default(),
-                    ));
+                    let mut image = image.clone();
+                    self.color_lut.apply_all(&mut image.pixels);
+                    self.render.cached_texture =
+                        Some(ctx.load_texture("fractal", image, egui::TextureOptions::default()));
                     self.render.texture_dirty = false;
                 }
             }
@@ -1321,16 +4997,44 @@ impl eframe::App for FractalApp {
                 None
             };
 
+            // Parameter map in the bottom-right corner; clicking it picks c
+            self.render_parameter_map(ctx);
+            let parameter_map_rect = if let Some(ref texture) = self.cached_parameter_map_texture {
+                let map_size = PARAMETER_MAP_SIZE as f32;
+                let map_rect = egui::Rect::from_min_size(
+                    egui::pos2(rect.max.x - map_size - 10.0, rect.max.y - map_size - 10.0),
+                    egui::vec2(map_size, map_size),
+                );
+                ui.put(map_rect, egui::Image::new((texture.id(), map_rect.size())));
+                let map_response = ui
+                    .interact(
+                        map_rect,
+                        egui::Id::new("parameter_map"),
+                        egui::Sense::click_and_drag(),
+                    )
+                    .on_hover_text("Click to set c");
+                if map_response.clicked() || map_response.drag_stopped() {
+                    if let Some(pos) = map_response.interact_pointer_pos() {
+                        let u = ((pos.x - map_rect.min.x) / map_rect.width()).clamp(0.0, 1.0);
+                        let v = ((pos.y - map_rect.min.y) / map_rect.height()).clamp(0.0, 1.0);
+                        let (c_re, c_im) = parameter_map_to_seed(u as f64, v as f64);
+                        self.set_seed(c_re, c_im);
+                    }
+                }
+                Some(map_rect)
+            } else {
+                None
+            };
+
             let painter = ui.painter();
 
             // Draw zoom preview if available
             if let Some(ref preview) = self.interaction.zoom_preview {
                 if let Some(ref image) = self.render.prev_image {
-                    let texture = ctx.load_texture(
-                        "fractal_preview",
-                        image.clone(),
-                        egui::TextureOptions::default(),
-                    );
+                    let mut image = image.clone();
+                    self.color_lut.apply_all(&mut image.pixels);
+                    let texture =
+                        ctx.load_texture("fractal_preview", image, egui::TextureOptions::default());
                     let uv_min = egui::pos2(
                         (preview.sel_min.x / rect.width()).clamp(0.0, 1.0),
                         (preview.sel_min.y / rect.height()).clamp(0.0, 1.0),
@@ -1348,25 +5052,67 @@ impl eframe::App for FractalApp {
                 }
             }
 
-            // Draw selection rectangle outline
-            if self.interaction.zoom_preview.is_none() {
-                if let (Some(start), Some(end)) =
-                    (self.interaction.drag_start, self.interaction.drag_current)
-                {
+            if self.show_grid && self.interaction.zoom_preview.is_none() {
+                self.draw_grid(painter, rect);
+            }
+
+            if self.show_iteration_labels && self.interaction.zoom_preview.is_none() {
+                self.draw_iteration_labels(painter, rect);
+            }
+
+            if self.show_lemniscates && self.interaction.zoom_preview.is_none() {
+                self.draw_lemniscates(painter, rect);
+            }
+
+            if self.measure_mode && self.interaction.zoom_preview.is_none() {
+                self.draw_measurement(painter, rect);
+            }
+
+            // Draw selection rectangle outline
+            if self.interaction.zoom_preview.is_none() {
+                if let (Some(start), Some(end)) =
+                    (self.interaction.drag_start, self.interaction.drag_current)
+                {
                     let sel_rect = egui::Rect::from_two_pos(start, end);
-                    painter.rect_stroke(sel_rect, 1.0, egui::Stroke::new(2.0, egui::Color32::BLUE));
+                    let color = self.selection_stroke_color(sel_rect, rect);
+                    painter.rect_stroke(sel_rect, 1.0, egui::Stroke::new(2.0, color));
                 }
             }
 
             // Draw border around minimap
             if let Some(minimap_rect) = minimap_rect {
+                self.draw_minimap_view_rect(painter, minimap_rect);
                 painter.rect_stroke(
                     minimap_rect,
                     0.0,
-                    egui::Stroke::new(2.0, egui::Color32::WHITE),
+                    egui::Stroke::new(2.0, self.minimap_border_color),
                 );
             }
 
+            // Border and current-c marker on the parameter map
+            if let Some(map_rect) = parameter_map_rect {
+                painter.rect_stroke(map_rect, 0.0, egui::Stroke::new(2.0, egui::Color32::WHITE));
+                if let Some((u, v)) = self.seed_on_parameter_map() {
+                    let marker = egui::pos2(
+                        map_rect.min.x + u as f32 * map_rect.width(),
+                        map_rect.min.y + v as f32 * map_rect.height(),
+                    );
+                    painter.circle_stroke(marker, 4.0, egui::Stroke::new(2.0, egui::Color32::RED));
+                }
+            }
+
+            // Fading white flash after a long render or export
+            if let Some(start) = self.completion_flash {
+                let t = start.elapsed().as_secs_f32() / NOTIFY_FLASH_SECS;
+                if t < 1.0 {
+                    let alpha = ((1.0 - t) * NOTIFY_FLASH_ALPHA) as u8;
+                    painter.rect_filled(rect, 0.0, egui::Color32::from_white_alpha(alpha));
+                    ctx.request_repaint();
+                } else {
+                    self.completion_flash = None;
+                }
+            }
+
             // Rendering logic using the new RenderEngine
             if self.render.is_rendering {
                 if let Some(ref config) = self.render.config.clone() {
@@ -1430,10 +5176,7 @@ impl eframe::App for FractalApp {
                             self.render.render_chunk_start = 0;
                             self.render.config = None;
 
-                            if let Some(start_time) = self.render.render_start_time.take() {
-                                self.render.last_render_time =
-                                    Some(start_time.elapsed().as_secs_f64());
-                            }
+                            self.record_render_time();
                             ctx.request_repaint();
                         }
                     } else {
@@ -1458,6 +5201,9 @@ impl eframe::App for FractalApp {
                         } else {
                             // Rendering complete
                             if let Some(pixels) = self.render.engine.finalize(config) {
+                                if renderer::is_nearly_uniform(&pixels) {
+                                    self.warn_featureless_view(config.max_iterations);
+                                }
                                 self.render.cached_image = Some(egui::ColorImage {
                                     size: [config.width as _, config.height as _],
                                     pixels,
@@ -1474,10 +5220,10 @@ impl eframe::App for FractalApp {
                             self.render.render_chunk_start = 0;
                             self.render.config = None;
 
-                            if let Some(start_time) = self.render.render_start_time.take() {
-                                self.render.last_render_time =
-                                    Some(start_time.elapsed().as_secs_f64());
-                            }
+                            self.record_render_time();
+                            // Pan patches cover only part of the frame, so
+                            // only full renders tune the budget
+                            self.update_iteration_budget(config.max_iterations);
                             ctx.request_repaint();
                         }
                     }
@@ -1486,27 +5232,52 @@ impl eframe::App for FractalApp {
 
             // Start new render if needed
             if self.render.needs_render && !self.render.is_rendering && !self.show_bookmark_dialog {
-                let view = self.get_view();
-                let max_iter = if self.render.adaptive_iterations {
-                    self.calculate_adaptive_iterations(view.zoom)
+                let max_iter = self.rendered_iterations();
+
+                let quality = if self.render.parameter_preview {
+                    RenderQuality::Draft
                 } else {
-                    self.controls.max_iterations
+                    self.current_render_quality(response.dragged())
+                };
+                let draft = quality == RenderQuality::Draft;
+                let (config_width, config_height, max_iter) = if draft {
+                    (
+                        (render_width / DRAFT_QUALITY_DIVISOR).max(1),
+                        (render_height / DRAFT_QUALITY_DIVISOR).max(1),
+                        (max_iter / DRAFT_QUALITY_DIVISOR).max(MIN_ITERATIONS),
+                    )
+                } else {
+                    (render_width, render_height, max_iter)
                 };
 
+                // Pan regions patch the cached image, so they only apply
+                // when it has the resolution being rendered
+                if let Some(cached) = &self.render.cached_image {
+                    if cached.size != [config_width as usize, config_height as usize] {
+                        self.render.partial_render_regions.clear();
+                        self.render.current_region_index = 0;
+                    }
+                }
+
                 let config = RenderConfig {
-                    width,
-                    height,
-                    supersampling: self.render.supersampling_enabled,
+                    width: config_width,
+                    height: config_height,
+                    supersampling: self.render.supersampling_enabled && !draft,
                     max_iterations: max_iter,
                     palette_type: self.controls.palette_type,
                     palette_offset: self.controls.palette_offset,
-                    color_pipeline: color_pipeline::ColorPipeline::from_type(
-                        view.color_processor_type,
-                    ),
+                    palette_frequency: self.controls.palette_frequency,
+                    color_pipeline: self.color_pipeline(),
+                    interior_color: self.interior_color,
+                    invert: self.invert_colors,
+                    adjustment: self.color_adjustment,
+                    use_gpu: self.render.gpu_enabled,
                 };
 
                 self.render.engine.start_render(&config);
                 self.render.config = Some(config);
+                self.render.canvas_size = (render_width, render_height);
+                self.render.quality = quality;
                 self.render.is_rendering = true;
                 self.render.render_start_time = Some(Instant::now());
                 self.render.render_progress = 0.0;
@@ -1528,7 +5299,35 @@ impl eframe::App for FractalApp {
             default_palette: self.controls.palette_type,
             supersampling_enabled: self.render.supersampling_enabled,
             adaptive_iterations: self.render.adaptive_iterations,
+            adaptive_quality: self.render.adaptive_quality,
+            render_budget: self.render.render_budget,
             bookmarks: self.bookmarks.clone(),
+            interior_color: [
+                self.interior_color.r(),
+                self.interior_color.g(),
+                self.interior_color.b(),
+            ],
+            invert_colors: self.invert_colors,
+            selection_color: rgb_bytes(self.selection_color),
+            selection_auto_contrast: self.selection_auto_contrast,
+            minimap_border_color: rgb_bytes(self.minimap_border_color),
+            color_adjustment: self.color_adjustment,
+            notify_on_completion: self.notify_on_completion,
+            bookmark_screenshots: self.bookmark_screenshots,
+            native_dpi: self.render.native_dpi,
+            min_zoom: self.min_zoom,
+            snap_zoom: self.snap_zoom,
+            render_delay_frames: self.render.zoom_render_delay,
+            min_feature_ulps: self.min_feature_ulps,
+            export_megapixel_cap: self.export_megapixel_cap,
+            history_size: self.history_size,
+            views: self.views.clone(),
+            custom_palette: self
+                .controls
+                .custom_palette
+                .as_ref()
+                .map(|p| p.stops())
+                .unwrap_or_default(),
         };
         if let Err(e) = config.save() {
             eprintln!("Failed to save config: {}", e);
@@ -1536,10 +5335,778 @@ impl eframe::App for FractalApp {
     }
 }
 
+fn default_min_zoom() -> f64 {
+    DEFAULT_MIN_ZOOM
+}
+
+fn default_render_delay_frames() -> u32 {
+    RENDER_DELAY_FRAMES
+}
+
+fn default_selection_color() -> [u8; 3] {
+    [0, 0, 255]
+}
+
+fn default_minimap_border_color() -> [u8; 3] {
+    [255, 255, 255]
+}
+
+fn rgb_color([r, g, b]: [u8; 3]) -> egui::Color32 {
+    egui::Color32::from_rgb(r, g, b)
+}
+
+fn rgb_bytes(color: egui::Color32) -> [u8; 3] {
+    [color.r(), color.g(), color.b()]
+}
+
+/// Mean Rec. 601 luma (0-255) of `image` within the pixel box
+/// `[min, max)`, sampled on a grid of at most `CONTRAST_SAMPLES` points per
+/// side. None if the box is empty.
+fn mean_luma(
+    image: &egui::ColorImage,
+    lut: &ColorLut,
+    min: [usize; 2],
+    max: [usize; 2],
+) -> Option<f32> {
+    let max = [max[0].min(image.size[0]), max[1].min(image.size[1])];
+    if min[0] >= max[0] || min[1] >= max[1] {
+        return None;
+    }
+    let step_x = (max[0] - min[0]).div_ceil(CONTRAST_SAMPLES);
+    let step_y = (max[1] - min[1]).div_ceil(CONTRAST_SAMPLES);
+    let (mut total, mut count) = (0.0, 0);
+    for y in (min[1]..max[1]).step_by(step_y) {
+        for x in (min[0]..max[0]).step_by(step_x) {
+            let c = lut.apply(image.pixels[y * image.size[0] + x]);
+            total += 0.299 * c.r() as f32 + 0.587 * c.g() as f32 + 0.114 * c.b() as f32;
+            count += 1;
+        }
+    }
+    Some(total / count as f32)
+}
+
+/// Overlay color that stands out against a background of the given mean
+/// luma: white over dark images, black over light ones
+fn contrasting_overlay(mean_luma: f32) -> egui::Color32 {
+    if mean_luma < CONTRAST_LUMA_THRESHOLD {
+        egui::Color32::WHITE
+    } else {
+        egui::Color32::BLACK
+    }
+}
+
+fn default_min_feature_ulps() -> f64 {
+    DEFAULT_MIN_FEATURE_ULPS
+}
+
+fn default_export_megapixel_cap() -> f64 {
+    DEFAULT_EXPORT_MEGAPIXEL_CAP
+}
+
+fn default_history_size() -> usize {
+    UNDO_HISTORY_CAPACITY
+}
+
+/// Refuse an export whose pixels would be closer together than f64 can
+/// resolve at this view (`min_ulps` of 0 disables the check)
+fn check_export_precision(
+    view: &FractalViewState,
+    width: u32,
+    height: u32,
+    min_ulps: f64,
+) -> Result<(), String> {
+    let viewport = Viewport::from_view(view.center_x, view.center_y, view.zoom, width, height);
+    let spacing = viewport.pixel_spacing_ulps(height);
+    if spacing < min_ulps {
+        return Err(format!(
+            "Export at {}x{} exceeds f64 precision at zoom {:.3e} ({:.2} ulps per pixel, \
+             limit {}); this needs a perturbation renderer - zoom out or export smaller",
+            width, height, view.zoom, spacing, min_ulps
+        ));
+    }
+    Ok(())
+}
+
+/// Size of a `scale`x export of a `width` x `height` view, refused when it
+/// exceeds `megapixel_cap` so huge requests fail cleanly instead of
+/// attempting the allocation
+fn scaled_export_size(
+    width: u32,
+    height: u32,
+    scale: u32,
+    megapixel_cap: f64,
+) -> Result<(u32, u32), String> {
+    let (export_width, export_height) = (width as u64 * scale as u64, height as u64 * scale as u64);
+    let megapixels = (export_width * export_height) as f64 / 1e6;
+    if megapixels > megapixel_cap {
+        return Err(format!(
+            "Export at {}x{} is {:.0} megapixels (about {:.1} GB), over the {} megapixel \
+             limit - export smaller or raise the limit in Settings",
+            export_width,
+            export_height,
+            megapixels,
+            megapixels * EXPORT_BYTES_PER_PIXEL / 1e3,
+            megapixel_cap
+        ));
+    }
+    match (u32::try_from(export_width), u32::try_from(export_height)) {
+        (Ok(w), Ok(h)) => Ok((w, h)),
+        _ => Err(format!(
+            "Export at {}x{} is too large",
+            export_width, export_height
+        )),
+    }
+}
+
+/// Keep a zoom level between the zoom-out limit and `MAX_ZOOM`
+fn clamp_zoom(zoom: f64, min_zoom: f64) -> f64 {
+    zoom.clamp(min_zoom, MAX_ZOOM)
+}
+
+/// Nearest power of two to `requested` (relative to the base zoom of 1).
+/// A request that would round back onto `current` still moves one power in
+/// its direction, so small scroll steps are not swallowed.
+fn snap_zoom_to_power_of_two(current: f64, requested: f64) -> f64 {
+    let mut exponent = requested.log2().round();
+    let current_exponent = current.log2();
+    if requested > current && exponent <= current_exponent {
+        exponent = current_exponent.floor() + 1.0;
+    } else if requested < current && exponent >= current_exponent {
+        exponent = current_exponent.ceil() - 1.0;
+    }
+    2f64.powi(exponent as i32)
+}
+
+/// Resolution/iteration level of a render pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RenderQuality {
+    /// Reduced resolution and iterations while the user is interacting
+    Draft,
+    Full,
+}
+
+/// Draft while the user is interacting or until the view has been idle for
+/// `QUALITY_IDLE_SECS`, full quality afterwards
+fn select_render_quality(interacting: bool, idle_secs: f64) -> RenderQuality {
+    if interacting || idle_secs < QUALITY_IDLE_SECS {
+        RenderQuality::Draft
+    } else {
+        RenderQuality::Full
+    }
+}
+
+/// Whether a finished render or export should trigger the completion flash
+fn should_notify(enabled: bool, elapsed_secs: f64) -> bool {
+    enabled && elapsed_secs >= NOTIFY_MIN_RENDER_SECS
+}
+
+/// Render quality after a parameter slider interaction: a coarse draft
+/// while it is dragged, full quality once the change is committed, and no
+/// new render otherwise
+fn parameter_render_quality(previewing: bool, committed: bool) -> Option<RenderQuality> {
+    if committed {
+        Some(RenderQuality::Full)
+    } else if previewing {
+        Some(RenderQuality::Draft)
+    } else {
+        None
+    }
+}
+
+/// Iteration cap that keeps a render near `budget_secs`, assuming render
+/// time grows linearly with iterations: a render of `iterations` that took
+/// `elapsed_secs` scales to the budget, within `MIN_BUDGET_ITERATIONS` and
+/// `MAX_ITERATIONS_CAP`
+fn budget_iteration_cap(iterations: u32, elapsed_secs: f64, budget_secs: f64) -> u32 {
+    if elapsed_secs.is_nan() || elapsed_secs <= 0.0 {
+        return MAX_ITERATIONS_CAP;
+    }
+    let cap = iterations as f64 * budget_secs / elapsed_secs;
+    cap.clamp(MIN_BUDGET_ITERATIONS as f64, MAX_ITERATIONS_CAP as f64) as u32
+}
+
+/// Iterations to render with: boosted while the high-iteration preview key
+/// is held, never below the base setting
+fn effective_iterations(base: u32, preview_held: bool) -> u32 {
+    if preview_held {
+        base.saturating_mul(PREVIEW_ITERATION_MULTIPLIER)
+            .min(PREVIEW_MAX_ITERATIONS)
+            .max(base)
+    } else {
+        base
+    }
+}
+
+/// Pack rendered pixels (row-major) into an RGB image
+fn pixels_to_rgb_image(
+    pixels: &[egui::Color32],
+    width: u32,
+    height: u32,
+) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
+    let mut img: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(width, height);
+    for (i, color) in pixels.iter().enumerate() {
+        let x = (i % width as usize) as u32;
+        let y = (i / width as usize) as u32;
+        img.put_pixel(x, y, Rgb([color.r(), color.g(), color.b()]));
+    }
+    img
+}
+
+/// A location parsed from pasted text
+#[derive(Debug, Clone, PartialEq)]
+struct PastedLocation {
+    re: f64,
+    im: f64,
+    zoom: Option<f64>,
+    /// Full-precision center when the text carries more digits than f64
+    hp_center: Option<HpCenter>,
+}
+
+/// Parse shared coordinates such as `re=-0.7436, im=0.1318, zoom=1e9`,
+/// `re: -0.7436 im: 0.1318` or plain `-0.7436, 0.1318, 1e9`. Zoom is
+/// optional; when present it must be positive.
+fn parse_location(text: &str) -> Option<PastedLocation> {
+    let tokens: Vec<&str> = text
+        .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+        .filter(|t| !t.is_empty())
+        .collect();
+    if tokens.is_empty() {
+        return None;
+    }
+
+    let (re, im, zoom) = if tokens.iter().any(|t| t.contains('=') || t.contains(':')) {
+        // Keyed form; allow "key=value", "key:value" or "key: value"
+        let mut values: HashMap<String, &str> = HashMap::new();
+        let mut pending_key: Option<String> = None;
+        for token in &tokens {
+            if let Some((key, value)) = token.split_once(['=', ':']) {
+                let key = key.to_ascii_lowercase();
+                if value.is_empty() {
+                    pending_key = Some(key);
+                } else {
+                    values.insert(key, value);
+                }
+            } else if let Some(key) = pending_key.take() {
+                values.insert(key, token);
+            } else {
+                return None;
+            }
+        }
+        let lookup = |keys: &[&str]| keys.iter().find_map(|k| values.get(*k).copied());
+        (
+            lookup(&["re", "real", "x"])?,
+            lookup(&["im", "imag", "y"])?,
+            lookup(&["zoom", "z"]),
+        )
+    } else {
+        match tokens.as_slice() {
+            [re, im] => (*re, *im, None),
+            [re, im, zoom] => (*re, *im, Some(*zoom)),
+            _ => return None,
+        }
+    };
+
+    let re_value: f64 = re.parse().ok().filter(|v: &f64| v.is_finite())?;
+    let im_value: f64 = im.parse().ok().filter(|v: &f64| v.is_finite())?;
+    let zoom = match zoom {
+        Some(z) => Some(z.parse().ok().filter(|v: &f64| v.is_finite() && *v > 0.0)?),
+        None => None,
+    };
+    let hp_center = HpCenter::parse(re, im)
+        .ok()
+        .filter(|hp| Some(hp) != HpCenter::from_f64(re_value, im_value).as_ref());
+
+    Some(PastedLocation {
+        re: re_value,
+        im: im_value,
+        zoom,
+        hp_center,
+    })
+}
+
+/// Pick a 1/2/5 x 10^n grid spacing giving roughly `target_lines` lines
+/// across `range` world units. Returns 0.0 for an empty or invalid range.
+fn nice_grid_interval(range: f64, target_lines: f64) -> f64 {
+    if !range.is_finite() || range <= 0.0 || target_lines <= 0.0 {
+        return 0.0;
+    }
+    let raw = range / target_lines;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let normalized = raw / magnitude;
+    let step = if normalized < 1.5 {
+        1.0
+    } else if normalized < 3.5 {
+        2.0
+    } else if normalized < 7.5 {
+        5.0
+    } else {
+        10.0
+    };
+    step * magnitude
+}
+
+/// One cell of the iteration-label overlay: its center in canvas pixels
+/// and the iteration count computed there
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct IterationLabel {
+    x: u32,
+    y: u32,
+    iterations: u32,
+}
+
+/// Iteration counts at the centers of `ITERATION_LABEL_CELL_PX` cells
+/// covering the canvas, or none if there would be more than
+/// `ITERATION_LABEL_MAX_CELLS`
+fn iteration_label_cells(
+    fractal: &dyn Fractal,
+    viewport: &Viewport,
+    width: u32,
+    height: u32,
+    max_iter: u32,
+) -> Vec<IterationLabel> {
+    let columns = width / ITERATION_LABEL_CELL_PX;
+    let rows = height / ITERATION_LABEL_CELL_PX;
+    if (columns * rows) as usize > ITERATION_LABEL_MAX_CELLS {
+        return Vec::new();
+    }
+    let mut labels = Vec::with_capacity((columns * rows) as usize);
+    for row in 0..rows {
+        for column in 0..columns {
+            let x = column * ITERATION_LABEL_CELL_PX + ITERATION_LABEL_CELL_PX / 2;
+            let y = row * ITERATION_LABEL_CELL_PX + ITERATION_LABEL_CELL_PX / 2;
+            let world = viewport.screen_to_world(x, y, width, height);
+            labels.push(IterationLabel {
+                x,
+                y,
+                iterations: fractal.compute(world.re, world.im, max_iter),
+            });
+        }
+    }
+    labels
+}
+
+/// Closed outlines, in world coordinates, of the lemniscates
+/// {c : z_k(c) stays within the bailout for k <= n} for n = 1..=`levels`
+/// (index n - 1), traced with marching squares over samples every
+/// `LEMNISCATE_CELL_PX` pixels. The escape count of `compute` with
+/// `levels + 1` iterations gives every level from one pass.
+fn lemniscate_contours(
+    fractal: &dyn Fractal,
+    viewport: &Viewport,
+    width: u32,
+    height: u32,
+    levels: u32,
+) -> Vec<Vec<Vec<(f64, f64)>>> {
+    let columns = width / LEMNISCATE_CELL_PX + 1;
+    let rows = height / LEMNISCATE_CELL_PX + 1;
+    let origin = viewport.screen_to_world(0, 0, width, height);
+    let step_x = viewport.screen_to_world(LEMNISCATE_CELL_PX, 0, width, height) - origin;
+    let step_y = viewport.screen_to_world(0, LEMNISCATE_CELL_PX, width, height) - origin;
+    let sample = |column: f64, row: f64| origin + step_x * column + step_y * row;
+
+    let escapes: Vec<u32> = (0..rows)
+        .into_par_iter()
+        .flat_map(|row| {
+            (0..columns)
+                .map(|column| {
+                    let c = sample(column as f64, row as f64);
+                    fractal.compute(c.re, c.im, levels + 1)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    (1..=levels)
+        .map(|n| {
+            svg::trace_region(&escapes, columns, rows, |escape| escape > n)
+                .into_iter()
+                .map(|outline| {
+                    // Doubled grid coordinates: k / 2 samples along each axis
+                    outline
+                        .into_iter()
+                        .map(|(kx, ky)| {
+                            let c = sample(kx as f64 / 2.0, ky as f64 / 2.0);
+                            (c.re, c.im)
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Whether at least `interval_secs` have passed since `last` (or it never
+/// happened), for rate-limiting expensive redraws
+fn throttle_elapsed(last: Option<Instant>, now: Instant, interval_secs: f64) -> bool {
+    last.is_none_or(|last| now.saturating_duration_since(last).as_secs_f64() >= interval_secs)
+}
+
+/// Euclidean distance between two world points
+fn world_distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+/// Describe the iteration behaviour of a single point for the cursor readout.
+fn cursor_readout(fractal: &dyn Fractal, re: f64, im: f64, max_iter: u32) -> String {
+    let result = fractal.compute_full(re, im, max_iter);
+    if result.escaped {
+        format!("Iterations: {} (escaped)", result.iterations)
+    } else {
+        format!("Iterations: {} (inside)", result.iterations)
+    }
+}
+
+/// Expand a drag-zoom selection around its center to the canvas aspect
+/// ratio, so a view filling the canvas contains everything selected
+fn fit_selection_to_canvas(
+    min: egui::Pos2,
+    max: egui::Pos2,
+    width: f32,
+    height: f32,
+) -> (egui::Pos2, egui::Pos2) {
+    let center = min + (max - min) / 2.0;
+    let scale = ((max.x - min.x) / width).max((max.y - min.y) / height);
+    let half = egui::vec2(width, height) * scale / 2.0;
+    (center - half, center + half)
+}
+
+/// Render `view` in a plain palette pinned to one fixed range, so probes at
+/// different iteration counts only differ where a pixel's count does
+fn iteration_probe(
+    fractal: &dyn Fractal,
+    view: &FractalViewState,
+    width: u32,
+    height: u32,
+    max_iter: u32,
+) -> Option<Vec<egui::Color32>> {
+    let config = RenderConfig {
+        color_pipeline: color_pipeline::ColorPipeline::from_type(
+            color_pipeline::ColorProcessorType::Palette,
+        )
+        .with_iteration_range(Some((0, MAX_ITERATIONS_CAP))),
+        ..RenderConfig::new(width, height, max_iter)
+    };
+    RenderEngine::default().render_high_res(fractal, view, &config, 1, &ExportControl::none())
+}
+
+/// Per-pixel color difference of two equally sized renders as a heatmap:
+/// unchanged pixels are black, larger changes run from red to yellow to
+/// white. Also returns how many pixels changed.
+fn difference_heatmap(a: &[egui::Color32], b: &[egui::Color32]) -> (Vec<egui::Color32>, usize) {
+    let mut changed = 0;
+    let pixels = a
+        .iter()
+        .zip(b)
+        .map(|(a, b)| {
+            let diff = a.r().abs_diff(b.r()) as u32
+                + a.g().abs_diff(b.g()) as u32
+                + a.b().abs_diff(b.b()) as u32;
+            if diff == 0 {
+                return egui::Color32::BLACK;
+            }
+            changed += 1;
+            // Square root so small changes remain visible
+            let heat = (diff as f32 / (3.0 * 255.0)).sqrt() * 3.0;
+            let channel = |start: f32| ((heat - start).clamp(0.0, 1.0) * 255.0) as u8;
+            egui::Color32::from_rgb(channel(0.0).max(64), channel(1.0), channel(2.0))
+        })
+        .collect();
+    (pixels, changed)
+}
+
+/// One `n,re,im` row per orbit point under a header. Values are written in
+/// full (shortest round-trip) precision.
+fn orbit_to_csv(orbit: &[Complex64]) -> String {
+    let mut csv = String::from("n,re,im\n");
+    for (n, z) in orbit.iter().enumerate() {
+        csv.push_str(&format!("{},{},{}\n", n, z.re, z.im));
+    }
+    csv
+}
+
+/// Lowest iteration count on a rising ladder past which raising it further
+/// changes fewer than `SUGGEST_CHANGE_THRESHOLD` of the pixels of a coarse
+/// `target_width` probe of `view`. Colors are pinned to a fixed range, so a
+/// pixel only changes when more iterations let it escape.
+fn suggest_iterations(
+    fractal: &dyn Fractal,
+    view: &FractalViewState,
+    target_width: u32,
+    aspect_ratio: f64,
+) -> Option<u32> {
+    let width = target_width.max(1);
+    let height = ((width as f64 / aspect_ratio).round() as u32).max(1);
+    let probes = iteration_ladder(MIN_ITERATIONS, MAX_ITERATIONS_CAP)
+        .into_iter()
+        .map_while(|max_iter| {
+            let pixels = iteration_probe(fractal, view, width, height, max_iter)?;
+            Some((max_iter, pixels))
+        });
+    converged_iterations(probes, SUGGEST_CHANGE_THRESHOLD)
+}
+
+/// Iteration counts from `min` growing by `SUGGEST_ITERATION_STEP`, ending
+/// exactly at `max`
+fn iteration_ladder(min: u32, max: u32) -> Vec<u32> {
+    let mut ladder = vec![min.min(max)];
+    while let Some(&last) = ladder.last().filter(|&&last| last < max) {
+        let next = ((last as f64 * SUGGEST_ITERATION_STEP).round() as u32).max(last + 1);
+        ladder.push(next.min(max));
+    }
+    ladder
+}
+
+/// First probe level whose successor changes fewer than `threshold` of the
+/// pixels; the last level if none settle. Probes are consumed lazily, so
+/// later levels are never rendered once one converges.
+fn converged_iterations(
+    mut probes: impl Iterator<Item = (u32, Vec<egui::Color32>)>,
+    threshold: f64,
+) -> Option<u32> {
+    let (mut level, mut pixels) = probes.next()?;
+    for (next_level, next_pixels) in probes {
+        let changed = pixels
+            .iter()
+            .zip(&next_pixels)
+            .filter(|(a, b)| a != b)
+            .count();
+        if (changed as f64) < threshold * pixels.len() as f64 {
+            return Some(level);
+        }
+        (level, pixels) = (next_level, next_pixels);
+    }
+    Some(level)
+}
+
+/// Pixel size to render a canvas of the given logical size. With `native`
+/// set, HiDPI displays get one rendered pixel per device pixel instead of
+/// an upscaled logical-resolution image.
+fn canvas_render_size(width: f32, height: f32, pixels_per_point: f32, native: bool) -> (u32, u32) {
+    let scale = if native && pixels_per_point > 0.0 {
+        pixels_per_point
+    } else {
+        1.0
+    };
+    ((width * scale) as u32, (height * scale) as u32)
+}
+
+/// Largest width x height with the given width/height ratio that fits the
+/// canvas; the rest of the canvas is drawn as letterbox bars
+fn letterbox_size(width: f32, height: f32, aspect: f32) -> (f32, f32) {
+    if width > height * aspect {
+        (height * aspect, height)
+    } else {
+        (width, width / aspect)
+    }
+}
+
+/// Wrap a palette offset into [0, 1)
+fn wrap_palette_offset(offset: f32) -> f32 {
+    let wrapped = offset.rem_euclid(1.0);
+    // rem_euclid rounds tiny negative values up to exactly 1.0
+    if wrapped >= 1.0 {
+        0.0
+    } else {
+        wrapped
+    }
+}
+
+/// Parameter map position (fractions of its width and height, y down) to
+/// the seed c it represents
+fn parameter_map_to_seed(u: f64, v: f64) -> (f64, f64) {
+    (
+        (u - 0.5) * PARAMETER_MAP_RANGE,
+        (0.5 - v) * PARAMETER_MAP_RANGE,
+    )
+}
+
+/// Inverse of `parameter_map_to_seed`
+fn seed_to_parameter_map(c_re: f64, c_im: f64) -> (f64, f64) {
+    (
+        c_re / PARAMETER_MAP_RANGE + 0.5,
+        0.5 - c_im / PARAMETER_MAP_RANGE,
+    )
+}
+
+/// Render every registered fractal at its default view and save one PNG
+/// per type (named by registry id) into `dir`.
+fn render_gallery(
+    registry: &FractalRegistry,
+    dir: &std::path::Path,
+    width: u32,
+    height: u32,
+    max_iterations: u32,
+    palette_type: PaletteType,
+) -> Result<Vec<PathBuf>, String> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("Failed to create gallery directory: {}", e))?;
+    let engine = RenderEngine::default();
+    let mut paths = Vec::new();
+
+    for ft in registry.all_types() {
+        let (Some(fractal), Some(metadata)) = (registry.create(ft), registry.metadata(ft)) else {
+            continue;
+        };
+        let view = FractalViewState {
+            center_x: metadata.default_center.0,
+            center_y: metadata.default_center.1,
+            zoom: metadata.default_zoom,
+            max_iterations,
+            palette_type,
+            ..Default::default()
+        };
+        let config = RenderConfig {
+            palette_type,
+            color_pipeline: color_pipeline::ColorPipeline::from_type(view.color_processor_type),
+            ..RenderConfig::new(width, height, max_iterations)
+        };
+        let pixels =
+            engine.render_high_res(fractal.as_ref(), &view, &config, 1, &ExportControl::none());
+        let pixels = pixels.ok_or("Render cancelled")?;
+
+        let path = dir.join(format!("{}.png", metadata.id));
+        pixels_to_rgb_image(&pixels, width, height)
+            .save(&path)
+            .map_err(|e| format!("Failed to save {}: {}", path.display(), e))?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+/// Per-frame render times from a benchmark run
+struct BenchStats {
+    frame_secs: Vec<f64>,
+}
+
+impl BenchStats {
+    fn mean(&self) -> f64 {
+        self.frame_secs.iter().sum::<f64>() / self.frame_secs.len().max(1) as f64
+    }
+
+    fn median(&self) -> f64 {
+        let mut sorted = self.frame_secs.clone();
+        sorted.sort_by(f64::total_cmp);
+        match sorted.len() {
+            0 => 0.0,
+            n if n.is_multiple_of(2) => (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0,
+            n => sorted[n / 2],
+        }
+    }
+}
+
+/// Render the scripted zoom sequence into the seahorse valley `runs` times
+/// and collect the time of every frame
+fn run_benchmark(
+    fractal: &dyn Fractal,
+    runs: usize,
+    zoom_steps: usize,
+    width: u32,
+    height: u32,
+    max_iterations: u32,
+) -> BenchStats {
+    let engine = RenderEngine::default();
+    let mut frame_secs = Vec::with_capacity(runs * zoom_steps);
+    for _ in 0..runs {
+        for step in 0..zoom_steps {
+            let view = FractalViewState {
+                center_x: BENCH_CENTER.0,
+                center_y: BENCH_CENTER.1,
+                zoom: BENCH_ZOOM_FACTOR.powi(step as i32),
+                max_iterations,
+                ..Default::default()
+            };
+            let start = Instant::now();
+            let config = RenderConfig {
+                palette_type: view.palette_type,
+                color_pipeline: color_pipeline::ColorPipeline::from_type(view.color_processor_type),
+                ..RenderConfig::new(width, height, max_iterations)
+            };
+            let _ = engine.render_high_res(fractal, &view, &config, 1, &ExportControl::none());
+            frame_secs.push(start.elapsed().as_secs_f64());
+        }
+    }
+    BenchStats { frame_secs }
+}
+
 fn main() -> eframe::Result {
     eprintln!("STARTING Fractal Oxide...");
 
-    let config = AppConfig::load();
+    let (config, warnings) = AppConfig::load();
+
+    // Headless gallery export: render every fractal type and exit
+    if std::env::args().any(|arg| arg == "--gallery") {
+        match render_gallery(
+            &FractalRegistry::default(),
+            std::path::Path::new(GALLERY_DIR),
+            GALLERY_WIDTH,
+            GALLERY_HEIGHT,
+            config.default_iterations,
+            config.default_palette,
+        ) {
+            Ok(paths) => eprintln!("Rendered {} images to {}/", paths.len(), GALLERY_DIR),
+            Err(e) => eprintln!("Gallery render failed: {}", e),
+        }
+        return Ok(());
+    }
+
+    // Headless benchmark: time a fixed Mandelbrot zoom sequence and exit
+    if std::env::args().any(|arg| arg == "--bench") {
+        let Some(fractal) = FractalRegistry::default().create(FractalType::Mandelbrot) else {
+            return Ok(());
+        };
+        let stats = run_benchmark(
+            fractal.as_ref(),
+            BENCH_RUNS,
+            BENCH_ZOOM_STEPS,
+            BENCH_WIDTH,
+            BENCH_HEIGHT,
+            BENCH_ITERATIONS,
+        );
+        eprintln!(
+            "Rendered {} frames at {}x{}: mean {:.1} ms, median {:.1} ms",
+            stats.frame_secs.len(),
+            BENCH_WIDTH,
+            BENCH_HEIGHT,
+            stats.mean() * 1000.0,
+            stats.median() * 1000.0
+        );
+        return Ok(());
+    }
+
+    // Headless statistics: interior fraction of Julia sets over a grid of c
+    if std::env::args().any(|arg| arg == "--interior-stats") {
+        let Some(mut fractal) = FractalRegistry::default().create(FractalType::Julia) else {
+            return Ok(());
+        };
+        let axis = |name, (min, max)| stats::ParameterAxis {
+            name,
+            min,
+            max,
+            steps: STATS_STEPS,
+        };
+        let (x, y) = (axis("c_real", STATS_C_REAL), axis("c_imag", STATS_C_IMAG));
+        let samples = stats::interior_fraction_grid(
+            fractal.as_mut(),
+            &x,
+            &y,
+            STATS_REGION,
+            STATS_SAMPLES,
+            STATS_ITERATIONS,
+        );
+        match std::fs::write(STATS_FILE, stats::samples_to_csv(x.name, y.name, &samples)) {
+            Ok(()) => eprintln!(
+                "Wrote {} parameter samples to {}",
+                samples.len(),
+                STATS_FILE
+            ),
+            Err(e) => eprintln!("Failed to write {}: {}", STATS_FILE, e),
+        }
+        return Ok(());
+    }
+    for warning in &warnings {
+        eprintln!("Config warning: {}", warning);
+    }
 
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
@@ -1551,6 +6118,1802 @@ fn main() -> eframe::Result {
     eframe::run_native(
         "Fractal Oxide",
         options,
-        Box::new(|_cc| Ok(Box::new(FractalApp::new(&config)))),
+        Box::new(move |_cc| {
+            let mut app = FractalApp::new(&config);
+            if !warnings.is_empty() {
+                app.push_log(format!("Config: {}", warnings.join("; ")));
+            }
+            Ok(Box::new(app))
+        }),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_bookmark() -> Bookmark {
+        Bookmark {
+            name: "test".to_string(),
+            fractal_type: FractalType::Mandelbrot,
+            center_x: -0.75,
+            center_y: 0.1,
+            zoom: 4.0,
+            max_iterations: 300,
+            palette_type: PaletteType::Fire,
+            color_processor_type: color_pipeline::ColorProcessorType::default(),
+            fractal_params: HashMap::new(),
+            hp_center: None,
+            processor_params: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_bookmark_screenshot() {
+        let dir = std::env::temp_dir().join(format!("fractal_bookmark_png_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.bookmark_image_dir = dir.clone();
+        app.render.cached_image = Some(egui::ColorImage::new([8, 6], egui::Color32::RED));
+
+        app.add_bookmark("no screenshot".to_string());
+        assert!(!dir.exists());
+
+        app.bookmark_screenshots = true;
+        app.add_bookmark("Seahorse valley/2".to_string());
+        let path = dir.join("Seahorse_valley_2.png");
+        let image = image::open(&path).unwrap().to_rgb8();
+        assert_eq!(image.dimensions(), (8, 6));
+        assert_eq!(app.bookmarks.len(), 2);
+
+        // Names sharing a stem don't overwrite earlier screenshots
+        app.add_bookmark("Seahorse valley 2".to_string());
+        app.add_bookmark("Seahorse valley/2".to_string());
+        assert!(path.exists());
+        assert!(dir.join("Seahorse_valley_2_2.png").exists());
+        assert!(dir.join("Seahorse_valley_2_3.png").exists());
+        assert_eq!(app.bookmarks.len(), 4);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_bookmark_file_round_trip() {
+        let dir = std::env::temp_dir().join(format!("fractal_bookmarks_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bookmarks.json");
+
+        let mut source = FractalApp::new(&AppConfig::default());
+        let mut julia = test_bookmark();
+        julia.name = "julia".to_string();
+        julia.fractal_type = FractalType::Julia;
+        julia.fractal_params.insert("c_real".to_string(), -0.7);
+        julia.hp_center = Some(HpCenter::parse("-0.75000000000000000001", "0.1").unwrap());
+        source.bookmarks = vec![test_bookmark(), julia];
+        assert_eq!(source.export_bookmarks(&path), Ok(2));
+
+        let mut target = FractalApp::new(&AppConfig::default());
+        let mut existing = test_bookmark();
+        existing.name = "existing".to_string();
+        target.bookmarks = vec![existing.clone()];
+        assert_eq!(target.import_bookmarks(&path, true), Ok(2));
+        assert_eq!(target.bookmarks, source.bookmarks);
+
+        target.bookmarks = vec![existing.clone()];
+        assert_eq!(target.import_bookmarks(&path, false), Ok(2));
+        assert_eq!(target.bookmarks.len(), 3);
+        assert_eq!(target.bookmarks[0], existing);
+        assert_eq!(target.bookmarks[1..], source.bookmarks[..]);
+
+        // A bad file leaves the list alone
+        std::fs::write(&path, "not json").unwrap();
+        assert!(target.import_bookmarks(&path, true).is_err());
+        assert_eq!(target.bookmarks.len(), 3);
+
+        // Degenerate entries are repaired on import and logged
+        let mut broken = test_bookmark();
+        broken.name = "broken".to_string();
+        broken.zoom = -1.0;
+        broken.max_iterations = 0;
+        write_bookmarks(&path, &[broken]).unwrap();
+        assert_eq!(target.import_bookmarks(&path, true), Ok(1));
+        assert_eq!(target.bookmarks[0].zoom, 1.0);
+        assert_eq!(target.bookmarks[0].max_iterations, MIN_ITERATIONS);
+        assert!(target
+            .log
+            .iter()
+            .any(|(_, entry)| entry.contains("'broken' repaired")));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_bookmark_geometry_only() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.controls.fractal_type = FractalType::Julia;
+        app.fractal = app.create_fractal(FractalType::Julia);
+        app.set_view(app.views[&FractalType::Julia].clone());
+        let julia_params = app.get_view().fractal_params;
+        app.bookmarks.push(test_bookmark());
+
+        app.load_bookmark(0, true);
+        assert_eq!(app.controls.fractal_type, FractalType::Julia);
+        let view = app.get_view();
+        assert_eq!((view.center_x, view.center_y, view.zoom), (-0.75, 0.1, 4.0));
+        assert_eq!(view.max_iterations, 300);
+        assert_eq!(app.controls.max_iterations, 300);
+        // Coloring and parameters stay with the current fractal
+        assert_ne!(view.palette_type, PaletteType::Fire);
+        assert_eq!(view.fractal_params, julia_params);
+        assert!(app.get_command_history().can_undo());
+
+        // A plain load switches to the bookmark's fractal
+        app.load_bookmark(0, false);
+        assert_eq!(app.controls.fractal_type, FractalType::Mandelbrot);
+        assert_eq!(app.controls.palette_type, PaletteType::Fire);
+    }
+
+    #[test]
+    fn test_bookmark_sanitize_valid_unchanged() {
+        let mut b = test_bookmark();
+        assert!(!b.sanitize());
+        assert_eq!(b.zoom, 4.0);
+        assert_eq!(b.center_x, -0.75);
+    }
+
+    #[test]
+    fn test_bookmark_sanitize_zero_zoom() {
+        let mut b = test_bookmark();
+        b.zoom = 0.0;
+        assert!(b.sanitize());
+        assert!(b.zoom > 0.0 && b.zoom.is_finite());
+    }
+
+    #[test]
+    fn test_bookmark_sanitize_nan_center() {
+        let mut b = test_bookmark();
+        b.center_x = f64::NAN;
+        b.center_y = f64::INFINITY;
+        b.fractal_params.insert("power".to_string(), f64::NAN);
+        b.hp_center = HpCenter::from_f64(0.25, 0.5);
+        assert!(b.sanitize());
+        assert_eq!(
+            (b.center_x, b.center_y),
+            FractalType::Mandelbrot.default_center()
+        );
+        assert!(b.hp_center.is_none());
+        assert!(b.fractal_params.is_empty());
+    }
+
+    #[test]
+    fn test_bookmark_hp_center_round_trip() {
+        let mut b = test_bookmark();
+        b.hp_center = Some(
+            HpCenter::parse(
+                "-0.74364388703715870475219150611477458",
+                "0.13182590420531197049313205638642636",
+            )
+            .unwrap(),
+        );
+        let json = serde_json::to_string(&b).unwrap();
+        let back: Bookmark = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.hp_center, b.hp_center);
+        assert_eq!(
+            back.hp_center.unwrap().re.to_string(),
+            "-0.74364388703715870475219150611477458"
+        );
+    }
+
+    #[test]
+    fn test_bookmark_processor_params_round_trip() {
+        let processor = color_pipeline::ColorProcessorType::OrbitTrapOrigin;
+        let saved = color_pipeline::ColorPipeline::from_type_with_params(
+            processor,
+            &HashMap::from([("threshold".to_string(), 0.125)]),
+        );
+
+        let mut b = test_bookmark();
+        b.color_processor_type = processor;
+        b.processor_params = saved.parameter_values();
+        let json = serde_json::to_string(&b).unwrap();
+        let back: Bookmark = serde_json::from_str(&json).unwrap();
+
+        let restored = color_pipeline::ColorPipeline::from_type_with_params(
+            back.color_processor_type,
+            &back.processor_params,
+        );
+        assert_eq!(restored.parameter_values(), saved.parameter_values());
+        assert_eq!(restored.parameter_values()["threshold"], 0.125);
+
+        // Bookmarks written before processor params existed still load
+        let mut legacy: serde_json::Value = serde_json::from_str(&json).unwrap();
+        legacy.as_object_mut().unwrap().remove("processor_params");
+        let legacy = legacy.to_string();
+        assert!(!legacy.contains("processor_params"));
+        let legacy: Bookmark = serde_json::from_str(&legacy).unwrap();
+        assert!(legacy.processor_params.is_empty());
+    }
+
+    #[test]
+    fn test_parse_location() {
+        let expected = PastedLocation {
+            re: -0.743643887037,
+            im: 0.131825904205,
+            zoom: Some(1e9),
+            hp_center: None,
+        };
+        for text in [
+            "re=-0.743643887037, im=0.131825904205, zoom=1e9",
+            "re: -0.743643887037 im: 0.131825904205 zoom: 1e9",
+            "  RE=-0.743643887037;IM=0.131825904205;ZOOM=1000000000 ",
+            "-0.743643887037, 0.131825904205, 1e9",
+            "-0.743643887037 0.131825904205 1e9",
+        ] {
+            assert_eq!(parse_location(text), Some(expected.clone()), "{}", text);
+        }
+
+        let no_zoom = parse_location("-0.5, 0.25").unwrap();
+        assert_eq!((no_zoom.re, no_zoom.im, no_zoom.zoom), (-0.5, 0.25, None));
+
+        // Extra digits beyond f64 are kept in the high-precision center
+        let deep = parse_location("re=-0.74364388703715870475219150611477, im=0.1318").unwrap();
+        assert_eq!(
+            deep.hp_center.unwrap().re.to_string(),
+            "-0.74364388703715870475219150611477"
+        );
+
+        for text in [
+            "",
+            "hello world",
+            "re=-0.5",
+            "re=abc, im=0.1",
+            "-0.5, 0.1, 0",
+            "-0.5, 0.1, -3",
+            "1, 2, 3, 4",
+            "re=1, im=2, zoom=inf",
+            "re=1 2 im=3",
+        ] {
+            assert_eq!(parse_location(text), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_cursor_readout() {
+        let m = fractal::Mandelbrot::default();
+        let inside = cursor_readout(&m, -0.1, 0.0, 100);
+        assert_eq!(inside, "Iterations: 100 (inside)");
+
+        let outside = cursor_readout(&m, 1.0, 1.0, 100);
+        assert!(outside.contains("escaped"), "got {}", outside);
+        let count: u32 = outside
+            .trim_start_matches("Iterations: ")
+            .split(' ')
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(count < 100);
+    }
+
+    #[test]
+    fn test_cursor_readout_uses_rendered_iterations() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.render.adaptive_iterations = true;
+        let mut view = app.get_view();
+        view.zoom = 1e6;
+        app.set_view(view);
+        let adaptive = app.calculate_adaptive_iterations(1e6);
+        assert_ne!(adaptive, app.controls.max_iterations);
+
+        // The view center (-0.5, 0) is inside the set
+        let rect = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(100.0, 100.0));
+        app.update_mouse_position(rect.center(), &rect);
+        assert_eq!(
+            app.interaction.mouse_readout.as_deref(),
+            Some(format!("Iterations: {} (inside)", adaptive).as_str())
+        );
+    }
+
+    #[test]
+    fn test_render_gallery_writes_one_file_per_type() {
+        let registry = FractalRegistry::default();
+        let dir = std::env::temp_dir().join(format!("fractal_gallery_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let paths = render_gallery(&registry, &dir, 16, 12, 32, PaletteType::Classic).unwrap();
+        assert_eq!(paths.len(), registry.all_types().len());
+
+        let mut files: Vec<String> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        files.sort();
+        let mut expected: Vec<String> = registry
+            .all_types()
+            .into_iter()
+            .map(|ft| format!("{}.png", registry.metadata(ft).unwrap().id))
+            .collect();
+        expected.sort();
+        assert_eq!(files, expected);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_tiled_export_matches_in_memory() {
+        let registry = FractalRegistry::default();
+        let dir = std::env::temp_dir().join(format!("fractal_tiled_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let export = |name: &str, tiled: bool| {
+            ExportRequest {
+                fractal: registry.create(FractalType::Mandelbrot).unwrap(),
+                view: FractalViewState {
+                    center_x: -0.75,
+                    center_y: 0.1,
+                    zoom: 3.0,
+                    ..Default::default()
+                },
+                path: dir.join(name),
+                // Several row batches, and a width that isn't a multiple of 4
+                config: RenderConfig {
+                    palette_type: PaletteType::Fire,
+                    interior_color: egui::Color32::from_rgb(10, 20, 30),
+                    ..RenderConfig::new(203, 150, 64)
+                },
+                supersample_factor: 2,
+                transparent: false,
+                tiled,
+                bit_depth: ExportBitDepth::Eight,
+            }
+            .run(&ExportControl::none())
+            .unwrap()
+            .unwrap()
+        };
+        let memory = image::open(export("memory.png", false)).unwrap().to_rgb8();
+        let tiled = image::open(export("tiled.png", true)).unwrap().to_rgb8();
+        assert_eq!(tiled.dimensions(), (203, 150));
+        assert!(memory.as_raw() == tiled.as_raw(), "tiled pixels differ");
+
+        // Cancelling leaves no partial file behind
+        let cancel = AtomicBool::new(true);
+        let request = ExportRequest {
+            path: dir.join("cancelled.png"),
+            tiled: true,
+            ..ExportRequest {
+                fractal: registry.create(FractalType::Mandelbrot).unwrap(),
+                view: FractalViewState::default(),
+                path: PathBuf::new(),
+                config: RenderConfig::new(8, 8, 8),
+                supersample_factor: 1,
+                transparent: false,
+                tiled: false,
+                bit_depth: ExportBitDepth::Eight,
+            }
+        };
+        let control = ExportControl {
+            progress: &|_| {},
+            cancel: &cancel,
+        };
+        assert_eq!(request.run(&control), Ok(None));
+        assert!(!dir.join("cancelled.png").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_julia_seed_export() {
+        let dir = std::env::temp_dir().join(format!("fractal_seed_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let request = |name: &str| {
+            let fractal = FractalRegistry::default()
+                .create(FractalType::Julia)
+                .unwrap();
+            JuliaSeedRequest {
+                space: fractal.parameter_space_fractal().unwrap(),
+                fractal,
+                view: FractalViewState {
+                    zoom: 1.0,
+                    ..Default::default()
+                },
+                seed: (-0.7, 0.27),
+                path: dir.join(name),
+                config: RenderConfig::new(30, 20, 32),
+                supersample_factor: 1,
+            }
+        };
+
+        let last = std::sync::Mutex::new(0.0f32);
+        let progress = |p: f32| *last.lock().unwrap() = p;
+        let cancel = AtomicBool::new(false);
+        let control = ExportControl {
+            progress: &progress,
+            cancel: &cancel,
+        };
+        let path = request("poster.png").run(&control).unwrap().unwrap();
+        let poster = image::open(&path).unwrap();
+        assert_eq!(
+            (poster.width(), poster.height()),
+            (30 + 20, 20 + composite::CAPTION_HEIGHT)
+        );
+        assert!((*last.lock().unwrap() - 1.0).abs() < 1e-6);
+
+        cancel.store(true, Ordering::Relaxed);
+        assert_eq!(request("cancelled.png").run(&control), Ok(None));
+        assert!(!dir.join("cancelled.png").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_sixteen_bit_export() {
+        let dir = std::env::temp_dir().join(format!("fractal_16bit_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let path = ExportRequest {
+            fractal: FractalRegistry::default()
+                .create(FractalType::Mandelbrot)
+                .unwrap(),
+            view: FractalViewState {
+                center_x: -0.5,
+                zoom: 1.0,
+                ..Default::default()
+            },
+            path: dir.join("deep.png"),
+            config: RenderConfig::new(61, 40, 100),
+            supersample_factor: 2,
+            transparent: false,
+            tiled: false,
+            bit_depth: ExportBitDepth::Sixteen,
+        }
+        .run(&ExportControl::none())
+        .unwrap()
+        .unwrap();
+
+        let image = image::open(&path).unwrap();
+        let gray = image.as_luma16().expect("16-bit grayscale PNG");
+        assert_eq!(gray.dimensions(), (61, 40));
+        // Interior black; escaped points use levels 8-bit output can't hold
+        assert_eq!(gray.get_pixel(30, 20).0[0], 0);
+        assert!(gray.pixels().any(|p| p.0[0] > 0 && p.0[0] % 257 != 0));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_export_queue_runs_jobs_in_order() {
+        let registry = FractalRegistry::default();
+        let dir = std::env::temp_dir().join(format!("fractal_queue_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let paths: Vec<PathBuf> = (0..3).map(|i| dir.join(format!("job{}.png", i))).collect();
+        let mut queue = ExportQueue::default();
+        for (i, path) in paths.iter().enumerate() {
+            queue.push(ExportRequest {
+                fractal: registry.create(FractalType::Mandelbrot).unwrap(),
+                view: FractalViewState {
+                    center_x: -0.5,
+                    zoom: 1.0 + i as f64,
+                    ..Default::default()
+                },
+                path: path.clone(),
+                config: RenderConfig::new(16, 12, 32),
+                supersample_factor: 1,
+                transparent: i == 1,
+                tiled: i == 2,
+                bit_depth: ExportBitDepth::Eight,
+            });
+        }
+        // Only one job runs at a time
+        assert!(queue.running.is_some());
+        assert_eq!(queue.pending.len(), 2);
+
+        while queue.is_busy() {
+            queue.poll();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+
+        let finished: Vec<&PathBuf> = queue.finished.iter().map(|f| &f.path).collect();
+        assert_eq!(finished, paths.iter().collect::<Vec<_>>());
+        for (path, record) in paths.iter().zip(&queue.finished) {
+            assert!(path.exists(), "missing {}", path.display());
+            assert!(record.message.starts_with("Saved"), "{}", record.message);
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_parameter_map_coordinates() {
+        assert_eq!(parameter_map_to_seed(0.5, 0.5), (0.0, 0.0));
+        // Top-left corner is the most negative c_real, most positive c_imag
+        assert_eq!(parameter_map_to_seed(0.0, 0.0), (-2.0, 2.0));
+        let (u, v) = seed_to_parameter_map(-0.7, 0.27015);
+        let (c_re, c_im) = parameter_map_to_seed(u, v);
+        assert!((c_re + 0.7).abs() < 1e-12 && (c_im - 0.27015).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_wrap_palette_offset() {
+        assert_eq!(wrap_palette_offset(0.25), 0.25);
+        assert!((wrap_palette_offset(1.25) - 0.25).abs() < 1e-6);
+        assert!((wrap_palette_offset(-0.25) - 0.75).abs() < 1e-6);
+        assert_eq!(wrap_palette_offset(1.0), 0.0);
+        for offset in [-1e-9, -3.0, 7.5, f32::EPSILON - 1.0] {
+            let wrapped = wrap_palette_offset(offset);
+            assert!((0.0..1.0).contains(&wrapped), "{} -> {}", offset, wrapped);
+        }
+    }
+
+    #[test]
+    fn test_letterbox_square_on_wide_window() {
+        let (w, h) = letterbox_size(800.0, 400.0, 1.0);
+        assert_eq!((w, h), (400.0, 400.0));
+
+        let viewport = Viewport::from_view(-0.5, 0.0, 1.0, w as u32, h as u32);
+        let ((min_x, min_y), (max_x, max_y)) = viewport.visible_rect();
+        assert!(((max_x - min_x) - (max_y - min_y)).abs() < 1e-12);
+
+        // Tall windows get bars above and below instead
+        assert_eq!(letterbox_size(300.0, 600.0, 1.0), (300.0, 300.0));
+        assert_eq!(letterbox_size(800.0, 400.0, 2.0), (800.0, 400.0));
+    }
+
+    #[test]
+    fn test_canvas_render_size_native_dpi() {
+        assert_eq!(canvas_render_size(800.0, 600.0, 2.0, true), (1600, 1200));
+        assert_eq!(canvas_render_size(800.0, 600.0, 2.0, false), (800, 600));
+        assert_eq!(canvas_render_size(800.0, 600.0, 1.0, true), (800, 600));
+        assert_eq!(canvas_render_size(801.0, 600.0, 1.5, true), (1201, 900));
+    }
+
+    #[test]
+    fn test_select_render_quality() {
+        assert_eq!(select_render_quality(true, 10.0), RenderQuality::Draft);
+        assert_eq!(select_render_quality(false, 0.0), RenderQuality::Draft);
+        assert_eq!(
+            select_render_quality(false, QUALITY_IDLE_SECS * 0.5),
+            RenderQuality::Draft
+        );
+        assert_eq!(
+            select_render_quality(false, QUALITY_IDLE_SECS),
+            RenderQuality::Full
+        );
+        // Never interacted
+        assert_eq!(
+            select_render_quality(false, f64::INFINITY),
+            RenderQuality::Full
+        );
+    }
+
+    #[test]
+    fn test_palette_offset_undo_redo() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.controls.palette_type = PaletteType::Psychedelic;
+        app.controls.palette_offset = 0.4;
+        app.execute_palette_offset_command(0.0);
+        assert_eq!(app.controls.palette_offset, 0.4);
+
+        app.undo();
+        assert_eq!(app.controls.palette_offset, 0.0);
+        assert_eq!(app.controls.pending_palette_offset, 0.0);
+        assert_eq!(app.controls.palette_type, PaletteType::Psychedelic);
+
+        app.redo();
+        assert_eq!(app.controls.palette_offset, 0.4);
+
+        // An unchanged offset records nothing
+        app.execute_palette_offset_command(0.4);
+        app.undo();
+        assert_eq!(app.controls.palette_offset, 0.0);
+    }
+
+    #[test]
+    fn test_palette_scroll_gesture_is_one_undo_step() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.controls.palette_type = PaletteType::Psychedelic;
+        let start = Instant::now();
+        let at = |secs: f64| start + std::time::Duration::from_secs_f64(secs);
+        for step in 0..3 {
+            app.scroll_palette_offset(0.1, at(step as f64 * 0.05));
+        }
+        assert!(app.finish_palette_scroll(at(0.2)));
+        assert!(!app.finish_palette_scroll(at(1.0)));
+        assert!((app.controls.palette_offset - 0.3).abs() < 1e-6);
+        assert_eq!(app.get_command_history().len(), 1);
+
+        app.undo();
+        assert_eq!(app.controls.palette_offset, 0.0);
+
+        // Other palettes take part when the offset colors them
+        use color_pipeline::ColorProcessorType;
+        let dual = ColorProcessorType::DualPalette {
+            first: PaletteType::Fire,
+            second: PaletteType::Psychedelic,
+        };
+        assert!(dual.uses_palette_offset(PaletteType::Classic));
+        assert!(ColorProcessorType::NewtonBasin.uses_palette_offset(PaletteType::Ice));
+        assert!(!ColorProcessorType::Smooth.uses_palette_offset(PaletteType::Ice));
+    }
+
+    #[test]
+    fn test_palette_offset_undo_keeps_fractal_params() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.controls.fractal_type = FractalType::Julia;
+        app.activate_fractal();
+        app.set_seed(0.25, -0.4);
+
+        app.controls.palette_offset = 0.3;
+        app.execute_palette_offset_command(0.0);
+        app.undo();
+        assert_eq!(app.controls.palette_offset, 0.0);
+        let (re_name, im_name) = fractal::SEED_PARAMETERS;
+        assert_eq!(app.fractal.get_parameter(re_name), Some(0.25));
+        assert_eq!(app.fractal.get_parameter(im_name), Some(-0.4));
+    }
+
+    #[test]
+    fn test_zoom_clamped_to_limits() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        for _ in 0..100 {
+            app.zoom_view(1.0 / ZOOM_KEYBOARD_FACTOR);
+            assert!(app.get_view().zoom >= DEFAULT_MIN_ZOOM);
+        }
+        assert_eq!(app.get_view().zoom, DEFAULT_MIN_ZOOM);
+
+        // Clamped cursor zoom leaves the view unchanged rather than drifting
+        let center = (app.get_view().center_x, app.get_view().center_y);
+        app.zoom_at_point(0.5, 10, 10, 100, 100);
+        assert_eq!(app.get_view().zoom, DEFAULT_MIN_ZOOM);
+        assert_eq!((app.get_view().center_x, app.get_view().center_y), center);
+
+        for _ in 0..200 {
+            app.zoom_at_point(2.0, 50, 50, 100, 100);
+        }
+        assert_eq!(app.get_view().zoom, MAX_ZOOM);
+
+        // Out-of-range configured limits are reset
+        let (config, warnings) = AppConfig::from_json(r#"{"min_zoom": 0.0}"#);
+        assert_eq!(config.min_zoom, DEFAULT_MIN_ZOOM);
+        assert!(!warnings.is_empty());
+    }
+
+    #[test]
+    fn test_parameter_render_quality() {
+        assert_eq!(parameter_render_quality(false, false), None);
+        assert_eq!(
+            parameter_render_quality(true, false),
+            Some(RenderQuality::Draft)
+        );
+        // Release (or any other committed change) always renders in full
+        assert_eq!(
+            parameter_render_quality(false, true),
+            Some(RenderQuality::Full)
+        );
+        assert_eq!(
+            parameter_render_quality(true, true),
+            Some(RenderQuality::Full)
+        );
+    }
+
+    #[test]
+    fn test_view_lock_blocks_navigation() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.view_locked = true;
+        let before = app.get_view();
+        app.pan_view(1.0, -1.0);
+        app.zoom_view(ZOOM_KEYBOARD_FACTOR);
+        app.zoom_at_point(2.0, 10, 10, 100, 100);
+        let after = app.get_view();
+        assert_eq!(
+            (after.center_x, after.center_y, after.zoom),
+            (before.center_x, before.center_y, before.zoom)
+        );
+        assert!(!app.get_command_history().can_undo());
+
+        app.bookmarks.push(test_bookmark());
+        app.load_bookmark(0, true);
+        app.go_to_pasted_location("0.25, 0.5");
+        let after = app.get_view();
+        assert_eq!(
+            (after.center_x, after.center_y, after.zoom),
+            (before.center_x, before.center_y, before.zoom)
+        );
+        assert!(!app.get_command_history().can_undo());
+
+        app.view_locked = false;
+        app.pan_view(1.0, 0.0);
+        assert_ne!(app.get_view().center_x, before.center_x);
+    }
+
+    #[test]
+    fn test_replay_script_repairs_views_per_fractal() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        assert_eq!(app.controls.fractal_type, FractalType::Mandelbrot);
+        let julia_view = FractalViewState {
+            zoom: 0.0,
+            max_iterations: 100_000,
+            ..app.views[&FractalType::Julia].clone()
+        };
+        let script = vec![
+            CommandDescriptor::View {
+                old_center_x: -0.5,
+                old_center_y: 0.0,
+                old_zoom: 1.0,
+                new_center_x: -0.75,
+                new_center_y: 0.1,
+                new_zoom: -4.0,
+            },
+            CommandDescriptor::FractalType {
+                old_type: FractalType::Mandelbrot,
+                new_type: FractalType::Julia,
+                old_view: Box::new(app.get_view()),
+                new_view: Box::new(julia_view),
+            },
+            CommandDescriptor::View {
+                old_center_x: 0.0,
+                old_center_y: 0.0,
+                old_zoom: 1.0,
+                new_center_x: 0.2,
+                new_center_y: 0.0,
+                new_zoom: 2.0,
+            },
+        ];
+        app.replay_script(&script);
+
+        assert_eq!(app.controls.fractal_type, FractalType::Julia);
+        let view = app.get_view();
+        assert_eq!((view.center_x, view.zoom), (0.2, 2.0));
+        assert_eq!(view.max_iterations, MAX_ITERATIONS_CAP);
+        // The switch is recorded with the fractal it left
+        assert_eq!(app.command_histories[&FractalType::Mandelbrot].len(), 2);
+        assert_eq!(app.command_histories[&FractalType::Julia].len(), 1);
+        let mut state = app.to_app_state();
+        app.command_histories
+            .get_mut(&FractalType::Mandelbrot)
+            .unwrap()
+            .undo(&mut state);
+        assert_eq!(state.fractal_type, FractalType::Mandelbrot);
+        assert_eq!(state.view.zoom, 1.0);
+        assert!(app
+            .log
+            .iter()
+            .any(|(_, entry)| entry.contains("invalid values reset")));
+    }
+
+    #[test]
+    fn test_pasted_and_bookmark_zoom_clamped() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.go_to_pasted_location(&format!("0.25, 0.5, {:e}", MAX_ZOOM * 10.0));
+        assert_eq!(app.get_view().zoom, MAX_ZOOM);
+
+        let mut bookmark = test_bookmark();
+        bookmark.zoom = app.min_zoom / 10.0;
+        app.bookmarks.push(bookmark);
+        app.load_bookmark(0, true);
+        assert_eq!(app.get_view().zoom, app.min_zoom);
+    }
+
+    #[test]
+    fn test_exact_zoom_factor() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        let before = app.get_view();
+        app.zoom_view(ZOOM_KEY_TEN_FACTOR);
+        let after = app.get_view();
+        assert_eq!(after.zoom, before.zoom * 10.0);
+        assert_eq!(
+            (after.center_x, after.center_y),
+            (before.center_x, before.center_y)
+        );
+        assert!(app.get_command_history().can_undo());
+
+        app.zoom_view(1.0 / ZOOM_KEY_TEN_FACTOR);
+        assert_eq!(app.get_view().zoom, before.zoom);
+    }
+
+    #[test]
+    fn test_snap_zoom_to_power_of_two() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.snap_zoom = true;
+        app.zoom_view(1.3);
+        assert_eq!(app.get_view().zoom, 2.0);
+        app.zoom_at_point(1.3, 10, 10, 100, 100);
+        assert_eq!(app.get_view().zoom, 4.0);
+        app.zoom_view(1.0 / 1.3);
+        assert_eq!(app.get_view().zoom, 2.0);
+
+        // Off-grid zooms snap to the nearest power in the zoom's direction
+        assert_eq!(snap_zoom_to_power_of_two(1.5, 1.5 * 1.3), 2.0);
+        assert_eq!(snap_zoom_to_power_of_two(3.0, 3.0 * 10.0), 32.0);
+        assert_eq!(snap_zoom_to_power_of_two(3.0, 3.0 / 1.3), 2.0);
+        assert_eq!(snap_zoom_to_power_of_two(1.0, 0.7), 0.5);
+
+        app.snap_zoom = false;
+        app.zoom_view(1.3);
+        assert_eq!(app.get_view().zoom, 2.0 * 1.3);
+    }
+
+    #[test]
+    fn test_snap_zoom_accumulates_smooth_scroll() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.snap_zoom = true;
+        // One notch arriving as many small deltas is a single step
+        for _ in 0..8 {
+            app.scroll_zoom(SNAP_SCROLL_STEP / 8.0, 50, 50, 100, 100);
+        }
+        assert_eq!(app.get_view().zoom, 2.0);
+        app.scroll_zoom(SNAP_SCROLL_STEP / 2.0, 50, 50, 100, 100);
+        assert_eq!(app.get_view().zoom, 2.0);
+        // Reversing drops the partial scroll in the old direction
+        app.scroll_zoom(-SNAP_SCROLL_STEP * 0.75, 50, 50, 100, 100);
+        assert_eq!(app.get_view().zoom, 2.0);
+        app.scroll_zoom(-SNAP_SCROLL_STEP * 0.25, 50, 50, 100, 100);
+        assert_eq!(app.get_view().zoom, 1.0);
+        // A fast flick covering several notches steps once per notch
+        app.scroll_zoom(SNAP_SCROLL_STEP * 3.0, 50, 50, 100, 100);
+        assert_eq!(app.get_view().zoom, 8.0);
+    }
+
+    #[test]
+    fn test_lock_animation_colors() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.add_keyframe();
+        app.zoom_view(8.0);
+        app.add_keyframe();
+        app.animation_frame_count = 5;
+
+        let frames = app.animation_frames().unwrap();
+        assert_eq!(frames.len(), 5);
+
+        let mut fractal = app.create_fractal(app.controls.fractal_type);
+        let locked = animation_color_range(fractal.as_mut(), &frames, 1.5)
+            .expect("first frame has escaping points");
+        assert!(locked.0 < locked.1);
+        // Measured on the most zoomed-out frame
+        let widest = &frames[0];
+        let probe = renderer::escape_iteration_range(
+            app.fractal.as_ref(),
+            widest,
+            COLOR_LOCK_PROBE_WIDTH,
+            (COLOR_LOCK_PROBE_WIDTH as f64 / 1.5).round() as u32,
+            widest.max_iterations,
+        );
+        assert_eq!(probe, Some(locked));
+    }
+
+    #[test]
+    fn test_animation_export_job() {
+        let dir = std::env::temp_dir().join(format!("fractal_animation_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.add_keyframe();
+        app.zoom_view(8.0);
+        app.add_keyframe();
+        app.animation_frame_count = 3;
+        let request = |color_range, lock_colors| AnimationRequest {
+            fractal: app.create_fractal(app.controls.fractal_type),
+            views: app.animation_frames().unwrap(),
+            dir: dir.clone(),
+            config: RenderConfig::new(12, 8, 32),
+            trap_image: None,
+            custom_palette: None,
+            color_range,
+            lock_colors,
+            supersample_factor: 1,
+        };
+
+        let last = std::sync::Mutex::new(0.0f32);
+        let progress = |p: f32| *last.lock().unwrap() = p;
+        let cancel = AtomicBool::new(false);
+        let control = ExportControl {
+            progress: &progress,
+            cancel: &cancel,
+        };
+        let frame = |i: usize| image::open(dir.join(format!("frame_{:04}.png", i))).unwrap();
+        assert_eq!(request(None, false).run(&control), Ok(Some(dir.clone())));
+        assert!((*last.lock().unwrap() - 1.0).abs() < 1e-6);
+        assert_eq!(frame(2).width(), 12);
+        let unlocked = frame(0).to_rgb8();
+
+        // Locked colors are probed in the job and change the first frame
+        request(None, true).run(&control).unwrap();
+        assert_ne!(frame(0).to_rgb8(), unlocked);
+
+        // A pinned range wins over the lock
+        let max_iter = app.animation_frames().unwrap()[0].max_iterations;
+        request(Some((0, max_iter)), true).run(&control).unwrap();
+        assert_eq!(frame(0).to_rgb8(), unlocked);
+
+        cancel.store(true, Ordering::Relaxed);
+        assert_eq!(request(None, false).run(&control), Ok(None));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_center_orbit_csv() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        let mut view = app.get_view();
+        (view.center_x, view.center_y) = (0.5, 0.5);
+        app.set_view(view);
+
+        let csv = app.center_orbit_csv().unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("n,re,im"));
+        let rows: Vec<(usize, f64, f64)> = lines
+            .map(|line| {
+                let fields: Vec<&str> = line.split(',').collect();
+                (
+                    fields[0].parse().unwrap(),
+                    fields[1].parse().unwrap(),
+                    fields[2].parse().unwrap(),
+                )
+            })
+            .collect();
+        assert!(rows.windows(2).all(|w| w[1].0 == w[0].0 + 1));
+        assert_eq!(rows[0], (0, 0.0, 0.0));
+        // z1 = 0^2 + c = c
+        assert_eq!(rows[1], (1, 0.5, 0.5));
+        // An escaping orbit ends on its first point past the bailout
+        let escape = app.fractal.compute(0.5, 0.5, app.controls.max_iterations);
+        assert_eq!(rows.len() as u32, escape + 1);
+        let (_, re, im) = rows[rows.len() - 1];
+        assert!(re * re + im * im > 4.0);
+    }
+
+    #[test]
+    fn test_center_orbit_csv_uses_hp_center() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        let mut view = app.get_view();
+        (view.center_x, view.center_y) = (-2.0, 0.0);
+        app.set_view(view.clone());
+        // At -2 exactly the orbit never escapes
+        let max_iter = app.controls.max_iterations as usize;
+        assert_eq!(
+            app.center_orbit_csv().unwrap().lines().count(),
+            max_iter + 2
+        );
+
+        // Just past -2, beyond f64 precision, it does
+        view.hp_center = Some(HpCenter::parse("-2.0000000000000000000000001", "0").unwrap());
+        app.set_view(view);
+        assert_eq!(app.get_view().center_x, -2.0);
+        let rows = app.center_orbit_csv().unwrap().lines().count();
+        assert!(rows < max_iter, "{} rows", rows);
+
+        // Fractals iterating only in f64 refuse rather than round the center
+        app.fractal = app.create_fractal(FractalType::Spider);
+        assert!(app.center_orbit_csv().is_err());
+    }
+
+    #[test]
+    fn test_fit_selection_contains_selected_rect() {
+        let (width, height) = (400u32, 300u32);
+        let old = Viewport::from_view(-0.5, 0.0, 1.0, width, height);
+        for (min, max) in [
+            (egui::pos2(50.0, 100.0), egui::pos2(350.0, 140.0)),
+            (egui::pos2(180.0, 20.0), egui::pos2(220.0, 280.0)),
+        ] {
+            let (fit_min, fit_max) = fit_selection_to_canvas(min, max, width as f32, height as f32);
+            let aspect = (fit_max.x - fit_min.x) / (fit_max.y - fit_min.y);
+            assert!((aspect - width as f32 / height as f32).abs() < 1e-4);
+
+            let tl = old.screen_to_world(min.x as u32, min.y as u32, width, height);
+            let br = old.screen_to_world(max.x as u32, max.y as u32, width, height);
+            let zoom = height as f64 / (fit_max.y - fit_min.y) as f64;
+            let new = Viewport::from_view(
+                (tl.re + br.re) / 2.0,
+                (tl.im + br.im) / 2.0,
+                zoom,
+                width,
+                height,
+            );
+            let a = new.screen_to_world(0, 0, width, height);
+            let b = new.screen_to_world(width, height, width, height);
+            let eps = 1e-9;
+            for corner in [tl, br] {
+                assert!(corner.re >= a.re.min(b.re) - eps && corner.re <= a.re.max(b.re) + eps);
+                assert!(corner.im >= a.im.min(b.im) - eps && corner.im <= a.im.max(b.im) + eps);
+            }
+        }
+    }
+
+    #[test]
+    fn test_log_evicts_oldest_past_capacity() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        for i in 0..LOG_CAPACITY + 5 {
+            app.push_log(format!("event {}", i));
+        }
+        assert_eq!(app.log.len(), LOG_CAPACITY);
+        assert_eq!(app.log.front().unwrap().1, "event 5");
+        assert_eq!(
+            app.log.back().unwrap().1,
+            format!("event {}", LOG_CAPACITY + 4)
+        );
+        assert_eq!(
+            app.interaction.status_message.as_ref().unwrap().0,
+            format!("event {}", LOG_CAPACITY + 4)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_view_to_other_fractal() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.zoom_view(ZOOM_KEY_TEN_FACTOR);
+        app.pan_view(1.0, 0.5);
+        let source = app.get_view();
+        let julia_params = app.views[&FractalType::Julia].fractal_params.clone();
+
+        app.duplicate_view_to(FractalType::Julia);
+        assert_eq!(app.controls.fractal_type, FractalType::Julia);
+        let target = app.get_view();
+        assert_eq!(
+            (
+                target.center_x,
+                target.center_y,
+                target.zoom,
+                target.max_iterations
+            ),
+            (
+                source.center_x,
+                source.center_y,
+                source.zoom,
+                source.max_iterations
+            )
+        );
+        assert_eq!(target.fractal_params, julia_params);
+        assert_eq!(app.controls.max_iterations, source.max_iterations);
+    }
+
+    #[test]
+    fn test_resize_debounce_waits_for_stable_size() {
+        let rendered = (800, 600);
+        let mut debounce = ResizeDebounce {
+            size: rendered,
+            stable_frames: 0,
+        };
+        assert!(!debounce.update(rendered, rendered));
+        assert!(!debounce.pending(rendered));
+
+        // A size that changes every frame never renders
+        for width in 801..820 {
+            assert!(!debounce.update((width, 600), rendered));
+            assert!(debounce.pending(rendered));
+        }
+
+        // Once it holds still, exactly one render after the settle period
+        let settled: Vec<bool> = (0..RESIZE_SETTLE_FRAMES + 3)
+            .map(|_| debounce.update((819, 600), rendered))
+            .collect();
+        assert_eq!(settled.iter().filter(|&&fire| fire).count(), 1);
+        assert!(settled[RESIZE_SETTLE_FRAMES as usize - 1]);
+        assert!(!debounce.pending(rendered));
+
+        // Resizing back to the rendered size needs no render
+        let mut debounce = ResizeDebounce::default();
+        for _ in 0..RESIZE_SETTLE_FRAMES + 2 {
+            assert!(!debounce.update(rendered, rendered));
+        }
+    }
+
+    #[test]
+    fn test_render_budget_lowers_iteration_cap() {
+        // A render 4x over budget cuts the cap to a quarter
+        assert_eq!(budget_iteration_cap(1000, 2.0, 0.5), 250);
+        // Fast renders allow the full cap; the floor bounds slow ones
+        assert_eq!(budget_iteration_cap(1000, 0.01, 0.5), MAX_ITERATIONS_CAP);
+        assert_eq!(
+            budget_iteration_cap(1000, 1000.0, 0.5),
+            MIN_BUDGET_ITERATIONS
+        );
+        assert_eq!(budget_iteration_cap(1000, 0.0, 0.5), MAX_ITERATIONS_CAP);
+
+        let mut app = FractalApp::new(&AppConfig::default());
+        let deep_zoom = 1e9;
+        let unbudgeted = app.calculate_adaptive_iterations(deep_zoom);
+        app.render.render_budget = true;
+        app.render.last_render_time = Some(RENDER_BUDGET_SECS * 4.0);
+        app.update_iteration_budget(1000);
+        assert_eq!(app.render.iteration_cap, 250);
+        assert!(app.calculate_adaptive_iterations(deep_zoom) < unbudgeted);
+        assert_eq!(app.calculate_adaptive_iterations(deep_zoom), 250);
+    }
+
+    #[test]
+    fn test_check_export_precision() {
+        let mut view = FractalViewState {
+            center_x: -0.743643887037151,
+            center_y: 0.13182590420533,
+            zoom: 1e3,
+            ..FractalViewState::default()
+        };
+        assert!(check_export_precision(&view, 3200, 2400, DEFAULT_MIN_FEATURE_ULPS).is_ok());
+
+        // At the zoom limit a 4x export puts pixels below one ulp apart
+        view.zoom = MAX_ZOOM;
+        let err = check_export_precision(&view, 3200, 2400, DEFAULT_MIN_FEATURE_ULPS).unwrap_err();
+        assert!(err.contains("perturbation"));
+        assert!(check_export_precision(&view, 3200, 2400, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_export_size_guard() {
+        assert_eq!(
+            scaled_export_size(800, 600, 4, DEFAULT_EXPORT_MEGAPIXEL_CAP),
+            Ok((3200, 2400))
+        );
+        let err =
+            scaled_export_size(100_000, 100_000, 1, DEFAULT_EXPORT_MEGAPIXEL_CAP).unwrap_err();
+        assert!(err.contains("100000x100000"), "{}", err);
+        assert!(err.contains("megapixel limit"), "{}", err);
+        // Sizes past u32 are refused rather than wrapping
+        assert!(scaled_export_size(u32::MAX, 1, 4, MAX_EXPORT_MEGAPIXEL_CAP).is_err());
+
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.render.cached_image = Some(egui::ColorImage::new([800, 600], egui::Color32::RED));
+        app.export_megapixel_cap = MIN_EXPORT_MEGAPIXEL_CAP;
+        let err = app.save_image(4).unwrap_err();
+        assert!(err.contains("3200x2400"), "{}", err);
+        assert!(app.export_queue.running.is_none());
+
+        // Tiled exports stream to disk, so the memory cap doesn't apply
+        app.tiled_export = true;
+        assert_eq!(app.export_size(32), Ok((25600, 19200)));
+        app.transparent_interior = true;
+        assert!(app.export_size(32).is_err());
+    }
+
+    #[test]
+    fn test_minimap_full_coloring() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        let mut view = app.get_view();
+        view.color_processor_type = color_pipeline::ColorProcessorType::OrbitTrapOrigin;
+        app.set_view(view);
+        app.controls.palette_offset = 0.3;
+        app.minimap_full_coloring = true;
+
+        let size = MINIMAP_SIZE as u32;
+        let pixels = app.minimap_pixels();
+        assert_eq!(pixels.len(), MINIMAP_SIZE * MINIMAP_SIZE);
+
+        // A point outside the set, colored by the orbit-trap processor
+        let (x, y) = (size - 10, size / 3);
+        let world = app.minimap_viewport().screen_to_world(x, y, size, size);
+        let result = app.color_pipeline().compute(
+            app.fractal.as_ref(),
+            world.re,
+            world.im,
+            MINIMAP_MAX_ITER,
+        );
+        assert!(result.escaped);
+        let context = color_pipeline::ColorContext::new(
+            MINIMAP_MAX_ITER,
+            PaletteType::Classic,
+            0.3,
+            size,
+            size,
+        )
+        .with_world_per_pixel(MINIMAP_MAP_RANGE / size as f64);
+        let expected = app.color_pipeline().process(&result, &context);
+        assert_eq!(pixels[(y * size + x) as usize], expected);
+
+        app.minimap_full_coloring = false;
+        assert_ne!(app.minimap_pixels(), pixels);
+    }
+
+    #[test]
+    fn test_toggle_previous_fractal() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.toggle_previous_fractal();
+        assert_eq!(app.controls.fractal_type, FractalType::Mandelbrot);
+
+        // Pick Julia from the dropdown and move its view
+        app.controls.fractal_type = FractalType::Julia;
+        app.remember_fractal_type(FractalType::Mandelbrot);
+        app.activate_fractal();
+        let mut julia_view = app.get_view();
+        julia_view.center_x = 0.3;
+        app.set_view(julia_view);
+
+        app.toggle_previous_fractal();
+        assert_eq!(app.controls.fractal_type, FractalType::Mandelbrot);
+        assert_eq!(app.fractal.name(), "Mandelbrot");
+        app.toggle_previous_fractal();
+        assert_eq!(app.controls.fractal_type, FractalType::Julia);
+        assert_eq!(app.fractal.name(), "Julia");
+        assert_eq!(app.get_view().center_x, 0.3);
+
+        // Each switch is recorded in the history of the fractal it left
+        let last_switch = |app: &mut FractalApp, fractal_type| match app
+            .command_histories
+            .get(&fractal_type)?
+            .export_script()
+            .pop()?
+        {
+            CommandDescriptor::FractalType {
+                old_type, new_type, ..
+            } => Some((old_type, new_type)),
+            _ => None,
+        };
+        assert_eq!(
+            last_switch(&mut app, FractalType::Julia),
+            Some((FractalType::Julia, FractalType::Mandelbrot))
+        );
+        assert_eq!(
+            last_switch(&mut app, FractalType::Mandelbrot),
+            Some((FractalType::Mandelbrot, FractalType::Julia))
+        );
+
+        // Undoing from the left fractal's history restores its view
+        app.toggle_previous_fractal();
+        app.undo();
+        assert_eq!(app.controls.fractal_type, FractalType::Mandelbrot);
+        assert_eq!(app.get_view(), app.views[&FractalType::Mandelbrot]);
+        app.redo();
+        assert_eq!(app.controls.fractal_type, FractalType::Julia);
+    }
+
+    #[test]
+    fn test_seek_history_restores_fractal_params() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.controls.fractal_type = FractalType::Julia;
+        app.activate_fractal();
+        let (re_name, _) = fractal::SEED_PARAMETERS;
+        let original = app.fractal.get_parameter(re_name).unwrap();
+
+        let mut state = app.to_app_state();
+        let command = command::ParameterCommand::new(re_name.to_string(), original, -0.8);
+        app.get_command_history()
+            .execute(Box::new(command), &mut state);
+        app.apply_app_state(&state);
+        app.restore_fractal(&state);
+        app.zoom_view(2.0);
+        assert_eq!(app.fractal.get_parameter(re_name), Some(-0.8));
+
+        app.seek_history(0);
+        assert_eq!(app.fractal.get_parameter(re_name), Some(original));
+        assert_eq!(app.controls.pending_fractal_params[re_name], original);
+        app.seek_history(2);
+        assert_eq!(app.fractal.get_parameter(re_name), Some(-0.8));
+        app.undo();
+        assert_eq!(app.fractal.get_parameter(re_name), Some(-0.8));
+        app.undo();
+        assert_eq!(app.fractal.get_parameter(re_name), Some(original));
+        app.redo();
+        assert_eq!(app.fractal.get_parameter(re_name), Some(-0.8));
+    }
+
+    #[test]
+    fn test_history_size_setting() {
+        let config = AppConfig {
+            history_size: 2,
+            ..AppConfig::default()
+        };
+        let mut app = FractalApp::new(&config);
+        for factor in [2.0, 2.0, 2.0] {
+            app.zoom_view(factor);
+        }
+        assert_eq!(app.get_command_history().len(), 2);
+
+        app.set_history_size(1);
+        assert_eq!(app.get_command_history().len(), 1);
+        app.set_history_size(0);
+        assert_eq!(app.history_size, MIN_HISTORY_SIZE);
+
+        let (config, warnings) = AppConfig::from_json(r#"{"history_size": 0}"#);
+        assert_eq!(config.history_size, MIN_HISTORY_SIZE);
+        assert!(!warnings.is_empty());
+    }
+
+    #[test]
+    fn test_auto_render_off_defers_renders() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.render.cached_image = Some(egui::ColorImage::new([4, 4], egui::Color32::BLACK));
+        app.render.needs_render = false;
+
+        app.render.auto_render = false;
+        app.fractal.set_parameter("power", 3.0);
+        app.invalidate_cache();
+        assert!(app.render.render_pending);
+        assert!(!app.render.needs_render);
+        // Nothing else starts the render
+        app.invalidate_cache();
+        assert!(!app.render.needs_render);
+
+        app.render_now();
+        assert!(app.render.needs_render);
+        assert!(!app.render.render_pending);
+
+        // Auto-render renders straight away
+        app.render.needs_render = false;
+        app.render.auto_render = true;
+        app.invalidate_cache();
+        assert!(app.render.needs_render);
+        assert!(!app.render.render_pending);
+    }
+
+    #[test]
+    fn test_contrasting_overlay() {
+        let mut image = egui::ColorImage::new([100, 60], egui::Color32::from_rgb(10, 10, 40));
+        // Light right half
+        for y in 0..60 {
+            for x in 50..100 {
+                image.pixels[y * 100 + x] = egui::Color32::from_rgb(240, 240, 200);
+            }
+        }
+        let dark = mean_luma(&image, &ColorLut::default(), [0, 0], [50, 60]).unwrap();
+        let light = mean_luma(&image, &ColorLut::default(), [50, 0], [100, 60]).unwrap();
+        assert_eq!(contrasting_overlay(dark), egui::Color32::WHITE);
+        assert_eq!(contrasting_overlay(light), egui::Color32::BLACK);
+        // Boxes past the edge are clipped; empty boxes have no mean
+        let clipped = mean_luma(&image, &ColorLut::default(), [60, 10], [500, 500]).unwrap();
+        assert!((clipped - light).abs() < 1e-3);
+        assert_eq!(
+            mean_luma(&image, &ColorLut::default(), [20, 20], [20, 40]),
+            None
+        );
+        // Measured on the colors as shown
+        let inverted = ColorLut::new(&ColorAdjustment::default(), true);
+        let shown = mean_luma(&image, &inverted, [0, 0], [50, 60]).unwrap();
+        assert_eq!(contrasting_overlay(shown), egui::Color32::BLACK);
+
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.render.cached_image = Some(image);
+        let canvas = egui::Rect::from_min_size(egui::pos2(10.0, 10.0), egui::vec2(200.0, 120.0));
+        let left = egui::Rect::from_min_max(egui::pos2(20.0, 20.0), egui::pos2(90.0, 100.0));
+        let right = egui::Rect::from_min_max(egui::pos2(130.0, 20.0), egui::pos2(200.0, 100.0));
+        assert_eq!(
+            app.selection_stroke_color(left, canvas),
+            app.selection_color
+        );
+        app.selection_auto_contrast = true;
+        assert_eq!(
+            app.selection_stroke_color(left, canvas),
+            egui::Color32::WHITE
+        );
+        assert_eq!(
+            app.selection_stroke_color(right, canvas),
+            egui::Color32::BLACK
+        );
+    }
+
+    #[test]
+    fn test_reset_coloring_keeps_view() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        let mut view = app.get_view();
+        view.center_x = -0.7435;
+        view.center_y = 0.1314;
+        view.zoom = 350.0;
+        view.fractal_params = HashMap::from([("power".to_string(), 3.0)]);
+        view.palette_type = PaletteType::Fire;
+        view.color_processor_type = color_pipeline::ColorProcessorType::OrbitTrapOrigin;
+        view.processor_params = HashMap::from([("trap_radius".to_string(), 0.4)]);
+        app.set_view(view.clone());
+        app.controls.palette_type = PaletteType::Fire;
+        app.controls.color_processor_type = color_pipeline::ColorProcessorType::OrbitTrapOrigin;
+        app.controls.palette_offset = 0.6;
+        app.controls.pending_palette_offset = 0.6;
+
+        app.reset_coloring();
+        let reset = app.get_view();
+        assert_eq!(reset.center_x, view.center_x);
+        assert_eq!(reset.center_y, view.center_y);
+        assert_eq!(reset.zoom, view.zoom);
+        assert_eq!(reset.fractal_params, view.fractal_params);
+        assert_eq!(reset.palette_type, PaletteType::Classic);
+        assert_eq!(
+            reset.color_processor_type,
+            color_pipeline::ColorProcessorType::default()
+        );
+        assert!(reset.processor_params.is_empty());
+        assert_eq!(app.controls.palette_type, PaletteType::Classic);
+        assert_eq!(app.controls.palette_offset, 0.0);
+        assert_eq!(app.controls.pending_palette_offset, 0.0);
+    }
+
+    #[test]
+    fn test_converged_iterations() {
+        let black = egui::Color32::BLACK;
+        let red = egui::Color32::RED;
+        // 100-pixel probes: 40, then 10, then 1, then 0 pixels change
+        // between successive levels
+        let probe = |escaped: usize| {
+            let mut pixels = vec![black; 100];
+            pixels[..escaped].fill(red);
+            pixels
+        };
+        let probes = vec![
+            (16, probe(10)),
+            (24, probe(50)),
+            (36, probe(60)),
+            (54, probe(61)),
+            (81, probe(61)),
+        ];
+        assert_eq!(
+            converged_iterations(probes.clone().into_iter(), 0.05),
+            Some(36)
+        );
+        assert_eq!(
+            converged_iterations(probes.clone().into_iter(), 0.005),
+            Some(54)
+        );
+        // Later probes are not consumed once converged
+        let mut rendered = 0;
+        let counted = probes.iter().cloned().inspect(|_| rendered += 1);
+        assert_eq!(converged_iterations(counted, 0.05), Some(36));
+        assert_eq!(rendered, 4);
+        // Never settling suggests the highest level tried
+        assert_eq!(
+            converged_iterations(probes.into_iter().take(3), 0.05),
+            Some(36)
+        );
+        assert_eq!(converged_iterations(std::iter::empty(), 0.05), None);
+
+        let ladder = iteration_ladder(MIN_ITERATIONS, MAX_ITERATIONS_CAP);
+        assert_eq!(ladder.first(), Some(&MIN_ITERATIONS));
+        assert_eq!(ladder.last(), Some(&MAX_ITERATIONS_CAP));
+        assert!(ladder.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_lemniscate_circle() {
+        let mandelbrot = FractalRegistry::default()
+            .create(FractalType::Mandelbrot)
+            .unwrap();
+        let viewport = Viewport::from_view(0.0, 0.0, 1.0, 240, 200);
+        let levels = lemniscate_contours(mandelbrot.as_ref(), &viewport, 240, 200, 3);
+        assert_eq!(levels.len(), 3);
+
+        // n = 1: z_1 = c, so the lemniscate is the circle |c| = 2, traced to
+        // within one sample spacing
+        let spacing = (viewport.screen_to_world(LEMNISCATE_CELL_PX, 0, 240, 200)
+            - viewport.screen_to_world(0, 0, 240, 200))
+        .norm();
+        assert!(spacing < 0.1);
+        assert_eq!(levels[0].len(), 1);
+        let circle = &levels[0][0];
+        assert!(circle.len() > 16);
+        for &(re, im) in circle {
+            assert!(
+                (re.hypot(im) - 2.0).abs() <= spacing,
+                "({}, {}) is off the circle",
+                re,
+                im
+            );
+        }
+        // It goes all the way around
+        assert!(circle.iter().any(|&(re, _)| re > 1.9));
+        assert!(circle.iter().any(|&(re, _)| re < -1.9));
+        assert!(circle.iter().any(|&(_, im)| im > 1.9));
+        assert!(circle.iter().any(|&(_, im)| im < -1.9));
+
+        // n = 2 (|c^2 + c| = 2) is no longer a circle
+        let radii: Vec<f64> = levels[1][0].iter().map(|&(re, im)| re.hypot(im)).collect();
+        let spread = radii.iter().cloned().fold(f64::MIN, f64::max)
+            - radii.iter().cloned().fold(f64::MAX, f64::min);
+        assert!(spread > 0.5);
+    }
+
+    #[test]
+    fn test_zoom_render_delay() {
+        let mut app = FractalApp::new(&AppConfig::default());
+        assert_eq!(app.render.zoom_render_delay, RENDER_DELAY_FRAMES);
+        app.render.needs_render = false;
+        app.schedule_zoom_render();
+        assert_eq!(app.render.render_delay, RENDER_DELAY_FRAMES);
+        assert!(!app.render.needs_render);
+
+        // No delay: the render is triggered right away
+        app.render.render_delay = 0;
+        app.render.zoom_render_delay = 0;
+        app.schedule_zoom_render();
+        assert_eq!(app.render.render_delay, 0);
+        assert!(app.render.needs_render);
+
+        let (config, warnings) = AppConfig::from_json(r#"{"render_delay_frames": 500}"#);
+        assert_eq!(config.render_delay_frames, MAX_RENDER_DELAY_FRAMES);
+        assert!(warnings.iter().any(|w| w == "Render delay clamped"));
+    }
+
+    #[test]
+    fn test_should_notify() {
+        assert!(!should_notify(true, 0.2));
+        assert!(!should_notify(true, NOTIFY_MIN_RENDER_SECS - 0.01));
+        assert!(should_notify(true, NOTIFY_MIN_RENDER_SECS));
+        assert!(should_notify(true, 60.0));
+        assert!(!should_notify(false, 60.0));
+    }
+
+    #[test]
+    fn test_run_benchmark() {
+        let fractal = FractalRegistry::default()
+            .create(FractalType::Mandelbrot)
+            .unwrap();
+        let stats = run_benchmark(fractal.as_ref(), 2, 3, 16, 12, 50);
+        assert_eq!(stats.frame_secs.len(), 6);
+        assert!(stats.frame_secs.iter().all(|&t| t >= 0.0));
+        assert!(stats.mean() >= 0.0);
+
+        let stats = BenchStats {
+            frame_secs: vec![4.0, 1.0, 3.0, 2.0],
+        };
+        assert_eq!(stats.mean(), 2.5);
+        assert_eq!(stats.median(), 2.5);
+        let stats = BenchStats {
+            frame_secs: vec![5.0, 1.0, 2.0],
+        };
+        assert_eq!(stats.median(), 2.0);
+    }
+
+    #[test]
+    fn test_iteration_probe_ignores_unreached_limit() {
+        // Every point here escapes within a few iterations, so raising the
+        // limit must not recolor anything
+        let view = FractalViewState {
+            center_x: 2.0,
+            center_y: 2.0,
+            zoom: 8.0,
+            ..FractalViewState::default()
+        };
+        let fractal = fractal::Mandelbrot::default();
+        let low = iteration_probe(&fractal, &view, 16, 16, 50).unwrap();
+        let high = iteration_probe(&fractal, &view, 16, 16, 500).unwrap();
+        assert_eq!(difference_heatmap(&low, &high).1, 0);
+    }
+
+    #[test]
+    fn test_difference_heatmap() {
+        use egui::Color32;
+        let a = [
+            Color32::BLACK,
+            Color32::WHITE,
+            Color32::from_rgb(10, 20, 30),
+        ];
+        let b = [
+            Color32::BLACK,
+            Color32::BLACK,
+            Color32::from_rgb(11, 20, 30),
+        ];
+        let (pixels, changed) = difference_heatmap(&a, &b);
+        assert_eq!(pixels.len(), 3);
+        assert_eq!(changed, 2);
+        assert_eq!(pixels[0], Color32::BLACK);
+        // Maximal change is white, a tiny one is a dim red
+        assert_eq!(pixels[1], Color32::WHITE);
+        assert_ne!(pixels[2], Color32::BLACK);
+        assert_eq!((pixels[2].g(), pixels[2].b()), (0, 0));
+
+        let (_, changed) = difference_heatmap(&a, &a);
+        assert_eq!(changed, 0);
+    }
+
+    #[test]
+    fn test_throttle_elapsed() {
+        let start = Instant::now();
+        let after = |secs: f64| start + std::time::Duration::from_secs_f64(secs);
+        assert!(throttle_elapsed(None, start, MINIMAP_MIN_INTERVAL_SECS));
+        assert!(!throttle_elapsed(
+            Some(start),
+            start,
+            MINIMAP_MIN_INTERVAL_SECS
+        ));
+        assert!(!throttle_elapsed(
+            Some(start),
+            after(MINIMAP_MIN_INTERVAL_SECS * 0.5),
+            MINIMAP_MIN_INTERVAL_SECS
+        ));
+        assert!(throttle_elapsed(
+            Some(start),
+            after(MINIMAP_MIN_INTERVAL_SECS),
+            MINIMAP_MIN_INTERVAL_SECS
+        ));
+        // A clock that appears to run backwards never permits a render
+        assert!(!throttle_elapsed(
+            Some(after(1.0)),
+            start,
+            MINIMAP_MIN_INTERVAL_SECS
+        ));
+    }
+
+    #[test]
+    fn test_world_distance() {
+        assert_eq!(world_distance((0.0, 0.0), (3.0, 4.0)), 5.0);
+        assert_eq!(world_distance((-0.75, 0.1), (-0.75, 0.1)), 0.0);
+        assert_eq!(world_distance((1.0, -2.0), (-2.0, 2.0)), 5.0);
+        // Deep-zoom scale distances keep their magnitude
+        let d = world_distance((-0.75, 0.1), (-0.75 + 3e-12, 0.1 + 4e-12));
+        assert!((d / 5e-12 - 1.0).abs() < 1e-3);
+
+        let mut app = FractalApp::new(&AppConfig::default());
+        app.add_measure_point((0.0, 0.0));
+        app.add_measure_point((1.0, 0.0));
+        // The next click starts a new measurement
+        app.add_measure_point((2.0, 2.0));
+        assert_eq!(app.interaction.measure_points, vec![(2.0, 2.0)]);
+
+        // A pan or zoom clears it; re-applying the same view does not
+        app.set_view(app.get_view());
+        assert_eq!(app.interaction.measure_points.len(), 1);
+        app.zoom_view(2.0);
+        assert!(app.interaction.measure_points.is_empty());
+        app.add_measure_point((0.0, 0.0));
+        let mut view = app.get_view();
+        view.center_x += 0.1;
+        app.set_view(view);
+        assert!(app.interaction.measure_points.is_empty());
+    }
+
+    #[test]
+    fn test_iteration_label_cells() {
+        let fractal = FractalRegistry::default()
+            .create(FractalType::Mandelbrot)
+            .unwrap();
+        let (width, height) = (ITERATION_LABEL_CELL_PX * 4, ITERATION_LABEL_CELL_PX * 3);
+        let viewport = Viewport::from_view(-0.75, 0.1, 2.0, width, height);
+        let labels = iteration_label_cells(fractal.as_ref(), &viewport, width, height, 200);
+        assert_eq!(labels.len(), 12);
+
+        // Second column, third row: centered in its cell
+        let label = labels[2 * 4 + 1];
+        let center = ITERATION_LABEL_CELL_PX / 2;
+        assert_eq!(label.x, ITERATION_LABEL_CELL_PX + center);
+        assert_eq!(label.y, 2 * ITERATION_LABEL_CELL_PX + center);
+        let world = viewport.screen_to_world(label.x, label.y, width, height);
+        assert_eq!(label.iterations, fractal.compute(world.re, world.im, 200));
+        // The view has both interior and exterior cells
+        assert!(labels.iter().any(|l| l.iterations == 200));
+        assert!(labels.iter().any(|l| l.iterations < 200));
+
+        // Too many cells: overlay off
+        let huge = ITERATION_LABEL_CELL_PX * 1000;
+        let viewport = Viewport::from_view(-0.75, 0.1, 2.0, huge, huge);
+        assert!(iteration_label_cells(fractal.as_ref(), &viewport, huge, huge, 200).is_empty());
+    }
+
+    #[test]
+    fn test_nice_grid_interval() {
+        // Visible width 4/zoom at aspect 1
+        let cases = [
+            (1.0, 0.5),
+            (3.0, 0.2),
+            (10.0, 0.05),
+            (1000.0, 0.0005),
+            (1e12, 5e-13),
+        ];
+        for (zoom, expected) in cases {
+            let range = 4.0 / zoom;
+            let interval = nice_grid_interval(range, GRID_TARGET_LINES);
+            assert!(
+                (interval - expected).abs() < expected * 1e-9,
+                "zoom {}: got {}, expected {}",
+                zoom,
+                interval,
+                expected
+            );
+            let lines = range / interval;
+            assert!((4.0..=16.0).contains(&lines));
+        }
+        assert_eq!(nice_grid_interval(0.0, GRID_TARGET_LINES), 0.0);
+        assert_eq!(nice_grid_interval(f64::NAN, GRID_TARGET_LINES), 0.0);
+    }
+
+    #[test]
+    fn test_effective_iterations() {
+        assert_eq!(effective_iterations(200, false), 200);
+        assert_eq!(effective_iterations(200, true), 1600);
+        // Boost is capped but never lowers the setting
+        assert_eq!(effective_iterations(2000, true), PREVIEW_MAX_ITERATIONS);
+        assert_eq!(effective_iterations(20_000, true), 20_000);
+    }
+
+    #[test]
+    fn test_config_sanitize_iterations() {
+        let mut config = AppConfig {
+            default_iterations: 0,
+            ..Default::default()
+        };
+        let warnings = config.sanitize();
+        assert_eq!(config.default_iterations, MIN_ITERATIONS);
+        assert!(!warnings.is_empty());
+    }
+
+    #[test]
+    fn test_config_sanitize_color_adjustment() {
+        let mut config = AppConfig::default();
+        config.color_adjustment.brightness = f32::NAN;
+        config.color_adjustment.contrast = MAX_CONTRAST * 2.0;
+        config.color_adjustment.curves.green.values[2] = 1.5;
+        config.color_adjustment.curves.blue.values[0] = 0.25;
+        config.custom_palette = vec![[0, 0, 0]; MAX_CUSTOM_PALETTE_STOPS + 1];
+        let warnings = config.sanitize();
+        assert_eq!(warnings.len(), 4);
+
+        let adjustment = config.color_adjustment;
+        assert_eq!(adjustment.brightness, 0.0);
+        assert_eq!(adjustment.contrast, 1.0);
+        assert!(adjustment.curves.green.is_identity());
+        // A valid edited curve is kept
+        assert_eq!(adjustment.curves.blue.values[0], 0.25);
+        assert_eq!(config.custom_palette.len(), MAX_CUSTOM_PALETTE_STOPS);
+        assert!(AppConfig::default().sanitize().is_empty());
+    }
+
+    #[test]
+    fn test_config_views_round_trip() {
+        let mut config = AppConfig::default();
+        config.views.insert(
+            FractalType::Julia,
+            FractalViewState {
+                palette_type: PaletteType::Fire,
+                color_processor_type: color_pipeline::ColorProcessorType::OrbitTrapOrigin,
+                processor_params: HashMap::from([("threshold".to_string(), 0.25)]),
+                ..Default::default()
+            },
+        );
+        config.views.insert(
+            FractalType::Newton,
+            FractalViewState {
+                palette_type: PaletteType::Ice,
+                ..Default::default()
+            },
+        );
+
+        let json = serde_json::to_string(&config).unwrap();
+        let (loaded, warnings) = AppConfig::from_json(&json);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(loaded.views, config.views);
+
+        // Startup restores each fractal's own coloring
+        let app = FractalApp::new(&loaded);
+        let julia = &app.views[&FractalType::Julia];
+        assert_eq!(julia.palette_type, PaletteType::Fire);
+        assert_eq!(
+            julia.color_processor_type,
+            color_pipeline::ColorProcessorType::OrbitTrapOrigin
+        );
+        assert_eq!(julia.processor_params.get("threshold"), Some(&0.25));
+        assert_eq!(
+            app.views[&FractalType::Newton].palette_type,
+            PaletteType::Ice
+        );
+        assert_eq!(
+            app.views[&FractalType::Mandelbrot].palette_type,
+            loaded.default_palette
+        );
+
+        // An unknown fractal in the map only drops that entry
+        let json = json.replacen("\"Newton\"", "\"Bogus\"", 1);
+        let (salvaged, warnings) = AppConfig::from_json(&json);
+        assert!(!warnings.is_empty());
+        assert_eq!(salvaged.views.len(), 1);
+        assert!(salvaged.views.contains_key(&FractalType::Julia));
+    }
+
+    #[test]
+    fn test_config_salvages_unknown_enum() {
+        let json = r#"{
+            "window_width": 900.0,
+            "default_fractal": "NotAFractal",
+            "bookmarks": [
+                {"name": "good", "fractal_type": "Julia", "center_x": 0.0, "center_y": 0.0,
+                 "zoom": 0.0, "max_iterations": 100, "palette_type": "Ice"},
+                {"name": "bad", "fractal_type": "Bogus", "center_x": 0.0, "center_y": 0.0,
+                 "zoom": 1.0, "max_iterations": 100, "palette_type": "Ice"}
+            ]
+        }"#;
+        let (config, warnings) = AppConfig::from_json(json);
+        assert_eq!(config.window_width, 900.0);
+        assert_eq!(config.default_fractal, FractalType::Mandelbrot);
+        assert_eq!(config.bookmarks.len(), 1);
+        assert_eq!(config.bookmarks[0].name, "good");
+        assert_eq!(config.bookmarks[0].zoom, 1.0);
+        assert!(!warnings.is_empty());
+    }
+}
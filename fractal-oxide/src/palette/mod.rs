@@ -1,5 +1,7 @@
 use eframe::egui::Color32;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::OnceLock;
 
 // Global singleton instances for each palette type
@@ -10,8 +12,11 @@ static ICE_PALETTE: OnceLock<IcePalette> = OnceLock::new();
 static GRAYSCALE_PALETTE: OnceLock<GrayscalePalette> = OnceLock::new();
 static PSYCHEDELIC_PALETTE: OnceLock<PsychedelicPalette> = OnceLock::new();
 
+/// Histogram bins per channel when extracting dominant colors (4 bits)
+const COLOR_BIN_SHIFT: u32 = 4;
+
 /// Available color palette types for fractal rendering.
-#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum PaletteType {
     #[default]
     Classic,
@@ -19,6 +24,16 @@ pub enum PaletteType {
     Ice,
     Grayscale,
     Psychedelic,
+    /// Stops set at runtime, e.g. extracted from an image
+    Custom,
+}
+
+impl PaletteType {
+    /// Whether the palette offset and banding frequency change this
+    /// palette's colors
+    pub fn uses_offset(self) -> bool {
+        matches!(self, PaletteType::Psychedelic)
+    }
 }
 
 /// Trait for color palettes.
@@ -145,6 +160,109 @@ impl Palette for PsychedelicPalette {
     }
 }
 
+/// Palette built from user-supplied color stops.
+///
+/// Stops are evenly spaced across t and interpolated like the built-in
+/// gradients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomPalette {
+    colors: Vec<(f32, f32, f32)>,
+}
+
+impl CustomPalette {
+    /// Returns None when there are no stops
+    pub fn new(stops: &[[u8; 3]]) -> Option<Self> {
+        if stops.is_empty() {
+            return None;
+        }
+        let colors = stops
+            .iter()
+            .map(|c| {
+                (
+                    c[0] as f32 / 255.0,
+                    c[1] as f32 / 255.0,
+                    c[2] as f32 / 255.0,
+                )
+            })
+            .collect();
+        Some(Self { colors })
+    }
+
+    /// Build a palette from the `count` dominant colors of an image file
+    pub fn from_image(path: &Path, count: usize) -> Result<Self, String> {
+        let img = image::open(path)
+            .map_err(|e| format!("Failed to load image: {}", e))?
+            .to_rgb8();
+        let pixels: Vec<[u8; 3]> = img.pixels().map(|p| p.0).collect();
+        Self::new(&dominant_colors(&pixels, count)).ok_or_else(|| "Image is empty".to_string())
+    }
+
+    /// The palette's color stops, in order
+    pub fn stops(&self) -> Vec<[u8; 3]> {
+        self.colors
+            .iter()
+            .map(|&(r, g, b)| {
+                [
+                    (r * 255.0).round() as u8,
+                    (g * 255.0).round() as u8,
+                    (b * 255.0).round() as u8,
+                ]
+            })
+            .collect()
+    }
+}
+
+impl Palette for CustomPalette {
+    fn name(&self) -> &str {
+        "Custom"
+    }
+
+    fn color(&self, t: f32) -> Color32 {
+        if self.colors.len() == 1 {
+            let (r, g, b) = self.colors[0];
+            return Color32::from_rgb((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8);
+        }
+        interpolate_colors(&self.colors, t)
+    }
+}
+
+/// Up to `count` representative colors of an image by histogram binning.
+///
+/// Pixels are grouped into coarse RGB bins; the most populated bins win and
+/// each contributes the average color of its pixels. The result is ordered
+/// dark to light so it reads as a gradient.
+pub fn dominant_colors(pixels: &[[u8; 3]], count: usize) -> Vec<[u8; 3]> {
+    let mut bins: HashMap<[u8; 3], (u64, [u64; 3])> = HashMap::new();
+    for p in pixels {
+        let key = p.map(|c| c >> COLOR_BIN_SHIFT);
+        let (n, sum) = bins.entry(key).or_default();
+        *n += 1;
+        for (s, &c) in sum.iter_mut().zip(p) {
+            *s += c as u64;
+        }
+    }
+
+    let mut ranked: Vec<(u64, [u8; 3], [u64; 3])> = bins
+        .into_iter()
+        .map(|(key, (n, sum))| (n, key, sum))
+        .collect();
+    // Ties broken by bin so the result doesn't depend on hash order
+    ranked.sort_unstable_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let mut colors: Vec<[u8; 3]> = ranked
+        .into_iter()
+        .take(count)
+        .map(|(n, _, sum)| sum.map(|s| (s / n) as u8))
+        .collect();
+    colors.sort_by_key(|&c| luminance(c));
+    colors
+}
+
+/// Integer Rec. 601 luma, used only for ordering
+fn luminance([r, g, b]: [u8; 3]) -> u32 {
+    299 * r as u32 + 587 * g as u32 + 114 * b as u32
+}
+
 /// Interpolates between a list of RGB colors.
 ///
 /// t should be in range [0.0, 1.0]. Colors are evenly spaced across this range.
@@ -178,7 +296,7 @@ fn interpolate_colors(colors: &[(f32, f32, f32)], t: f32) -> Color32 {
 /// h: hue in range [0.0, 1.0] (0=red, 1/3=green, 2/3=blue)
 /// s: saturation in range [0.0, 1.0]
 /// v: value/brightness in range [0.0, 1.0]
-fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
+pub fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
     let i = (h * 6.0).floor() as i32;
     let f = h * 6.0 - i as f32;
     let p = v * (1.0 - s);
@@ -198,9 +316,19 @@ fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
 /// Gets a color from the specified palette.
 ///
 /// For Psychedelic palette, offset is added to t for hue rotation.
+#[allow(dead_code)]
 pub fn get_color(palette_type: PaletteType, t: f32, offset: f32) -> Color32 {
-    let adjusted_t = if matches!(palette_type, PaletteType::Psychedelic) {
-        (t + offset) % 1.0
+    get_banded_color(palette_type, t, offset, 1.0)
+}
+
+/// Gets a color from the specified palette with hue banding.
+///
+/// For Psychedelic palette the hue cycles `frequency` times across t in
+/// [0, 1]: hue = (t * frequency + offset) % 1.0. Other palettes ignore
+/// offset and frequency.
+pub fn get_banded_color(palette_type: PaletteType, t: f32, offset: f32, frequency: f32) -> Color32 {
+    let adjusted_t = if palette_type.uses_offset() {
+        (t * frequency + offset).rem_euclid(1.0)
     } else {
         t
     };
@@ -217,6 +345,25 @@ pub fn get_color(palette_type: PaletteType, t: f32, offset: f32) -> Color32 {
         PaletteType::Psychedelic => PSYCHEDELIC_PALETTE
             .get_or_init(|| PsychedelicPalette)
             .color(adjusted_t),
+        // The stops live with the caller; see `get_custom_banded_color`
+        PaletteType::Custom => CLASSIC_PALETTE
+            .get_or_init(|| ClassicPalette)
+            .color(adjusted_t),
+    }
+}
+
+/// `get_banded_color`, drawing `PaletteType::Custom` from `custom`. Without
+/// custom stops it falls back to Classic.
+pub fn get_custom_banded_color(
+    palette_type: PaletteType,
+    custom: Option<&CustomPalette>,
+    t: f32,
+    offset: f32,
+    frequency: f32,
+) -> Color32 {
+    match (palette_type, custom) {
+        (PaletteType::Custom, Some(palette)) => palette.color(t),
+        _ => get_banded_color(palette_type, t, offset, frequency),
     }
 }
 
@@ -347,6 +494,62 @@ mod tests {
         assert_ne!(c1, c2, "Different offsets should produce different colors");
     }
 
+    #[test]
+    fn test_psychedelic_frequency_cycles() {
+        // With frequency 4 the hue wraps back to its start every quarter of t
+        let steps = 400;
+        let hue_at = |i: usize| {
+            get_banded_color(PaletteType::Psychedelic, i as f32 / steps as f32, 0.0, 4.0)
+        };
+        let start = hue_at(0);
+        let repeats = (1..steps)
+            .filter(|&i| hue_at(i) == start && hue_at(i - 1) != start)
+            .count();
+        assert_eq!(repeats, 3, "Hue should restart at t = 0.25, 0.5 and 0.75");
+        assert_eq!(hue_at(100), start);
+        assert_ne!(hue_at(50), start);
+
+        // Frequency 1 matches the plain palette lookup
+        assert_eq!(
+            get_banded_color(PaletteType::Psychedelic, 0.3, 0.2, 1.0),
+            get_color(PaletteType::Psychedelic, 0.3, 0.2)
+        );
+    }
+
+    #[test]
+    fn test_dominant_colors_of_two_color_image() {
+        // 3/4 teal, 1/4 orange, with a single stray pixel that shouldn't
+        // make the cut
+        let teal = [0, 128, 128];
+        let orange = [255, 140, 0];
+        let mut pixels = vec![teal; 300];
+        pixels.extend(vec![orange; 100]);
+        pixels.push([255, 255, 255]);
+
+        let colors = dominant_colors(&pixels, 2);
+        assert_eq!(colors, vec![teal, orange]);
+
+        let palette = CustomPalette::new(&colors).unwrap();
+        assert_eq!(palette.stops(), vec![teal, orange]);
+        assert_eq!(palette.color(0.0), Color32::from_rgb(0, 128, 128));
+        assert_eq!(palette.color(1.0), Color32::from_rgb(255, 140, 0));
+        assert!(CustomPalette::new(&[]).is_none());
+
+        // Custom colors come from the stops passed in, Classic without them
+        assert_eq!(
+            get_custom_banded_color(PaletteType::Custom, Some(&palette), 0.0, 0.0, 1.0),
+            palette.color(0.0)
+        );
+        assert_eq!(
+            get_custom_banded_color(PaletteType::Custom, None, 0.3, 0.0, 1.0),
+            get_color(PaletteType::Classic, 0.3, 0.0)
+        );
+        assert_eq!(
+            get_custom_banded_color(PaletteType::Fire, Some(&palette), 0.3, 0.0, 1.0),
+            get_color(PaletteType::Fire, 0.3, 0.0)
+        );
+    }
+
     #[test]
     fn test_hsv_to_rgb_red() {
         let (r, g, b) = hsv_to_rgb(0.0, 1.0, 1.0);
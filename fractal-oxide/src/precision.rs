@@ -0,0 +1,567 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Most significant digits an `HpDecimal` holds: `parse` rejects longer
+/// input and arithmetic results are truncated toward zero to this many.
+/// Far beyond any zoom depth reachable in practice, and keeps pasted text
+/// or repeated operations from building huge numbers.
+const MAX_DIGITS: usize = 1000;
+/// Largest decimal exponent magnitude `HpDecimal::parse` accepts, which
+/// bounds the length of the formatted string
+const MAX_EXPONENT: i64 = 1000;
+
+/// Arbitrary-precision decimal number used to store deep-zoom center
+/// coordinates without the ~16 significant digit limit of f64.
+///
+/// Stored internally as `digits * 10^exponent` with little-endian decimal
+/// digits. Serialized as a plain decimal string (e.g. "-0.7436438870371587").
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct HpDecimal {
+    negative: bool,
+    /// Decimal digits, least significant first. Empty for zero.
+    digits: Vec<u8>,
+    exponent: i32,
+}
+
+impl HpDecimal {
+    /// Parse a decimal string such as "-1.25", "3", ".5" or "1.5e-20".
+    /// Inputs with more than `MAX_DIGITS` digits or an exponent beyond
+    /// `MAX_EXPONENT` are rejected.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let s = input.trim();
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let (mantissa, exp_part) = match s.find(['e', 'E']) {
+            Some(pos) => (&s[..pos], Some(&s[pos + 1..])),
+            None => (s, None),
+        };
+        let exponent: i64 = match exp_part {
+            Some(e) => e
+                .parse()
+                .map_err(|_| format!("Invalid exponent in '{}'", input))?,
+            None => 0,
+        };
+
+        let (int_part, frac_part) = match mantissa.split_once('.') {
+            Some((i, f)) => (i, f),
+            None => (mantissa, ""),
+        };
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(format!("Invalid number '{}'", input));
+        }
+        if !int_part
+            .chars()
+            .chain(frac_part.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            return Err(format!("Invalid number '{}'", input));
+        }
+
+        if int_part.len() + frac_part.len() > MAX_DIGITS {
+            return Err(format!(
+                "Too many digits in '{}' (at most {})",
+                input, MAX_DIGITS
+            ));
+        }
+        let exponent = i64::try_from(frac_part.len())
+            .ok()
+            .and_then(|frac_len| exponent.checked_sub(frac_len))
+            .filter(|e| (-MAX_EXPONENT..=MAX_EXPONENT).contains(e))
+            .ok_or_else(|| format!("Exponent out of range in '{}'", input))?;
+        let exponent = exponent as i32;
+        let digits = int_part
+            .bytes()
+            .chain(frac_part.bytes())
+            .rev()
+            .map(|b| b - b'0')
+            .collect();
+
+        let mut value = Self {
+            negative,
+            digits,
+            exponent,
+        };
+        value.normalize();
+        Ok(value)
+    }
+
+    /// Convert an f64 exactly as Rust's shortest round-trip formatting
+    /// represents it. Returns None for NaN or infinite values.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        Self::parse(&format!("{:e}", value)).ok()
+    }
+
+    /// Nearest f64 approximation (used for display and f64 rendering).
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap_or(0.0)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Decimal addition, exact to `MAX_DIGITS` significant digits.
+    pub fn add(&self, other: &Self) -> Self {
+        if self.is_zero() {
+            return other.clone();
+        }
+        if other.is_zero() {
+            return self.clone();
+        }
+
+        // Align both operands to the smaller exponent, ignoring digits
+        // too far below the larger operand to survive truncation
+        let top = self.top_exponent().max(other.top_exponent());
+        let exponent = self
+            .exponent
+            .min(other.exponent)
+            .max(top - MAX_DIGITS as i32 - 1);
+        let a = self.truncate(exponent).shifted_digits(exponent);
+        let b = other.truncate(exponent).shifted_digits(exponent);
+
+        let mut result = if self.negative == other.negative {
+            Self {
+                negative: self.negative,
+                digits: add_magnitudes(&a, &b),
+                exponent,
+            }
+        } else {
+            match compare_magnitudes(&a, &b) {
+                Ordering::Equal => return Self::default(),
+                Ordering::Greater => Self {
+                    negative: self.negative,
+                    digits: sub_magnitudes(&a, &b),
+                    exponent,
+                },
+                Ordering::Less => Self {
+                    negative: other.negative,
+                    digits: sub_magnitudes(&b, &a),
+                    exponent,
+                },
+            }
+        };
+        result.normalize();
+        result
+    }
+
+    /// Add an f64 offset. The offset keeps its own ~17 significant digits
+    /// regardless of the magnitude of `self`, so small deep-zoom pans
+    /// accumulate without the rounding loss of plain f64 addition.
+    pub fn add_f64(&self, delta: f64) -> Self {
+        match Self::from_f64(delta) {
+            Some(d) => self.add(&d),
+            None => self.clone(),
+        }
+    }
+
+    /// Decimal multiplication, exact to `MAX_DIGITS` significant digits.
+    pub fn mul(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::default();
+        }
+        let mut sums = vec![0u32; self.digits.len() + other.digits.len()];
+        for (i, &a) in self.digits.iter().enumerate() {
+            for (j, &b) in other.digits.iter().enumerate() {
+                sums[i + j] += a as u32 * b as u32;
+            }
+        }
+        let mut digits = Vec::with_capacity(sums.len() + 1);
+        let mut carry = 0;
+        for sum in sums {
+            let value = sum + carry;
+            digits.push((value % 10) as u8);
+            carry = value / 10;
+        }
+        while carry > 0 {
+            digits.push((carry % 10) as u8);
+            carry /= 10;
+        }
+        let mut result = Self {
+            negative: self.negative != other.negative,
+            digits,
+            exponent: self.exponent + other.exponent,
+        };
+        result.normalize();
+        result
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            negative: !self.negative && !self.is_zero(),
+            ..self.clone()
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        Self {
+            negative: false,
+            ..self.clone()
+        }
+    }
+
+    /// Drop the digits below 10^`exponent`, rounding toward zero.
+    pub fn truncate(&self, exponent: i32) -> Self {
+        if self.exponent >= exponent {
+            return self.clone();
+        }
+        let drop = (exponent - self.exponent) as usize;
+        if drop >= self.digits.len() {
+            return Self::default();
+        }
+        let mut result = Self {
+            negative: self.negative,
+            digits: self.digits[drop..].to_vec(),
+            exponent,
+        };
+        result.normalize();
+        result
+    }
+
+    /// Number of digits after the decimal point.
+    pub fn fraction_digits(&self) -> u32 {
+        (-self.exponent).max(0) as u32
+    }
+
+    /// Exponent just above the most significant digit
+    fn top_exponent(&self) -> i32 {
+        self.exponent + self.digits.len() as i32
+    }
+
+    /// Digits re-expressed at a lower exponent (appends zeros).
+    fn shifted_digits(&self, exponent: i32) -> Vec<u8> {
+        let shift = (self.exponent - exponent) as usize;
+        let mut digits = vec![0; shift];
+        digits.extend_from_slice(&self.digits);
+        digits
+    }
+
+    /// Strip redundant zeros so equal values have identical
+    /// representations, and truncate to `MAX_DIGITS` significant digits.
+    fn normalize(&mut self) {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        if self.digits.len() > MAX_DIGITS {
+            let excess = self.digits.len() - MAX_DIGITS;
+            self.digits.drain(..excess);
+            self.exponent += excess as i32;
+        }
+        let trailing = self.digits.iter().take_while(|&&d| d == 0).count();
+        if trailing > 0 {
+            self.digits.drain(..trailing);
+            self.exponent += trailing as i32;
+        }
+        if self.digits.is_empty() {
+            self.negative = false;
+            self.exponent = 0;
+        }
+    }
+}
+
+impl fmt::Display for HpDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut s = String::new();
+        if self.negative {
+            s.push('-');
+        }
+        let most_significant_first: String = self
+            .digits
+            .iter()
+            .rev()
+            .map(|d| (b'0' + d) as char)
+            .collect();
+        if self.exponent >= 0 {
+            s.push_str(&most_significant_first);
+            s.extend(std::iter::repeat_n('0', self.exponent as usize));
+        } else {
+            let frac_len = (-self.exponent) as usize;
+            let len = most_significant_first.len();
+            if len > frac_len {
+                s.push_str(&most_significant_first[..len - frac_len]);
+                s.push('.');
+                s.push_str(&most_significant_first[len - frac_len..]);
+            } else {
+                s.push_str("0.");
+                s.extend(std::iter::repeat_n('0', frac_len - len));
+                s.push_str(&most_significant_first);
+            }
+        }
+        write!(f, "{}", s)
+    }
+}
+
+impl TryFrom<String> for HpDecimal {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<HpDecimal> for String {
+    fn from(value: HpDecimal) -> Self {
+        value.to_string()
+    }
+}
+
+/// High-precision view center. Seeds the reference orbit for deep zoom
+/// while the f64 `center_x`/`center_y` on the view remain the display values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HpCenter {
+    pub re: HpDecimal,
+    pub im: HpDecimal,
+}
+
+impl HpCenter {
+    pub fn from_f64(re: f64, im: f64) -> Option<Self> {
+        Some(Self {
+            re: HpDecimal::from_f64(re)?,
+            im: HpDecimal::from_f64(im)?,
+        })
+    }
+
+    /// Parse from two decimal strings.
+    pub fn parse(re: &str, im: &str) -> Result<Self, String> {
+        Ok(Self {
+            re: HpDecimal::parse(re)?,
+            im: HpDecimal::parse(im)?,
+        })
+    }
+
+    pub fn to_f64(&self) -> (f64, f64) {
+        (self.re.to_f64(), self.im.to_f64())
+    }
+
+    /// Bring the high-precision center in line with an f64 center that was
+    /// moved by ordinary view math, applying only the difference so digits
+    /// beyond f64 precision are preserved.
+    pub fn follow(&self, center_x: f64, center_y: f64) -> Self {
+        let (re, im) = self.to_f64();
+        Self {
+            re: if re == center_x {
+                self.re.clone()
+            } else {
+                self.re.add_f64(center_x - re)
+            },
+            im: if im == center_y {
+                self.im.clone()
+            } else {
+                self.im.add_f64(center_y - im)
+            },
+        }
+    }
+}
+
+fn add_magnitudes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let sum = a.get(i).copied().unwrap_or(0) + b.get(i).copied().unwrap_or(0) + carry;
+        result.push(sum % 10);
+        carry = sum / 10;
+    }
+    if carry > 0 {
+        result.push(carry);
+    }
+    result
+}
+
+/// Subtract magnitudes, requires a >= b.
+fn sub_magnitudes(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i8;
+    for (i, &digit) in a.iter().enumerate() {
+        let mut diff = digit as i8 - b.get(i).copied().unwrap_or(0) as i8 - borrow;
+        if diff < 0 {
+            diff += 10;
+            borrow = 1;
+        } else {
+            borrow = 0;
+        }
+        result.push(diff as u8);
+    }
+    result
+}
+
+fn compare_magnitudes(a: &[u8], b: &[u8]) -> Ordering {
+    let len = a.len().max(b.len());
+    for i in (0..len).rev() {
+        let da = a.get(i).copied().unwrap_or(0);
+        let db = b.get(i).copied().unwrap_or(0);
+        match da.cmp(&db) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    Ordering::Equal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        for s in [
+            "0",
+            "1",
+            "-1.25",
+            "0.001",
+            "123.456",
+            "-0.000000000000000000000123",
+        ] {
+            assert_eq!(HpDecimal::parse(s).unwrap().to_string(), s);
+        }
+        assert_eq!(HpDecimal::parse("1.5e-3").unwrap().to_string(), "0.0015");
+        assert_eq!(HpDecimal::parse("+2.50").unwrap().to_string(), "2.5");
+        assert_eq!(HpDecimal::parse("-0.0").unwrap().to_string(), "0");
+        assert!(HpDecimal::parse("abc").is_err());
+        assert!(HpDecimal::parse("").is_err());
+        assert!(HpDecimal::parse("1.2.3").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_out_of_range() {
+        for s in [
+            "1e2147483647",
+            "1e-2147483648",
+            "1e99999999999999999999",
+            "1e1001",
+            "0.5e-1000",
+        ] {
+            assert!(HpDecimal::parse(s).is_err(), "{} should be rejected", s);
+        }
+        let long = format!("0.{}", "3".repeat(MAX_DIGITS));
+        assert!(HpDecimal::parse(&long).is_err());
+        assert!(serde_json::from_str::<HpDecimal>("\"1e2000000000\"").is_err());
+
+        // The limits themselves still parse and format
+        assert_eq!(HpDecimal::parse("1e1000").unwrap().to_string().len(), 1001);
+        let small = HpDecimal::parse("1e-1000").unwrap();
+        assert_eq!(small.to_string().len(), 1002);
+        let digits = format!("0.{}", "3".repeat(MAX_DIGITS - 1));
+        assert_eq!(HpDecimal::parse(&digits).unwrap().to_string(), digits);
+    }
+
+    #[test]
+    fn test_from_f64() {
+        assert_eq!(HpDecimal::from_f64(-0.5).unwrap().to_string(), "-0.5");
+        assert_eq!(HpDecimal::from_f64(0.1).unwrap().to_f64(), 0.1);
+        assert!(HpDecimal::from_f64(f64::NAN).is_none());
+    }
+
+    #[test]
+    fn test_add() {
+        let a = HpDecimal::parse("1.5").unwrap();
+        let b = HpDecimal::parse("-2.25").unwrap();
+        assert_eq!(a.add(&b).to_string(), "-0.75");
+        assert_eq!(b.add(&a).to_string(), "-0.75");
+        assert_eq!(a.add(&a).to_string(), "3");
+        assert_eq!(a.add(&HpDecimal::parse("-1.5").unwrap()).to_string(), "0");
+        assert_eq!(
+            HpDecimal::parse("9.99")
+                .unwrap()
+                .add(&HpDecimal::parse("0.01").unwrap())
+                .to_string(),
+            "10"
+        );
+    }
+
+    #[test]
+    fn test_mul_and_truncate() {
+        let a = HpDecimal::parse("-1.5").unwrap();
+        let b = HpDecimal::parse("0.25").unwrap();
+        assert_eq!(a.mul(&b).to_string(), "-0.375");
+        assert_eq!(a.mul(&a).to_string(), "2.25");
+        assert_eq!(a.mul(&HpDecimal::default()).to_string(), "0");
+        assert_eq!(
+            HpDecimal::parse("99.9")
+                .unwrap()
+                .mul(&HpDecimal::parse("99.9").unwrap())
+                .to_string(),
+            "9980.01"
+        );
+        // Digits beyond f64 survive squaring
+        let tiny = HpDecimal::parse("1.00000000000000000001").unwrap();
+        assert_eq!(
+            tiny.mul(&tiny).to_string(),
+            "1.0000000000000000000200000000000000000001"
+        );
+
+        let x = HpDecimal::parse("-0.123456").unwrap();
+        assert_eq!(x.truncate(-3).to_string(), "-0.123");
+        assert_eq!(x.truncate(0).to_string(), "0");
+        assert_eq!(x.truncate(-9), x);
+        assert_eq!(x.fraction_digits(), 6);
+        assert_eq!(x.neg().to_string(), "0.123456");
+        assert_eq!(x.abs(), x.neg());
+        assert_eq!(HpDecimal::default().neg().to_string(), "0");
+    }
+
+    #[test]
+    fn test_arithmetic_precision_is_bounded() {
+        // Repeated squaring would double the digits every step
+        let mut x = HpDecimal::parse("1.0000001").unwrap();
+        for _ in 0..12 {
+            x = x.mul(&x);
+            assert!(x.digits.len() <= MAX_DIGITS);
+        }
+        assert!((x.to_f64() - 1.0000001f64.powi(4096)).abs() < 1e-12);
+
+        // A term far below the other's last kept digit is dropped
+        let tiny = HpDecimal::parse("1e-900").unwrap();
+        let tiny = tiny.mul(&tiny);
+        let one = HpDecimal::parse("1").unwrap();
+        assert_eq!(one.add(&tiny), one);
+        assert_eq!(tiny.add(&tiny), tiny.mul(&HpDecimal::parse("2").unwrap()));
+        let sum = HpDecimal::parse("0.5").unwrap().add(&x);
+        assert!(sum.digits.len() <= MAX_DIGITS);
+        assert!((sum.to_f64() - 0.5 - x.to_f64()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_add_f64_keeps_precision() {
+        // A step far below f64 resolution at this magnitude is preserved
+        let base = HpDecimal::parse("-0.75").unwrap();
+        let moved = base.add_f64(1e-30);
+        assert_eq!(moved.to_string(), "-0.749999999999999999999999999999");
+        assert_eq!(moved.to_f64(), -0.75);
+    }
+
+    #[test]
+    fn test_hp_center_follow() {
+        let hp = HpCenter::parse("-0.75000000000000000000001", "0.1").unwrap();
+        // Unchanged f64 center keeps every digit
+        let (x, y) = hp.to_f64();
+        assert_eq!(hp.follow(x, y), hp);
+        // Moving the f64 center shifts the high-precision one by the same delta
+        let moved = hp.follow(x + 0.25, y);
+        assert_eq!(moved.re.to_string(), "-0.50000000000000000000001");
+    }
+
+    #[test]
+    fn test_hp_center_serde_round_trip() {
+        let hp = HpCenter::parse(
+            "-1.7499999999999999999999999999999999123456789",
+            "0.0000000000000000000000000000000000000000031415926535",
+        )
+        .unwrap();
+        let json = serde_json::to_string(&hp).unwrap();
+        assert!(json.contains("-1.7499999999999999999999999999999999123456789"));
+        let back: HpCenter = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, hp);
+        assert_eq!(back.re.to_string(), hp.re.to_string());
+        assert_eq!(back.im.to_string(), hp.im.to_string());
+    }
+}
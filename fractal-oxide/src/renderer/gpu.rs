@@ -0,0 +1,484 @@
+//! Optional wgpu compute backend for power-2 Mandelbrot/Julia.
+//!
+//! The escape-time loop runs in a compute shader and only iteration counts
+//! and final z values are read back, so coloring still goes through the CPU
+//! color pipeline. The shader works in f32, which limits it to shallow zooms;
+//! `supports_view` decides when the CPU must take over.
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use num_complex::Complex64;
+
+use crate::color_pipeline::{FractalResult, OrbitData};
+use crate::fractal::GpuKernel;
+use crate::FractalViewState;
+
+/// Compute shader workgroup edge (must match @workgroup_size in the shader)
+const WORKGROUP_SIZE: u32 = 8;
+/// Bytes per sample written by the shader: iterations, escaped, z (vec2<f32>)
+const SAMPLE_SIZE: u64 = 16;
+/// Bytes in the Params uniform (including trailing padding)
+const PARAMS_SIZE: usize = 48;
+/// Smallest pixel spacing, relative to the coordinate magnitude, that f32
+/// still resolves with a few bits to spare
+const GPU_MIN_RELATIVE_STEP: f64 = 1e-5;
+
+const SHADER: &str = r#"
+struct Params {
+    origin: vec2<f32>,
+    step: vec2<f32>,
+    c: vec2<f32>,
+    width: u32,
+    rows: u32,
+    max_iter: u32,
+    julia: u32,
+    _pad: vec2<u32>,
+}
+
+struct Sample {
+    iterations: u32,
+    escaped: u32,
+    z: vec2<f32>,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read_write> samples: array<Sample>;
+
+@compute @workgroup_size(8, 8)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    if (id.x >= params.width || id.y >= params.rows) {
+        return;
+    }
+    let point = params.origin + vec2<f32>(f32(id.x), f32(id.y)) * params.step;
+    var z = vec2<f32>(0.0, 0.0);
+    var c = point;
+    if (params.julia != 0u) {
+        z = point;
+        c = params.c;
+    }
+
+    var sample = Sample(params.max_iter, 0u, z);
+    for (var i = 0u; i < params.max_iter; i = i + 1u) {
+        let r2 = z.x * z.x;
+        let i2 = z.y * z.y;
+        if (r2 + i2 > 4.0) {
+            sample = Sample(i, 1u, z);
+            break;
+        }
+        z = vec2<f32>(r2 - i2 + c.x, 2.0 * z.x * z.y + c.y);
+    }
+    samples[id.y * params.width + id.x] = sample;
+}
+"#;
+
+/// One pixel as computed on the GPU
+#[derive(Debug, Clone, Copy)]
+pub struct GpuSample {
+    pub iterations: u32,
+    pub escaped: bool,
+    pub final_z: Complex64,
+}
+
+impl GpuSample {
+    /// Convert to a result for the color pipeline (no orbit data)
+    pub fn to_result(self) -> FractalResult {
+        if self.escaped {
+            FractalResult::escaped(self.iterations, self.final_z, OrbitData::new())
+        } else {
+            FractalResult::inside_set(self.iterations)
+        }
+    }
+}
+
+/// Whether f32 has enough precision to render this view on the GPU
+pub fn supports_view(view: &FractalViewState, width: u32, height: u32) -> bool {
+    if view.zoom <= 0.0 || width == 0 || height == 0 {
+        return false;
+    }
+    let aspect = width as f64 / height as f64;
+    let half_height = 2.0 / view.zoom;
+    let magnitude = (view.center_x.abs() + half_height * aspect)
+        .max(view.center_y.abs() + half_height)
+        .max(1.0);
+    let step = 2.0 * half_height / height as f64;
+    step >= magnitude * GPU_MIN_RELATIVE_STEP
+}
+
+/// A wgpu device with the escape-time compute pipeline
+pub struct GpuRenderer {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    buffers: Mutex<Option<Box<ChunkBuffers>>>,
+}
+
+/// Buffers shared by every `compute` call, reallocated only when a chunk
+/// needs more sample storage than the last allocation holds
+struct ChunkBuffers {
+    capacity: u64,
+    params: wgpu::Buffer,
+    samples: wgpu::Buffer,
+    readback: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+impl GpuRenderer {
+    /// Open a GPU adapter and build the pipeline. None if no adapter is
+    /// available.
+    pub fn new() -> Option<Self> {
+        let instance = wgpu::Instance::default();
+        let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        }))?;
+        let (device, queue) = block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("fractal-oxide compute"),
+                required_limits: wgpu::Limits::downlevel_defaults(),
+                ..Default::default()
+            },
+            None,
+        ))
+        .ok()?;
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("escape-time"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("escape-time"),
+            layout: None,
+            module: &shader,
+            entry_point: "main",
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        Some(Self {
+            device,
+            queue,
+            pipeline,
+            buffers: Mutex::new(None),
+        })
+    }
+
+    fn create_buffers(&self, capacity: u64) -> ChunkBuffers {
+        let params = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("params"),
+            size: PARAMS_SIZE as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let samples = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("samples"),
+            size: capacity,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size: capacity,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("escape-time"),
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: params.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: samples.as_entire_binding(),
+                },
+            ],
+        });
+        ChunkBuffers {
+            capacity,
+            params,
+            samples,
+            readback,
+            bind_group,
+        }
+    }
+
+    /// Compute rows `y_start..y_end` of a `width` x `height` frame, in the
+    /// same pixel mapping as `screen_to_fractal`. Row-major samples, or None
+    /// if the readback failed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn compute(
+        &self,
+        kernel: GpuKernel,
+        view: &FractalViewState,
+        width: u32,
+        height: u32,
+        y_start: u32,
+        y_end: u32,
+        max_iter: u32,
+    ) -> Option<Vec<GpuSample>> {
+        let rows = y_end.saturating_sub(y_start);
+        if width == 0 || rows == 0 {
+            return Some(Vec::new());
+        }
+
+        // Same mapping as screen_to_fractal: 4/zoom world units per frame height
+        let step = 4.0 / (view.zoom * height as f64);
+        let aspect = width as f64 / height as f64;
+        let origin_x = view.center_x - 2.0 * aspect / view.zoom;
+        let origin_y = view.center_y + 2.0 / view.zoom - y_start as f64 * step;
+        let (c_re, c_im, julia) = match kernel {
+            GpuKernel::Mandelbrot => (0.0, 0.0, 0),
+            GpuKernel::Julia { c_re, c_im } => (c_re, c_im, 1),
+        };
+
+        let mut params = Vec::with_capacity(PARAMS_SIZE);
+        for value in [origin_x, origin_y, step, -step, c_re, c_im] {
+            params.extend_from_slice(&(value as f32).to_le_bytes());
+        }
+        for value in [width, rows, max_iter, julia, 0, 0] {
+            params.extend_from_slice(&value.to_le_bytes());
+        }
+
+        let output_size = width as u64 * rows as u64 * SAMPLE_SIZE;
+        // Held until the readback is unmapped, so concurrent chunks take turns
+        let mut cached = self.buffers.lock().unwrap_or_else(|e| e.into_inner());
+        let buffers = match &mut *cached {
+            Some(buffers) if buffers.capacity >= output_size => buffers,
+            slot => slot.insert(Box::new(self.create_buffers(output_size))),
+        };
+        self.queue.write_buffer(&buffers.params, 0, &params);
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &buffers.bind_group, &[]);
+            pass.dispatch_workgroups(
+                width.div_ceil(WORKGROUP_SIZE),
+                rows.div_ceil(WORKGROUP_SIZE),
+                1,
+            );
+        }
+        encoder.copy_buffer_to_buffer(&buffers.samples, 0, &buffers.readback, 0, output_size);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = buffers.readback.slice(..output_size);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver.recv().ok()?.ok()?;
+
+        let samples = {
+            let data = slice.get_mapped_range();
+            data.chunks_exact(SAMPLE_SIZE as usize)
+                .map(|chunk| {
+                    let word = |i: usize| [chunk[i], chunk[i + 1], chunk[i + 2], chunk[i + 3]];
+                    GpuSample {
+                        iterations: u32::from_le_bytes(word(0)),
+                        escaped: u32::from_le_bytes(word(4)) != 0,
+                        final_z: Complex64::new(
+                            f32::from_le_bytes(word(8)) as f64,
+                            f32::from_le_bytes(word(12)) as f64,
+                        ),
+                    }
+                })
+                .collect()
+        };
+        buffers.readback.unmap();
+        Some(samples)
+    }
+}
+
+/// Lazily created GPU renderer; remembers if no adapter could be opened so
+/// the CPU fallback doesn't retry every frame
+#[derive(Default)]
+pub enum GpuBackend {
+    #[default]
+    Uninitialized,
+    Ready(GpuRenderer),
+    Unavailable,
+}
+
+impl GpuBackend {
+    pub fn get(&mut self) -> Option<&GpuRenderer> {
+        if let GpuBackend::Uninitialized = self {
+            *self = match GpuRenderer::new() {
+                Some(renderer) => GpuBackend::Ready(renderer),
+                None => GpuBackend::Unavailable,
+            };
+        }
+        match self {
+            GpuBackend::Ready(renderer) => Some(renderer),
+            _ => None,
+        }
+    }
+}
+
+/// Wakes the blocked thread when the future makes progress
+struct ThreadWaker(std::thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Minimal executor for wgpu's adapter/device futures
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fractal::{Fractal, Julia, Mandelbrot};
+    use crate::renderer::screen_to_fractal;
+
+    /// Fraction of pixels whose GPU and CPU iteration counts differ by more
+    /// than one, or None when no GPU is available
+    fn mismatch_fraction(fractal: &dyn Fractal, view: &FractalViewState) -> Option<f64> {
+        let renderer = GpuRenderer::new()?;
+        let (width, height, max_iter) = (32, 24, 100);
+        let samples = renderer
+            .compute(
+                fractal.gpu_kernel().unwrap(),
+                view,
+                width,
+                height,
+                0,
+                height,
+                max_iter,
+            )
+            .unwrap();
+        assert_eq!(samples.len(), (width * height) as usize);
+
+        let mut mismatches = 0;
+        for y in 0..height {
+            for x in 0..width {
+                let (px, py) = screen_to_fractal(x, y, width, height, view);
+                let cpu = fractal.compute_full(px, py, max_iter);
+                let gpu = samples[(y * width + x) as usize];
+                if cpu.iterations.abs_diff(gpu.iterations) > 1 || cpu.escaped != gpu.escaped {
+                    mismatches += 1;
+                }
+            }
+        }
+        Some(mismatches as f64 / (width * height) as f64)
+    }
+
+    #[test]
+    fn test_gpu_matches_cpu_iterations() {
+        let view = FractalViewState {
+            center_x: -0.5,
+            zoom: 1.0,
+            ..Default::default()
+        };
+        let julia_view = FractalViewState {
+            zoom: 1.0,
+            ..Default::default()
+        };
+        let cases: [(&dyn Fractal, &FractalViewState); 2] = [
+            (&Mandelbrot::default(), &view),
+            (&Julia::default(), &julia_view),
+        ];
+        for (fractal, view) in cases {
+            let Some(fraction) = mismatch_fraction(fractal, view) else {
+                eprintln!("No GPU adapter available; skipping GPU comparison");
+                return;
+            };
+            // f32 rounding only shifts a few boundary pixels
+            assert!(
+                fraction < 0.05,
+                "{} mismatched {}",
+                fractal.name(),
+                fraction
+            );
+        }
+    }
+
+    #[test]
+    fn test_chunks_reuse_buffers() {
+        let Some(renderer) = GpuRenderer::new() else {
+            eprintln!("No GPU adapter available; skipping GPU buffer reuse");
+            return;
+        };
+        let view = FractalViewState {
+            center_x: -0.5,
+            zoom: 1.0,
+            ..Default::default()
+        };
+        let (width, height, max_iter) = (32, 24, 100);
+        let chunk = |y_start, y_end| {
+            renderer
+                .compute(
+                    GpuKernel::Mandelbrot,
+                    &view,
+                    width,
+                    height,
+                    y_start,
+                    y_end,
+                    max_iter,
+                )
+                .unwrap()
+        };
+        let full = chunk(0, height);
+        let capacity = |renderer: &GpuRenderer| {
+            renderer
+                .buffers
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|b| b.capacity)
+        };
+        let allocated = capacity(&renderer);
+
+        // Smaller chunks fit the existing buffers and read back only their rows
+        let mut chunked = chunk(0, 10);
+        chunked.extend(chunk(10, height));
+        assert_eq!(capacity(&renderer), allocated);
+        let iterations =
+            |samples: &[GpuSample]| samples.iter().map(|s| s.iterations).collect::<Vec<_>>();
+        assert_eq!(iterations(&chunked), iterations(&full));
+    }
+
+    #[test]
+    fn test_supports_view_precision_limit() {
+        let shallow = FractalViewState {
+            center_x: -0.5,
+            zoom: 1.0,
+            ..Default::default()
+        };
+        assert!(supports_view(&shallow, 800, 600));
+        let deep = FractalViewState {
+            zoom: 1e8,
+            ..shallow
+        };
+        assert!(!supports_view(&deep, 800, 600));
+    }
+
+    #[test]
+    fn test_power_two_only() {
+        let mut mandelbrot = Mandelbrot::default();
+        assert_eq!(mandelbrot.gpu_kernel(), Some(GpuKernel::Mandelbrot));
+        mandelbrot.set_parameter("power", 3.0);
+        assert_eq!(mandelbrot.gpu_kernel(), None);
+    }
+}
@@ -1,10 +1,18 @@
 use eframe::egui::Color32;
 use rayon::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::color_pipeline::{ColorContext, ColorPipeline};
-use crate::fractal::Fractal;
+use crate::color_pipeline::{
+    continuous_iterations, ChannelCurves, ColorContext, ColorCurve, ColorPipeline, FractalResult,
+};
+use crate::fractal::{Fractal, GpuKernel};
 use crate::palette::PaletteType;
+use crate::viewport::Viewport;
 use crate::FractalViewState;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "gpu")]
+pub mod gpu;
 
 /// A rectangular region to render
 #[derive(Clone, Debug)]
@@ -15,6 +23,86 @@ pub struct RenderRegion {
     pub height: u32,
 }
 
+/// Rows rendered between progress reports / cancellation checks in exports
+const EXPORT_ROW_BATCH: u32 = 16;
+
+/// Ordered-dither thresholds (in sixteenths) tiled over the image
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Per-channel distance from the mean color within which a pixel counts as
+/// matching it for `is_nearly_uniform`
+const UNIFORM_CHANNEL_TOLERANCE: i32 = 8;
+/// Fraction of pixels that must match the mean for a frame to be uniform
+const UNIFORM_PIXEL_FRACTION: f64 = 0.99;
+
+/// Largest |center_y|, in pixels, at which a view still counts as centered
+/// on the real axis for symmetry folding
+const SYMMETRY_CENTER_TOLERANCE_PX: f64 = 1e-3;
+
+/// Progress reporting and cancellation for long-running exports
+pub struct ExportControl<'a> {
+    /// Called with the completed fraction (0.0-1.0) after each row batch
+    pub progress: &'a (dyn Fn(f32) + Sync),
+    /// Checked between row batches; set to abort the render
+    pub cancel: &'a AtomicBool,
+}
+
+static NEVER_CANCEL: AtomicBool = AtomicBool::new(false);
+
+impl ExportControl<'static> {
+    /// No progress reporting and no cancellation
+    pub fn none() -> Self {
+        Self {
+            progress: &|_| {},
+            cancel: &NEVER_CANCEL,
+        }
+    }
+}
+
+impl ExportControl<'_> {
+    /// Render `height` rows in parallel batches. Returns None if cancelled.
+    fn render_rows<T: Send>(
+        &self,
+        width: u32,
+        height: u32,
+        pixel: impl Fn(u32, u32) -> T + Sync,
+    ) -> Option<Vec<T>> {
+        let mut pixels = Vec::with_capacity(width as usize * height as usize);
+        let finished = self.stream_rows(width, height, pixel, |batch| {
+            pixels.extend(batch);
+            Ok(())
+        });
+        matches!(finished, Ok(true)).then_some(pixels)
+    }
+
+    /// Render `height` rows in parallel batches, handing each batch (whole
+    /// rows, top to bottom) to `sink` instead of keeping the image. Returns
+    /// Ok(false) if cancelled.
+    fn stream_rows<T: Send>(
+        &self,
+        width: u32,
+        height: u32,
+        pixel: impl Fn(u32, u32) -> T + Sync,
+        mut sink: impl FnMut(Vec<T>) -> Result<(), String>,
+    ) -> Result<bool, String> {
+        let mut y_start = 0;
+        while y_start < height {
+            if self.cancel.load(Ordering::Relaxed) {
+                return Ok(false);
+            }
+            let y_end = (y_start + EXPORT_ROW_BATCH).min(height);
+            let batch: Vec<T> = (y_start..y_end)
+                .into_par_iter()
+                .flat_map(|y| (0..width).map(|x| pixel(x, y)).collect::<Vec<_>>())
+                .collect();
+            sink(batch)?;
+            y_start = y_end;
+            (self.progress)(y_start as f32 / height as f32);
+        }
+        Ok(true)
+    }
+}
+
 /// Configuration for rendering operations
 #[derive(Clone)]
 pub struct RenderConfig {
@@ -24,10 +112,41 @@ pub struct RenderConfig {
     pub max_iterations: u32,
     pub palette_type: PaletteType,
     pub palette_offset: f32,
+    /// Psychedelic hue cycles across the iteration range
+    pub palette_frequency: f32,
     pub color_pipeline: ColorPipeline,
+    /// Color for points that never escaped
+    pub interior_color: Color32,
+    /// Invert the final RGB (negative image). Like `adjustment`, applied to
+    /// finished pixels through `color_lut`, not by `color_for`
+    pub invert: bool,
+    /// Brightness/contrast applied before inversion
+    pub adjustment: ColorAdjustment,
+    /// Compute eligible full renders on the GPU (needs the `gpu` feature)
+    pub use_gpu: bool,
 }
 
 impl RenderConfig {
+    /// Plain export settings: Classic palette, default pipeline, black
+    /// interior, no adjustment, supersampling or GPU. Override fields with
+    /// struct update syntax.
+    pub fn new(width: u32, height: u32, max_iterations: u32) -> Self {
+        Self {
+            width,
+            height,
+            supersampling: false,
+            max_iterations,
+            palette_type: PaletteType::Classic,
+            palette_offset: 0.0,
+            palette_frequency: 1.0,
+            color_pipeline: ColorPipeline::default(),
+            interior_color: Color32::BLACK,
+            invert: false,
+            adjustment: ColorAdjustment::default(),
+            use_gpu: false,
+        }
+    }
+
     /// Get the actual render dimensions (accounting for supersampling)
     pub fn render_dimensions(&self) -> (u32, u32) {
         if self.supersampling {
@@ -37,8 +156,30 @@ impl RenderConfig {
         }
     }
 
-    /// Create a color context for the current render settings
-    fn color_context(&self) -> ColorContext {
+    /// The GPU kernel to use for this render, if GPU rendering is enabled
+    /// and the fractal and color processor can run without CPU-only data
+    fn gpu_kernel(&self, fractal: &dyn Fractal) -> Option<GpuKernel> {
+        if !self.use_gpu || self.color_pipeline.uses_orbit_data() {
+            return None;
+        }
+        fractal.gpu_kernel()
+    }
+
+    /// Whether the lower half of a full render can be mirrored from the
+    /// upper half: the fractal is symmetric about the real axis, the view is
+    /// centered on it, and coloring doesn't depend on orbit data (trap
+    /// positions need not be symmetric)
+    fn mirrors_real_axis(&self, fractal: &dyn Fractal, view: &FractalViewState) -> bool {
+        if !fractal.has_real_axis_symmetry() || self.color_pipeline.uses_orbit_data() {
+            return false;
+        }
+        let (_, render_height) = self.render_dimensions();
+        let pixel_height = 4.0 / (view.zoom * render_height as f64);
+        view.center_y.abs() <= SYMMETRY_CENTER_TOLERANCE_PX * pixel_height
+    }
+
+    /// Create a color context for the current render settings and view
+    pub fn color_context(&self, view: &FractalViewState) -> ColorContext<'_> {
         ColorContext::new(
             self.max_iterations,
             self.palette_type,
@@ -46,6 +187,26 @@ impl RenderConfig {
             self.width,
             self.height,
         )
+        .with_palette_frequency(self.palette_frequency)
+        .with_world_per_pixel(4.0 / (view.zoom * self.height.max(1) as f64))
+        .with_iteration_range(self.color_pipeline.iteration_range())
+        .with_custom_palette(self.color_pipeline.custom_palette())
+    }
+
+    /// Color a computed point before adjustment and inversion. Interior is
+    /// decided by the escaped flag, not the iteration count, so points
+    /// escaping at iteration 0 still get palette colors.
+    pub fn color_for(&self, result: &FractalResult, context: &ColorContext) -> Color32 {
+        if result.escaped {
+            self.color_pipeline.process(result, context)
+        } else {
+            self.interior_color
+        }
+    }
+
+    /// Table applying `adjustment` and `invert` to finished pixels
+    pub fn color_lut(&self) -> ColorLut {
+        ColorLut::new(&self.adjustment, self.invert)
     }
 }
 
@@ -66,6 +227,8 @@ pub struct RenderEngine {
     supersample_buffer: Option<Vec<Color32>>,
     // Buffer for normal rendering
     render_buffer: Option<Vec<Color32>>,
+    #[cfg(feature = "gpu")]
+    gpu: gpu::GpuBackend,
 }
 
 impl RenderEngine {
@@ -100,16 +263,33 @@ impl RenderEngine {
             return false;
         }
 
-        let chunk_pixels: Vec<Color32> = (y_start..y_end)
-            .into_par_iter()
-            .flat_map(|y| {
-                (0..render_width)
-                    .map(|x| {
-                        compute_pixel(x, y, render_width, render_height, fractal, view, config)
-                    })
-                    .collect::<Vec<_>>()
-            })
-            .collect();
+        let gpu_pixels = config
+            .gpu_kernel(fractal)
+            .and_then(|kernel| self.render_rows_gpu(kernel, view, config, y_start, y_end));
+        let mirror = gpu_pixels.is_none() && config.mirrors_real_axis(fractal, view);
+        let mirror_source = |y: u32| {
+            if mirror {
+                mirrored_row(y, render_height)
+            } else {
+                None
+            }
+        };
+        let chunk_pixels: Vec<Color32> = gpu_pixels.unwrap_or_else(|| {
+            (y_start..y_end)
+                .into_par_iter()
+                .flat_map(|y| {
+                    if mirror_source(y).is_some() {
+                        // Filled from the mirrored row below
+                        return vec![Color32::BLACK; render_width as usize];
+                    }
+                    (0..render_width)
+                        .map(|x| {
+                            compute_pixel(x, y, render_width, render_height, fractal, view, config)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        });
 
         // Write to appropriate buffer
         let buffer = if config.supersampling {
@@ -119,14 +299,75 @@ impl RenderEngine {
         };
 
         if let Some(buf) = buffer {
-            let start_idx = y_start as usize * render_width as usize;
-            let chunk_len = (y_end - y_start) as usize * render_width as usize;
+            let row_len = render_width as usize;
+            let start_idx = y_start as usize * row_len;
+            let chunk_len = (y_end - y_start) as usize * row_len;
             buf[start_idx..start_idx + chunk_len].copy_from_slice(&chunk_pixels);
+
+            // Source rows lie above their mirrors, so with chunks rendered
+            // top to bottom they are already in the buffer
+            for y in y_start..y_end {
+                if let Some(source) = mirror_source(y) {
+                    let source_idx = source as usize * row_len;
+                    buf.copy_within(source_idx..source_idx + row_len, y as usize * row_len);
+                }
+            }
         }
 
         true
     }
 
+    /// Compute and color rows on the GPU. None means the CPU should render
+    /// them (no adapter, or the view is too deep for f32).
+    #[cfg(feature = "gpu")]
+    fn render_rows_gpu(
+        &mut self,
+        kernel: GpuKernel,
+        view: &FractalViewState,
+        config: &RenderConfig,
+        y_start: u32,
+        y_end: u32,
+    ) -> Option<Vec<Color32>> {
+        let (render_width, render_height) = config.render_dimensions();
+        if !gpu::supports_view(view, render_width, render_height) {
+            return None;
+        }
+        let samples = self.gpu.get()?.compute(
+            kernel,
+            view,
+            render_width,
+            render_height,
+            y_start,
+            y_end,
+            config.max_iterations,
+        )?;
+        let context = config.color_context(view);
+        Some(
+            samples
+                .into_par_iter()
+                .map(|sample| config.color_for(&sample.to_result(), &context))
+                .collect(),
+        )
+    }
+
+    #[cfg(not(feature = "gpu"))]
+    fn render_rows_gpu(
+        &mut self,
+        _kernel: GpuKernel,
+        _view: &FractalViewState,
+        _config: &RenderConfig,
+        _y_start: u32,
+        _y_end: u32,
+    ) -> Option<Vec<Color32>> {
+        None
+    }
+
+    /// Whether a GPU adapter could be opened (initializing it if needed)
+    #[cfg(feature = "gpu")]
+    pub fn gpu_available(&mut self) -> bool {
+        self.gpu.get().is_some()
+    }
+
     /// Render a region (for pan optimization)
     /// Returns the rendered pixels for the region
     pub fn render_region(
@@ -160,6 +401,7 @@ impl RenderEngine {
                                 y,
                                 display_width,
                                 display_height,
+                                2,
                                 fractal,
                                 view,
                                 config,
@@ -195,7 +437,12 @@ impl RenderEngine {
         if config.supersampling {
             self.supersample_buffer.take().map(|pixels| {
                 let (render_width, render_height) = config.render_dimensions();
-                downsample_2x(&pixels, render_width, render_height)
+                downsample_2x(
+                    &pixels,
+                    render_width,
+                    render_height,
+                    config.adjustment.dither,
+                )
             })
         } else {
             self.render_buffer.take()
@@ -209,17 +456,15 @@ impl RenderEngine {
         image: &mut eframe::egui::ColorImage,
         dx: f64,
         dy: f64,
-        _zoom: f64,
+        zoom: f64,
     ) -> Vec<RenderRegion> {
         let width = image.width() as u32;
         let height = image.height() as u32;
 
-        // Calculate pixel shift based on fractal pan amount
-        // Fractal pan: 0.5 / zoom per keypress
-        // Visible range: 4.0 * aspect / zoom horizontal, 4.0 / zoom vertical
-        let aspect = width as f64 / height as f64;
-        let shift_x = (-dx * width as f64 / (8.0 * aspect)) as i32;
-        let shift_y = (dy * height as f64 / 8.0) as i32;
+        // Whole-pixel shift from the viewport's world-per-pixel scale; the
+        // caller snaps the pan to the same shift so reused pixels line up
+        let (shift_x, shift_y) = Viewport::from_view(0.0, 0.0, zoom, width, height)
+            .calculate_pixel_shift(dx, dy, width, height);
 
         // Clamp shift values to image dimensions
         let shift_x = shift_x.clamp(-(width as i32), width as i32);
@@ -306,40 +551,343 @@ impl RenderEngine {
             .collect()
     }
 
-    /// Render a high-resolution image for export
-    #[allow(clippy::too_many_arguments)]
+    /// Render a high-resolution image for export at `config.width` x
+    /// `config.height` with `supersample_factor`^2 samples per pixel. The
+    /// config's own `supersampling` and `use_gpu` are ignored.
     pub fn render_high_res(
         &self,
         fractal: &dyn Fractal,
         view: &FractalViewState,
-        width: u32,
-        height: u32,
-        max_iter: u32,
-        palette_type: PaletteType,
-        palette_offset: f32,
-        color_pipeline: ColorPipeline,
-    ) -> Vec<Color32> {
-        let config = RenderConfig {
+        config: &RenderConfig,
+        supersample_factor: u32,
+        control: &ExportControl,
+    ) -> Option<Vec<Color32>> {
+        let mut pixels = Vec::with_capacity(config.width as usize * config.height as usize);
+        let finished = self.render_high_res_streamed(
+            fractal,
+            view,
+            config,
+            supersample_factor,
+            control,
+            |batch| {
+                pixels.extend(batch);
+                Ok(())
+            },
+        );
+        matches!(finished, Ok(true)).then_some(pixels)
+    }
+
+    /// Like `render_high_res`, but hands each batch of finished rows to
+    /// `sink` instead of holding the whole image, so exports larger than
+    /// memory can be written as they render. Returns Ok(false) if cancelled.
+    pub fn render_high_res_streamed(
+        &self,
+        fractal: &dyn Fractal,
+        view: &FractalViewState,
+        config: &RenderConfig,
+        supersample_factor: u32,
+        control: &ExportControl,
+        mut sink: impl FnMut(Vec<Color32>) -> Result<(), String>,
+    ) -> Result<bool, String> {
+        let (width, height) = (config.width, config.height);
+        let lut = config.color_lut();
+        control.stream_rows(
             width,
             height,
-            supersampling: false,
-            max_iterations: max_iter,
-            palette_type,
-            palette_offset,
-            color_pipeline,
-        };
+            |x, y| {
+                if supersample_factor > 1 {
+                    compute_pixel_supersampled(
+                        x,
+                        y,
+                        width,
+                        height,
+                        supersample_factor,
+                        fractal,
+                        view,
+                        config,
+                    )
+                } else {
+                    compute_pixel(x, y, width, height, fractal, view, config)
+                }
+            },
+            |mut batch| {
+                lut.apply_all(&mut batch);
+                sink(batch)
+            },
+        )
+    }
+}
 
-        (0..height)
-            .into_par_iter()
-            .flat_map(|y| {
-                (0..width)
-                    .map(|x| compute_pixel(x, y, width, height, fractal, view, &config))
-                    .collect::<Vec<_>>()
+/// Render an RGBA image for export where interior (non-escaped) pixels are
+/// fully transparent and escaped pixels are opaque, with
+/// `supersample_factor`^2 samples per pixel. Edge pixels get the average
+/// color of their escaped samples and an alpha of the escaped fraction.
+/// The config's interior color is ignored.
+pub fn render_high_res_rgba(
+    fractal: &dyn Fractal,
+    view: &FractalViewState,
+    config: &RenderConfig,
+    supersample_factor: u32,
+    control: &ExportControl,
+) -> Option<Vec<[u8; 4]>> {
+    let (width, height) = (config.width, config.height);
+    let factor = supersample_factor.max(1);
+    let samples = factor * factor;
+    let context = config.color_context(view);
+    let lut = config.color_lut();
+
+    control.render_rows(width, height, |x, y| {
+        let mut sum = [0u32; 3];
+        let mut escaped = 0;
+        for sy in 0..factor {
+            for sx in 0..factor {
+                let (px, py) = screen_to_fractal(
+                    x * factor + sx,
+                    y * factor + sy,
+                    width * factor,
+                    height * factor,
+                    view,
+                );
+                let result = config
+                    .color_pipeline
+                    .compute(fractal, px, py, config.max_iterations);
+                if result.escaped {
+                    let color = config.color_for(&result, &context);
+                    sum[0] += color.r() as u32;
+                    sum[1] += color.g() as u32;
+                    sum[2] += color.b() as u32;
+                    escaped += 1;
+                }
+            }
+        }
+        if escaped == 0 {
+            return [0, 0, 0, 0];
+        }
+        let [r, g, b] = sum.map(|channel| (channel / escaped) as u8);
+        let color = lut.apply(Color32::from_rgb(r, g, b));
+        let alpha = (255 * escaped + samples / 2) / samples;
+        [color.r(), color.g(), color.b(), alpha as u8]
+    })
+}
+
+/// Render a 16-bit grayscale image of the smooth iteration field for
+/// export: escaped points run from black at 0 to white at `max_iter`,
+/// interior points are black (white with `invert`), averaged over
+/// `supersample_factor`^2 samples per pixel. Only the config's size,
+/// iterations and `invert` are used. Finer steps than 8-bit color avoid
+/// banding in slow gradients.
+pub fn render_high_res_gray16(
+    fractal: &dyn Fractal,
+    view: &FractalViewState,
+    config: &RenderConfig,
+    supersample_factor: u32,
+    control: &ExportControl,
+) -> Option<Vec<u16>> {
+    let (width, height, max_iter) = (config.width, config.height, config.max_iterations);
+    let factor = supersample_factor.max(1);
+    control.render_rows(width, height, |x, y| {
+        let mut sum = 0.0;
+        for sy in 0..factor {
+            for sx in 0..factor {
+                let (px, py) = screen_to_fractal(
+                    x * factor + sx,
+                    y * factor + sy,
+                    width * factor,
+                    height * factor,
+                    view,
+                );
+                let result = fractal.compute_full(px, py, max_iter);
+                if result.escaped {
+                    let t = continuous_iterations(&result) / max_iter.max(1) as f32;
+                    sum += t.clamp(0.0, 1.0);
+                }
+            }
+        }
+        let level = (sum / (factor * factor) as f32 * u16::MAX as f32).round() as u16;
+        if config.invert {
+            u16::MAX - level
+        } else {
+            level
+        }
+    })
+}
+
+/// Lowest and highest escape counts over a `width` x `height` sampling of
+/// `view`, or None if no sampled point escapes
+pub fn escape_iteration_range(
+    fractal: &dyn Fractal,
+    view: &FractalViewState,
+    width: u32,
+    height: u32,
+    max_iter: u32,
+) -> Option<(u32, u32)> {
+    let counts = ExportControl::none().render_rows(width, height, |x, y| {
+        let (px, py) = screen_to_fractal(x, y, width, height, view);
+        fractal.compute(px, py, max_iter)
+    })?;
+    counts
+        .into_iter()
+        .filter(|&n| n < max_iter)
+        .fold(None, |range, n| match range {
+            None => Some((n, n)),
+            Some((lo, hi)) => Some((lo.min(n), hi.max(n))),
+        })
+}
+
+/// Brightness and contrast applied to each RGB channel:
+/// `out = (in - 128) * contrast + 128 + brightness`, clamped to 0..=255,
+/// followed by the per-channel tone curves
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ColorAdjustment {
+    pub brightness: f32,
+    pub contrast: f32,
+    #[serde(default)]
+    pub curves: ChannelCurves,
+    /// Ordered-dither averaged supersamples instead of truncating them
+    #[serde(default)]
+    pub dither: bool,
+}
+
+impl Default for ColorAdjustment {
+    fn default() -> Self {
+        Self {
+            brightness: 0.0,
+            contrast: 1.0,
+            curves: ChannelCurves::default(),
+            dither: false,
+        }
+    }
+}
+
+impl ColorAdjustment {
+    /// Whether `apply` leaves colors unchanged (dithering happens when
+    /// supersamples are averaged, not here)
+    pub fn is_identity(&self) -> bool {
+        Self {
+            dither: false,
+            ..*self
+        } == Self::default()
+    }
+
+    /// Adjust a color's RGB channels, keeping alpha. Images go through a
+    /// `ColorLut` instead.
+    pub fn apply(&self, color: Color32) -> Color32 {
+        if self.is_identity() {
+            return color;
+        }
+        let color = Color32::from_rgba_unmultiplied(
+            self.tone(color.r()),
+            self.tone(color.g()),
+            self.tone(color.b()),
+            color.a(),
+        );
+        self.curves.apply(color)
+    }
+
+    /// Brightness and contrast of one channel
+    fn tone(&self, channel: u8) -> u8 {
+        ((channel as f32 - 128.0) * self.contrast + 128.0 + self.brightness)
+            .round()
+            .clamp(0.0, 255.0) as u8
+    }
+}
+
+/// Per-channel table of a `ColorAdjustment` followed by optional
+/// inversion. Every step maps each channel on its own, so 256 entries per
+/// channel cover them; built once per setting change, then applied to
+/// finished pixels, which lets adjustment edits recolor a cached render
+/// instead of recomputing it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorLut {
+    channels: [[u8; 256]; 3],
+}
+
+impl Default for ColorLut {
+    fn default() -> Self {
+        Self::new(&ColorAdjustment::default(), false)
+    }
+}
+
+impl ColorLut {
+    pub fn new(adjustment: &ColorAdjustment, invert: bool) -> Self {
+        let table = |curve: &ColorCurve| {
+            std::array::from_fn(|c| {
+                let value = curve.map(adjustment.tone(c as u8));
+                if invert {
+                    255 - value
+                } else {
+                    value
+                }
             })
-            .collect()
+        };
+        let curves = &adjustment.curves;
+        Self {
+            channels: [
+                table(&curves.red),
+                table(&curves.green),
+                table(&curves.blue),
+            ],
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.channels
+            .iter()
+            .all(|table| table.iter().enumerate().all(|(i, &v)| v as usize == i))
+    }
+
+    /// Map a color's RGB channels, keeping alpha
+    pub fn apply(&self, color: Color32) -> Color32 {
+        let [red, green, blue] = &self.channels;
+        Color32::from_rgba_unmultiplied(
+            red[color.r() as usize],
+            green[color.g() as usize],
+            blue[color.b() as usize],
+            color.a(),
+        )
+    }
+
+    /// Recolor `pixels` in place
+    pub fn apply_all(&self, pixels: &mut [Color32]) {
+        if self.is_identity() {
+            return;
+        }
+        pixels
+            .par_iter_mut()
+            .for_each(|pixel| *pixel = self.apply(*pixel));
     }
 }
 
+/// Complement each RGB channel (255 - channel), keeping alpha
+pub fn invert_color(color: Color32) -> Color32 {
+    Color32::from_rgba_unmultiplied(255 - color.r(), 255 - color.g(), 255 - color.b(), color.a())
+}
+
+/// Whether nearly every pixel has (almost) the same color, i.e. the frame
+/// shows no structure: the view is entirely inside or outside the set
+pub fn is_nearly_uniform(pixels: &[Color32]) -> bool {
+    if pixels.is_empty() {
+        return false;
+    }
+    let mut sum = [0u64; 3];
+    for p in pixels {
+        sum[0] += p.r() as u64;
+        sum[1] += p.g() as u64;
+        sum[2] += p.b() as u64;
+    }
+    let n = pixels.len() as u64;
+    let mean = sum.map(|channel| (channel / n) as i32);
+    let matching = pixels
+        .iter()
+        .filter(|p| {
+            (p.r() as i32 - mean[0]).abs() <= UNIFORM_CHANNEL_TOLERANCE
+                && (p.g() as i32 - mean[1]).abs() <= UNIFORM_CHANNEL_TOLERANCE
+                && (p.b() as i32 - mean[2]).abs() <= UNIFORM_CHANNEL_TOLERANCE
+        })
+        .count();
+    matching as f64 >= UNIFORM_PIXEL_FRACTION * pixels.len() as f64
+}
+
 /// Convert screen coordinates to fractal coordinates
 pub fn screen_to_fractal(
     x: u32,
@@ -356,6 +904,14 @@ pub fn screen_to_fractal(
     (px, py)
 }
 
+/// The row whose mirror image about the real axis is row `y`, when the view
+/// is centered on the axis and `y` lies in the lower half. Row y maps to an
+/// offset of (y/height - 0.5), so rows y and height - y are mirrors; row 0
+/// and the center row have no distinct partner and are always computed.
+fn mirrored_row(y: u32, height: u32) -> Option<u32> {
+    (y > height / 2).then(|| height - y)
+}
+
 /// Compute color for a single pixel
 fn compute_pixel(
     x: u32,
@@ -367,39 +923,45 @@ fn compute_pixel(
     config: &RenderConfig,
 ) -> Color32 {
     let (px, py) = screen_to_fractal(x, y, width, height, view);
-    let result = fractal.compute_full(px, py, config.max_iterations);
+    let result = config
+        .color_pipeline
+        .compute(fractal, px, py, config.max_iterations);
 
-    let context = config.color_context();
-    config.color_pipeline.process(&result, &context)
+    let context = config.color_context(view);
+    config.color_for(&result, &context)
 }
 
-/// Compute pixel with 2x2 supersampling and averaging
+/// Compute pixel with NxN supersampling and averaging
+#[allow(clippy::too_many_arguments)]
 fn compute_pixel_supersampled(
     x: u32,
     y: u32,
     display_width: u32,
     display_height: u32,
+    factor: u32,
     fractal: &dyn Fractal,
     view: &FractalViewState,
     config: &RenderConfig,
 ) -> Color32 {
-    let render_width = display_width * 2;
-    let render_height = display_height * 2;
+    let render_width = display_width * factor;
+    let render_height = display_height * factor;
 
     let mut r_sum = 0u32;
     let mut g_sum = 0u32;
     let mut b_sum = 0u32;
 
-    let context = config.color_context();
+    let context = config.color_context(view);
 
-    for sy in 0..2 {
-        for sx in 0..2 {
-            let sx_coord = x * 2 + sx;
-            let sy_coord = y * 2 + sy;
+    for sy in 0..factor {
+        for sx in 0..factor {
+            let sx_coord = x * factor + sx;
+            let sy_coord = y * factor + sy;
 
             let (px, py) = screen_to_fractal(sx_coord, sy_coord, render_width, render_height, view);
-            let result = fractal.compute_full(px, py, config.max_iterations);
-            let color = config.color_pipeline.process(&result, &context);
+            let result = config
+                .color_pipeline
+                .compute(fractal, px, py, config.max_iterations);
+            let color = config.color_for(&result, &context);
 
             r_sum += color.r() as u32;
             g_sum += color.g() as u32;
@@ -407,11 +969,28 @@ fn compute_pixel_supersampled(
         }
     }
 
-    Color32::from_rgb((r_sum / 4) as u8, (g_sum / 4) as u8, (b_sum / 4) as u8)
+    let samples = factor * factor;
+    if config.adjustment.dither {
+        let channel = |sum: u32| dither_channel(sum as f32 / samples as f32, x, y);
+        return Color32::from_rgb(channel(r_sum), channel(g_sum), channel(b_sum));
+    }
+    Color32::from_rgb(
+        (r_sum / samples) as u8,
+        (g_sum / samples) as u8,
+        (b_sum / samples) as u8,
+    )
+}
+
+/// Quantize a channel value in 0.0..=255.0 to 8 bits with a 4x4 Bayer
+/// threshold for pixel (x, y), so fractional levels become a fine pattern
+/// whose local average matches `value` instead of a hard band edge
+pub fn dither_channel(value: f32, x: u32, y: u32) -> u8 {
+    let threshold = (BAYER_4X4[(y % 4) as usize][(x % 4) as usize] as f32 + 0.5) / 16.0;
+    (value + threshold).floor().clamp(0.0, 255.0) as u8
 }
 
 /// Downsample 2x image to 1x using box filter
-fn downsample_2x(pixels: &[Color32], width: u32, height: u32) -> Vec<Color32> {
+fn downsample_2x(pixels: &[Color32], width: u32, height: u32, dither: bool) -> Vec<Color32> {
     let display_width = width / 2;
     let display_height = height / 2;
     let mut downsampled = vec![Color32::BLACK; (display_width * display_height) as usize];
@@ -433,9 +1012,21 @@ fn downsample_2x(pixels: &[Color32], width: u32, height: u32) -> Vec<Color32> {
             let c10 = pixels[idx10];
             let c11 = pixels[idx11];
 
-            let r = ((c00.r() as u16 + c01.r() as u16 + c10.r() as u16 + c11.r() as u16) / 4) as u8;
-            let g = ((c00.g() as u16 + c01.g() as u16 + c10.g() as u16 + c11.g() as u16) / 4) as u8;
-            let b = ((c00.b() as u16 + c01.b() as u16 + c10.b() as u16 + c11.b() as u16) / 4) as u8;
+            let sum = |c: fn(&Color32) -> u8| {
+                c(&c00) as u16 + c(&c01) as u16 + c(&c10) as u16 + c(&c11) as u16
+            };
+            let channel = |c: fn(&Color32) -> u8| {
+                if dither {
+                    dither_channel(sum(c) as f32 / 4.0, x, y)
+                } else {
+                    (sum(c) / 4) as u8
+                }
+            };
+            let (r, g, b) = (
+                channel(Color32::r),
+                channel(Color32::g),
+                channel(Color32::b),
+            );
 
             downsampled[(y * display_width + x) as usize] = Color32::from_rgb(r, g, b);
         }
@@ -458,6 +1049,8 @@ mod tests {
             fractal_params: HashMap::new(),
             color_processor_type: crate::color_pipeline::ColorProcessorType::default(),
             palette_type: PaletteType::Classic,
+            hp_center: None,
+            processor_params: HashMap::new(),
         }
     }
 
@@ -470,7 +1063,12 @@ mod tests {
             max_iterations: 100,
             palette_type: PaletteType::Classic,
             palette_offset: 0.0,
+            palette_frequency: 1.0,
             color_pipeline: ColorPipeline::default(),
+            interior_color: Color32::BLACK,
+            invert: false,
+            adjustment: ColorAdjustment::default(),
+            use_gpu: false,
         };
         assert_eq!(config_normal.render_dimensions(), (100, 100));
 
@@ -481,11 +1079,344 @@ mod tests {
             max_iterations: 100,
             palette_type: PaletteType::Classic,
             palette_offset: 0.0,
+            palette_frequency: 1.0,
             color_pipeline: ColorPipeline::default(),
+            interior_color: Color32::BLACK,
+            invert: false,
+            adjustment: ColorAdjustment::default(),
+            use_gpu: false,
         };
         assert_eq!(config_ss.render_dimensions(), (200, 200));
     }
 
+    #[test]
+    fn test_is_nearly_uniform() {
+        assert!(is_nearly_uniform(&[Color32::BLACK; 100]));
+
+        // Slight noise and a stray pixel or two still count as uniform
+        let mut noisy = vec![Color32::from_rgb(20, 40, 60); 200];
+        noisy[3] = Color32::from_rgb(24, 37, 60);
+        noisy[150] = Color32::WHITE;
+        assert!(is_nearly_uniform(&noisy));
+
+        let varied: Vec<Color32> = (0..100)
+            .map(|i| Color32::from_rgb(i as u8 * 2, 0, 0))
+            .collect();
+        assert!(!is_nearly_uniform(&varied));
+        assert!(!is_nearly_uniform(&[]));
+    }
+
+    #[test]
+    fn test_color_adjustment() {
+        let colors = [
+            Color32::from_rgb(10, 128, 250),
+            Color32::from_rgba_unmultiplied(200, 60, 0, 128),
+            Color32::BLACK,
+            Color32::WHITE,
+        ];
+        let identity = ColorAdjustment::default();
+        for color in colors {
+            assert_eq!(identity.apply(color), color);
+        }
+        let contrast = ColorAdjustment {
+            brightness: 0.0,
+            contrast: 2.0,
+            ..Default::default()
+        };
+        // Mid-gray is the pivot; values either side move away from it
+        assert_eq!(
+            contrast.apply(Color32::from_gray(128)),
+            Color32::from_gray(128)
+        );
+        assert_eq!(
+            contrast.apply(Color32::from_gray(160)),
+            Color32::from_gray(192)
+        );
+        assert_eq!(
+            contrast.apply(Color32::from_gray(96)),
+            Color32::from_gray(64)
+        );
+        assert_eq!(contrast.apply(Color32::from_gray(220)), Color32::WHITE);
+        assert_eq!(contrast.apply(Color32::from_gray(20)), Color32::BLACK);
+
+        let brighter = ColorAdjustment {
+            brightness: 30.0,
+            contrast: 1.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            brighter.apply(Color32::from_gray(100)),
+            Color32::from_gray(130)
+        );
+        assert_eq!(brighter.apply(Color32::from_gray(240)), Color32::WHITE);
+    }
+
+    #[test]
+    fn test_color_lut_matches_adjustment() {
+        let adjustment = ColorAdjustment {
+            brightness: -20.0,
+            contrast: 1.5,
+            curves: ChannelCurves {
+                green: ColorCurve {
+                    values: [0.0, 0.0, 0.5, 1.0, 1.0],
+                },
+                ..Default::default()
+            },
+            dither: false,
+        };
+        assert!(ColorLut::default().is_identity());
+        let plain = ColorLut::new(&adjustment, false);
+        let inverted = ColorLut::new(&adjustment, true);
+        assert!(!plain.is_identity());
+        for v in (0..=255u8).step_by(5) {
+            let color = Color32::from_rgb(v, 255 - v, v / 2);
+            assert_eq!(ColorLut::default().apply(color), color);
+            assert_eq!(plain.apply(color), adjustment.apply(color));
+            // Inversion comes after the adjustment
+            assert_eq!(inverted.apply(color), invert_color(adjustment.apply(color)));
+        }
+
+        let mut pixels = vec![Color32::from_gray(100); 4];
+        inverted.apply_all(&mut pixels);
+        assert!(pixels
+            .iter()
+            .all(|&p| p == invert_color(adjustment.apply(Color32::from_gray(100)))));
+    }
+
+    #[test]
+    fn test_invert_color() {
+        let color = Color32::from_rgb(10, 128, 255);
+        assert_eq!(invert_color(color), Color32::from_rgb(245, 127, 0));
+        assert_eq!(invert_color(invert_color(color)), color);
+        assert_eq!(invert_color(Color32::BLACK), Color32::WHITE);
+    }
+
+    #[test]
+    fn test_interior_color_distinct_from_immediate_escape() {
+        let config = RenderConfig {
+            width: 10,
+            height: 10,
+            supersampling: false,
+            max_iterations: 100,
+            palette_type: PaletteType::Classic,
+            palette_offset: 0.0,
+            palette_frequency: 1.0,
+            color_pipeline: ColorPipeline::default(),
+            interior_color: Color32::from_rgb(40, 0, 80),
+            invert: false,
+            adjustment: ColorAdjustment::default(),
+            use_gpu: false,
+        };
+        let context = config.color_context(&FractalViewState::default());
+
+        let escaped_at_zero = FractalResult::escaped(
+            0,
+            num_complex::Complex64::new(10.0, 0.0),
+            crate::color_pipeline::OrbitData::new(),
+        );
+        let inside = FractalResult::inside_set(100);
+
+        let escaped_color = config.color_for(&escaped_at_zero, &context);
+        let inside_color = config.color_for(&inside, &context);
+        assert_eq!(
+            escaped_color,
+            crate::palette::get_color(PaletteType::Classic, 0.0, 0.0)
+        );
+        assert_eq!(inside_color, Color32::from_rgb(40, 0, 80));
+        assert_ne!(escaped_color, inside_color);
+    }
+
+    #[test]
+    fn test_custom_palette_per_config() {
+        use crate::palette::CustomPalette;
+        use std::sync::Arc;
+
+        let config = |stops: &[[u8; 3]]| RenderConfig {
+            width: 10,
+            height: 10,
+            supersampling: false,
+            max_iterations: 100,
+            palette_type: PaletteType::Custom,
+            palette_offset: 0.0,
+            palette_frequency: 1.0,
+            color_pipeline: ColorPipeline::default()
+                .with_custom_palette(CustomPalette::new(stops).map(Arc::new)),
+            interior_color: Color32::BLACK,
+            invert: false,
+            adjustment: ColorAdjustment::default(),
+            use_gpu: false,
+        };
+        let red = config(&[[255, 0, 0]]);
+        let blue = config(&[[0, 0, 255]]);
+        let fallback = config(&[]);
+        let view = test_view();
+
+        let result = FractalResult::escaped(
+            10,
+            num_complex::Complex64::new(10.0, 0.0),
+            crate::color_pipeline::OrbitData::new(),
+        );
+        let color = |config: &RenderConfig| config.color_for(&result, &config.color_context(&view));
+        assert_eq!(color(&red), Color32::from_rgb(255, 0, 0));
+        assert_eq!(color(&blue), Color32::from_rgb(0, 0, 255));
+        // Without stops Custom draws from Classic
+        assert_eq!(
+            color(&fallback),
+            color(&RenderConfig {
+                palette_type: PaletteType::Classic,
+                ..fallback.clone()
+            })
+        );
+    }
+
+    #[test]
+    fn test_high_res_supersampling_softens_edges() {
+        let fractal = crate::fractal::Mandelbrot::default();
+        // Boundary region with many sharp set/escape transitions
+        let view = FractalViewState {
+            center_x: -0.75,
+            center_y: 0.1,
+            zoom: 8.0,
+            max_iterations: 100,
+            ..test_view()
+        };
+        let (width, height) = (48, 48);
+        let render = |factor| {
+            RenderEngine::default()
+                .render_high_res(
+                    &fractal,
+                    &view,
+                    &RenderConfig {
+                        palette_type: PaletteType::Grayscale,
+                        ..RenderConfig::new(width, height, 100)
+                    },
+                    factor,
+                    &ExportControl::none(),
+                )
+                .unwrap()
+        };
+
+        // Count neighbouring pixel pairs with a large brightness jump
+        let hard_edges = |pixels: &[Color32]| {
+            let luma = |c: Color32| c.r() as i32 + c.g() as i32 + c.b() as i32;
+            let mut count = 0;
+            for y in 0..height as usize {
+                for x in 0..width as usize - 1 {
+                    let i = y * width as usize + x;
+                    if (luma(pixels[i]) - luma(pixels[i + 1])).abs() > 150 {
+                        count += 1;
+                    }
+                }
+            }
+            count
+        };
+
+        let plain = render(1);
+        let smooth = render(3);
+        assert_eq!(smooth.len(), plain.len());
+        assert!(hard_edges(&plain) > 0);
+        assert!(
+            hard_edges(&smooth) < hard_edges(&plain),
+            "supersampled {} vs plain {}",
+            hard_edges(&smooth),
+            hard_edges(&plain)
+        );
+    }
+
+    #[test]
+    fn test_high_res_progress_and_cancel() {
+        use std::sync::atomic::AtomicUsize;
+
+        let fractal = crate::fractal::Mandelbrot::default();
+        let view = test_view();
+        let render = |control: &ExportControl| {
+            RenderEngine::default().render_high_res(
+                &fractal,
+                &view,
+                &RenderConfig::new(20, 40, 50),
+                1,
+                control,
+            )
+        };
+
+        // Progress is reported per row batch, ending at 1.0
+        let calls = AtomicUsize::new(0);
+        let last = std::sync::Mutex::new(0.0f32);
+        let cancel = AtomicBool::new(false);
+        let progress = |p: f32| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            *last.lock().unwrap() = p;
+        };
+        let pixels = render(&ExportControl {
+            progress: &progress,
+            cancel: &cancel,
+        });
+        assert_eq!(pixels.map(|p| p.len()), Some(800));
+        assert_eq!(calls.load(Ordering::Relaxed), 3); // 40 rows in batches of 16
+        assert_eq!(*last.lock().unwrap(), 1.0);
+
+        // Cancelling before the first batch returns None without progress
+        let calls = AtomicUsize::new(0);
+        let cancel = AtomicBool::new(true);
+        let progress = |_| {
+            calls.fetch_add(1, Ordering::Relaxed);
+        };
+        let pixels = render(&ExportControl {
+            progress: &progress,
+            cancel: &cancel,
+        });
+        assert!(pixels.is_none());
+        assert_eq!(calls.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_symmetry_folding_matches_full_render() {
+        // The iteration is sign-exact in floating point, so the mirror is
+        // bit-identical
+        let fractal = crate::fractal::Mandelbrot::default();
+        let config = RenderConfig {
+            width: 48,
+            height: 32,
+            supersampling: false,
+            max_iterations: 64,
+            palette_type: PaletteType::Classic,
+            palette_offset: 0.0,
+            palette_frequency: 1.0,
+            color_pipeline: ColorPipeline::default(),
+            interior_color: Color32::BLACK,
+            invert: false,
+            adjustment: ColorAdjustment::default(),
+            use_gpu: false,
+        };
+        let view = FractalViewState {
+            center_x: -0.3,
+            ..test_view()
+        };
+        assert!(config.mirrors_real_axis(&fractal, &view));
+
+        let mut engine = RenderEngine::default();
+        engine.start_render(&config);
+        let mut y = 0;
+        while engine.render_full_chunk(&fractal, &view, &config, y, 5) {
+            y += 5;
+        }
+        let folded = engine.finalize(&config).unwrap();
+
+        let full: Vec<Color32> = (0..32)
+            .flat_map(|y| (0..48).map(move |x| (x, y)))
+            .map(|(x, y)| compute_pixel(x, y, 48, 32, &fractal, &view, &config))
+            .collect();
+        assert_eq!(folded, full);
+
+        // Off-axis views and asymmetric fractals are computed in full
+        let off_axis = FractalViewState {
+            center_y: 0.1,
+            ..view.clone()
+        };
+        assert!(!config.mirrors_real_axis(&fractal, &off_axis));
+        assert!(!config.mirrors_real_axis(&crate::fractal::BurningShip::default(), &view));
+    }
+
     #[test]
     fn test_screen_to_fractal_center() {
         let view = test_view();
@@ -506,7 +1437,7 @@ mod tests {
             })
             .collect();
 
-        let downsampled = downsample_2x(&pixels, 4, 4);
+        let downsampled = downsample_2x(&pixels, 4, 4, false);
 
         assert_eq!(downsampled.len(), 4);
 
@@ -517,6 +1448,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dither_preserves_local_average() {
+        // A slow horizontal ramp spanning two 8-bit levels: truncation gives
+        // two flat bands, dithering a pattern averaging to the true value
+        let (width, height) = (256u32, 8u32);
+        let value = |x: u32| 100.0 + 2.0 * x as f32 / width as f32;
+        for block_x in (0..width).step_by(4) {
+            for block_y in (0..height).step_by(4) {
+                let (mut dithered, mut exact) = (0.0, 0.0);
+                for y in block_y..block_y + 4 {
+                    for x in block_x..block_x + 4 {
+                        dithered += dither_channel(value(x), x, y) as f32;
+                        exact += value(x);
+                    }
+                }
+                assert!(
+                    (dithered - exact).abs() / 16.0 <= 1.0 / 16.0,
+                    "block at x={} averages {} vs {}",
+                    block_x,
+                    dithered / 16.0,
+                    exact / 16.0
+                );
+            }
+        }
+        // Whole levels stay exact
+        assert_eq!(dither_channel(37.0, 3, 1), 37);
+        assert_eq!(dither_channel(255.0, 0, 3), 255);
+
+        // Downsampling: every 2x2 block averages 100.5, which truncation
+        // flattens to 100 and dithering splits evenly between 100 and 101
+        let pixels: Vec<Color32> = (0..16 * 16)
+            .map(|i| Color32::from_gray(if i % 2 == 0 { 100 } else { 101 }))
+            .collect();
+        let flat = downsample_2x(&pixels, 16, 16, false);
+        assert!(flat.iter().all(|p| p.r() == 100));
+        let dithered = downsample_2x(&pixels, 16, 16, true);
+        let mean = dithered.iter().map(|p| p.r() as f32).sum::<f32>() / dithered.len() as f32;
+        assert!((mean - 100.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_render_rgba_interior_transparent() {
+        let fractal = crate::fractal::Mandelbrot::default();
+        let mut view = test_view();
+        view.center_x = -0.5;
+        let config = RenderConfig::new(20, 20, 100);
+        let pixels =
+            render_high_res_rgba(&fractal, &view, &config, 1, &ExportControl::none()).unwrap();
+        assert_eq!(pixels.len(), 400);
+        // Center pixel maps to (-0.5, 0), inside the main cardioid
+        assert_eq!(pixels[10 * 20 + 10][3], 0);
+        // Top-left corner maps to (-2.5, 2), far outside the set
+        assert_eq!(pixels[0][3], 255);
+
+        // Supersampling keeps the solid areas and antialiases the boundary
+        let smooth =
+            render_high_res_rgba(&fractal, &view, &config, 4, &ExportControl::none()).unwrap();
+        assert_eq!(smooth[10 * 20 + 10][3], 0);
+        assert_eq!(smooth[0][3], 255);
+        assert!(smooth.iter().any(|p| p[3] > 0 && p[3] < 255));
+        assert!(pixels.iter().all(|p| p[3] == 0 || p[3] == 255));
+    }
+
     #[test]
     fn test_pan_regions_left() {
         let engine = RenderEngine::default();
@@ -0,0 +1,155 @@
+//! Batch statistics over a grid of fractal parameter values.
+//!
+//! For each parameter setting the fraction of a coarse sample grid that
+//! stays inside the set is recorded. For Julia sets this is a proxy for
+//! connectedness: connected sets (c in the Mandelbrot set) keep a solid
+//! interior, while Cantor-dust sets have almost none.
+
+use std::fmt::Write;
+
+use rayon::prelude::*;
+
+use crate::fractal::Fractal;
+
+/// One swept parameter: `steps` evenly spaced values from `min` to `max`
+pub struct ParameterAxis<'a> {
+    pub name: &'a str,
+    pub min: f64,
+    pub max: f64,
+    pub steps: u32,
+}
+
+impl ParameterAxis<'_> {
+    fn value(&self, i: u32) -> f64 {
+        if self.steps <= 1 {
+            return self.min;
+        }
+        self.min + (self.max - self.min) * i as f64 / (self.steps - 1) as f64
+    }
+}
+
+/// Interior fraction measured at one (x, y) parameter setting
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParameterSample {
+    pub x: f64,
+    pub y: f64,
+    pub interior_fraction: f64,
+}
+
+/// Fraction of a `samples` x `samples` grid of cell centers over `region`
+/// (re_min, re_max, im_min, im_max) that does not escape within `max_iter`
+pub fn interior_fraction(
+    fractal: &dyn Fractal,
+    region: (f64, f64, f64, f64),
+    samples: u32,
+    max_iter: u32,
+) -> f64 {
+    if samples == 0 {
+        return 0.0;
+    }
+    let (re_min, re_max, im_min, im_max) = region;
+    let step_re = (re_max - re_min) / samples as f64;
+    let step_im = (im_max - im_min) / samples as f64;
+    let inside: usize = (0..samples)
+        .into_par_iter()
+        .map(|row| {
+            let im = im_min + (row as f64 + 0.5) * step_im;
+            (0..samples)
+                .filter(|&col| {
+                    let re = re_min + (col as f64 + 0.5) * step_re;
+                    fractal.compute(re, im, max_iter) >= max_iter
+                })
+                .count()
+        })
+        .sum();
+    inside as f64 / (samples as f64 * samples as f64)
+}
+
+/// Sweep two parameters of `fractal` over their axes (row-major, `y`
+/// outer) and measure the interior fraction at each setting
+pub fn interior_fraction_grid(
+    fractal: &mut dyn Fractal,
+    x: &ParameterAxis,
+    y: &ParameterAxis,
+    region: (f64, f64, f64, f64),
+    samples: u32,
+    max_iter: u32,
+) -> Vec<ParameterSample> {
+    let mut results = Vec::with_capacity(x.steps as usize * y.steps as usize);
+    for j in 0..y.steps {
+        for i in 0..x.steps {
+            let (xv, yv) = (x.value(i), y.value(j));
+            fractal.set_parameter(x.name, xv);
+            fractal.set_parameter(y.name, yv);
+            results.push(ParameterSample {
+                x: xv,
+                y: yv,
+                interior_fraction: interior_fraction(fractal, region, samples, max_iter),
+            });
+        }
+    }
+    results
+}
+
+/// CSV with a header naming the two parameters, one row per sample
+pub fn samples_to_csv(x_name: &str, y_name: &str, samples: &[ParameterSample]) -> String {
+    let mut csv = format!("{},{},interior_fraction\n", x_name, y_name);
+    for s in samples {
+        let _ = writeln!(csv, "{},{},{:.6}", s.x, s.y, s.interior_fraction);
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fractal::registry::FractalRegistry;
+    use crate::fractal::FractalType;
+
+    #[test]
+    fn test_interior_fraction_trivial_regions() {
+        let mandelbrot = FractalRegistry::default()
+            .create(FractalType::Mandelbrot)
+            .unwrap();
+        // Around the origin every c stays bounded; far outside every c
+        // escapes on the first test
+        let inside = interior_fraction(mandelbrot.as_ref(), (-0.1, 0.1, -0.1, 0.1), 16, 100);
+        let outside = interior_fraction(mandelbrot.as_ref(), (3.0, 4.0, 3.0, 4.0), 16, 100);
+        assert_eq!(inside, 1.0);
+        assert_eq!(outside, 0.0);
+        let mixed = interior_fraction(mandelbrot.as_ref(), (-2.0, 1.0, -1.5, 1.5), 32, 100);
+        assert!(mixed > 0.0 && mixed < 1.0);
+    }
+
+    #[test]
+    fn test_interior_fraction_grid_csv() {
+        let mut julia = FractalRegistry::default()
+            .create(FractalType::Julia)
+            .unwrap();
+        let x = ParameterAxis {
+            name: "c_real",
+            min: -0.5,
+            max: 1.5,
+            steps: 2,
+        };
+        let y = ParameterAxis {
+            name: "c_imag",
+            min: 0.0,
+            max: 0.0,
+            steps: 1,
+        };
+        let samples =
+            interior_fraction_grid(julia.as_mut(), &x, &y, (-2.0, 2.0, -2.0, 2.0), 16, 100);
+        assert_eq!(samples.len(), 2);
+        // c = -0.5 is in the Mandelbrot set (solid Julia set), c = 1.5 is not
+        assert_eq!((samples[0].x, samples[0].y), (-0.5, 0.0));
+        assert!(samples[0].interior_fraction > 0.1);
+        assert_eq!(samples[1].interior_fraction, 0.0);
+
+        let csv = samples_to_csv(x.name, y.name, &samples);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "c_real,c_imag,interior_fraction");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2], "1.5,0,0.000000");
+    }
+}
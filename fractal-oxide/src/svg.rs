@@ -0,0 +1,245 @@
+//! Vector export of the iteration field as filled iso-iteration bands.
+//!
+//! Each band is traced with marching squares into closed outlines of the
+//! region at or above that band; the regions are stacked lowest band first so
+//! each visible area shows the color of its own band.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use eframe::egui::Color32;
+
+/// Point on the doubled marching-squares grid (edge midpoints are integers)
+pub type GridPoint = (i32, i32);
+
+/// Band index for a computed point: escaped points are spread across
+/// `0..band_count` by iteration count, interior points get `band_count`.
+pub fn band_of(iterations: u32, escaped: bool, max_iter: u32, band_count: u32) -> u32 {
+    if !escaped {
+        return band_count;
+    }
+    let band = iterations as u64 * band_count as u64 / max_iter.max(1) as u64;
+    (band as u32).min(band_count.saturating_sub(1))
+}
+
+/// Build an SVG document from a row-major band buffer. The lowest band
+/// present fills the background; every higher band present adds one path.
+pub fn bands_to_svg(
+    bands: &[u32],
+    width: u32,
+    height: u32,
+    band_color: impl Fn(u32) -> Color32,
+) -> String {
+    let mut svg = String::new();
+    let _ = writeln!(svg, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = width,
+        h = height
+    );
+
+    let mut levels: Vec<u32> = bands
+        .iter()
+        .copied()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    levels.sort_unstable();
+
+    if let Some(&lowest) = levels.first() {
+        let _ = writeln!(
+            svg,
+            r#"<rect width="{}" height="{}" fill="{}"/>"#,
+            width,
+            height,
+            hex_color(band_color(lowest))
+        );
+    }
+
+    for &level in levels.iter().skip(1) {
+        let loops = trace_region(bands, width, height, |band| band >= level);
+        if loops.is_empty() {
+            continue;
+        }
+        let mut d = String::new();
+        for outline in &loops {
+            for (i, &(kx, ky)) in outline.iter().enumerate() {
+                let _ = write!(
+                    d,
+                    "{}{} {} ",
+                    if i == 0 { "M" } else { "L" },
+                    grid_to_svg(kx),
+                    grid_to_svg(ky)
+                );
+            }
+            d.push_str("Z ");
+        }
+        let _ = writeln!(
+            svg,
+            r#"<path fill="{}" fill-rule="evenodd" d="{}"/>"#,
+            hex_color(band_color(level)),
+            d.trim_end()
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn hex_color(color: Color32) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
+}
+
+/// Grid coordinate to SVG units; corner (x, y) sits at pixel center x+0.5
+fn grid_to_svg(k: i32) -> f64 {
+    k as f64 / 2.0 + 0.5
+}
+
+/// Marching squares over the cells between pixel centers, padded by one
+/// cell of "outside" so every outline closes. Returns closed outlines in
+/// doubled grid coordinates with collinear points removed.
+pub fn trace_region(
+    bands: &[u32],
+    width: u32,
+    height: u32,
+    inside: impl Fn(u32) -> bool,
+) -> Vec<Vec<GridPoint>> {
+    let (w, h) = (width as i32, height as i32);
+    let corner = |x: i32, y: i32| -> bool {
+        x >= 0 && y >= 0 && x < w && y < h && inside(bands[(y * w + x) as usize])
+    };
+
+    let mut neighbours: HashMap<GridPoint, Vec<GridPoint>> = HashMap::new();
+    let mut link = |a: GridPoint, b: GridPoint| {
+        neighbours.entry(a).or_default().push(b);
+        neighbours.entry(b).or_default().push(a);
+    };
+
+    for y in -1..h {
+        for x in -1..w {
+            let case = (corner(x, y) as u8) << 3
+                | (corner(x + 1, y) as u8) << 2
+                | (corner(x + 1, y + 1) as u8) << 1
+                | corner(x, y + 1) as u8;
+            let top = (2 * x + 1, 2 * y);
+            let right = (2 * x + 2, 2 * y + 1);
+            let bottom = (2 * x + 1, 2 * y + 2);
+            let left = (2 * x, 2 * y + 1);
+            // Saddles (5, 10) keep diagonal corners separate
+            match case {
+                1 | 14 => link(left, bottom),
+                2 | 13 => link(bottom, right),
+                3 | 12 => link(left, right),
+                4 | 11 => link(top, right),
+                6 | 9 => link(top, bottom),
+                7 | 8 => link(top, left),
+                5 => {
+                    link(left, bottom);
+                    link(top, right);
+                }
+                10 => {
+                    link(top, left);
+                    link(bottom, right);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Every crossing point has exactly two neighbours, so walking from any
+    // unvisited point returns to it
+    let mut starts: Vec<GridPoint> = neighbours.keys().copied().collect();
+    starts.sort_unstable();
+    let mut visited: HashSet<GridPoint> = HashSet::new();
+    let mut loops = Vec::new();
+    for start in starts {
+        if !visited.insert(start) {
+            continue;
+        }
+        let mut outline = vec![start];
+        let mut prev = start;
+        let mut current = neighbours[&start][0];
+        while current != start {
+            visited.insert(current);
+            outline.push(current);
+            let next = neighbours[&current]
+                .iter()
+                .copied()
+                .find(|&p| p != prev)
+                .unwrap_or(start);
+            prev = current;
+            current = next;
+        }
+        loops.push(remove_collinear(outline));
+    }
+    loops
+}
+
+fn remove_collinear(points: Vec<GridPoint>) -> Vec<GridPoint> {
+    let n = points.len();
+    if n < 3 {
+        return points;
+    }
+    (0..n)
+        .filter(|&i| {
+            let (a, b, c) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+            (b.0 - a.0) * (c.1 - b.1) != (b.1 - a.1) * (c.0 - b.0)
+        })
+        .map(|i| points[i])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_band_of() {
+        assert_eq!(band_of(0, true, 100, 4), 0);
+        assert_eq!(band_of(99, true, 100, 4), 3);
+        assert_eq!(band_of(100, true, 100, 4), 3);
+        assert_eq!(band_of(100, false, 100, 4), 4);
+    }
+
+    #[test]
+    fn test_svg_band_paths() {
+        // Band 0 background, a band-1 ring with a band-2 core and a
+        // separate band-2 block in the corner
+        #[rustfmt::skip]
+        let bands = [
+            0, 0, 0, 0, 0, 0, 0,
+            0, 1, 1, 1, 0, 0, 0,
+            0, 1, 2, 1, 0, 0, 0,
+            0, 1, 1, 1, 0, 2, 2,
+            0, 0, 0, 0, 0, 2, 2,
+        ];
+        let colors = [Color32::BLACK, Color32::RED, Color32::WHITE];
+        let svg = bands_to_svg(&bands, 7, 5, |band| colors[band as usize]);
+
+        assert!(svg.starts_with("<?xml"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<svg ").count(), 1);
+        assert_eq!(svg.matches("<rect ").count(), 1);
+        assert!(svg.contains(r##"<rect width="7" height="5" fill="#000000"/>"##));
+
+        // One path per band above the background
+        let paths: Vec<&str> = svg.lines().filter(|l| l.starts_with("<path ")).collect();
+        assert_eq!(paths.len(), 2);
+        assert!(paths.iter().all(|p| p.ends_with("/>")));
+        assert!(paths[0].contains("#ff0000"));
+        assert!(paths[1].contains("#ffffff"));
+        // Band >= 1 is two separate regions; band 2 is too
+        assert_eq!(paths[0].matches('M').count(), 2);
+        assert_eq!(paths[1].matches('M').count(), 2);
+        assert_eq!(paths[0].matches('M').count(), paths[0].matches('Z').count());
+    }
+
+    #[test]
+    fn test_trace_single_pixel_is_diamond() {
+        let bands = [0, 0, 0, 0, 1, 0, 0, 0, 0];
+        let loops = trace_region(&bands, 3, 3, |b| b >= 1);
+        assert_eq!(loops.len(), 1);
+        assert_eq!(loops[0].len(), 4);
+    }
+}
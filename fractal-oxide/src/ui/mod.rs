@@ -1,9 +1,20 @@
 use eframe::egui;
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use crate::color_pipeline::ColorProcessorType;
+use crate::color_pipeline::{ColorCurve, ColorProcessorType, CURVE_POINTS};
+use crate::fractal::registry::{FractalMetadata, MAX_ITERATIONS_CAP, MIN_ITERATIONS};
 use crate::fractal::{Fractal, FractalType};
-use crate::palette::PaletteType;
+use crate::palette::{self, CustomPalette, PaletteType};
+
+/// Parameter ranges spanning at least this ratio (max/min) get a
+/// logarithmic slider, e.g. Biomorph's overflow bailout
+const LOG_SLIDER_RATIO: f64 = 1e4;
+/// Colors sampled for each palette's preview swatch
+const SWATCH_SAMPLES: usize = 12;
+const SWATCH_SIZE: egui::Vec2 = egui::vec2(60.0, 12.0);
+const CURVE_EDITOR_SIZE: f32 = 140.0;
+const CURVE_POINT_RADIUS: f32 = 4.0;
 
 /// Render status information for display in UI
 pub struct RenderStatus {
@@ -32,12 +43,20 @@ impl RenderStatus {
 pub struct FractalControls {
     pub fractal_type: FractalType,
     pub palette_type: PaletteType,
+    /// Stops for `PaletteType::Custom`, if a palette has been extracted
+    pub custom_palette: Option<Arc<CustomPalette>>,
     pub color_processor_type: ColorProcessorType,
     pub max_iterations: u32,
     pub palette_offset: f32,
+    /// Psychedelic hue cycles across the iteration range
+    pub palette_frequency: f32,
     pub pending_max_iterations: u32,
     pub pending_palette_offset: f32,
+    pub pending_palette_frequency: f32,
     pub pending_fractal_params: HashMap<String, f64>,
+    /// Apply fractal parameters while their sliders are dragged, for a
+    /// coarse live preview
+    pub live_parameter_preview: bool,
 }
 
 impl Default for FractalControls {
@@ -45,12 +64,16 @@ impl Default for FractalControls {
         FractalControls {
             fractal_type: FractalType::Mandelbrot,
             palette_type: PaletteType::Classic,
+            custom_palette: None,
             color_processor_type: ColorProcessorType::Palette,
             max_iterations: 200,
             palette_offset: 0.0,
+            palette_frequency: 1.0,
             pending_max_iterations: 200,
             pending_palette_offset: 0.0,
+            pending_palette_frequency: 1.0,
             pending_fractal_params: HashMap::new(),
+            live_parameter_preview: false,
         }
     }
 }
@@ -61,7 +84,9 @@ impl FractalControls {
         ui: &mut egui::Ui,
         fractal: &mut Box<dyn Fractal>,
         changed: &mut bool,
+        preview: &mut bool,
         render_status: &RenderStatus,
+        metadata: Option<&FractalMetadata>,
     ) {
         ui.heading("Fractal Oxide");
         ui.separator();
@@ -84,6 +109,8 @@ impl FractalControls {
                         FractalType::Spider => "Spider",
                         FractalType::OrbitTrap => "Orbit Trap",
                         FractalType::PickoverStalk => "Pickover Stalk",
+                        FractalType::PolynomialJulia => "Polynomial Julia",
+                        FractalType::MandelJuliaMorph => "Mandelbrot-Julia Morph",
                     })
                     .show_ui(ui, |ui| {
                         ui.selectable_value(
@@ -130,7 +157,26 @@ impl FractalControls {
                             FractalType::PickoverStalk,
                             "Pickover Stalk",
                         );
+                        ui.selectable_value(
+                            &mut self.fractal_type,
+                            FractalType::PolynomialJulia,
+                            "Polynomial Julia",
+                        );
+                        ui.selectable_value(
+                            &mut self.fractal_type,
+                            FractalType::MandelJuliaMorph,
+                            "Mandelbrot-Julia Morph",
+                        );
                     });
+                if let Some(description) = metadata.and_then(|m| m.description.as_deref()) {
+                    ui.small(description);
+                }
+                ui.label(
+                    egui::RichText::new(fractal.iteration_formula())
+                        .small()
+                        .strong(),
+                )
+                .on_hover_text("Iteration formula and escape condition");
             });
 
             ui.add(egui::Separator::default().vertical());
@@ -167,33 +213,12 @@ impl FractalControls {
         ui.horizontal(|ui| {
             ui.vertical(|ui| {
                 ui.label("Color Palette:");
-                egui::ComboBox::from_id_salt("palette_type")
-                    .selected_text(match self.palette_type {
-                        PaletteType::Classic => "Classic",
-                        PaletteType::Fire => "Fire",
-                        PaletteType::Ice => "Ice",
-                        PaletteType::Grayscale => "Grayscale",
-                        PaletteType::Psychedelic => "Psychedelic",
-                    })
-                    .show_ui(ui, |ui| {
-                        ui.selectable_value(
-                            &mut self.palette_type,
-                            PaletteType::Classic,
-                            "Classic",
-                        );
-                        ui.selectable_value(&mut self.palette_type, PaletteType::Fire, "Fire");
-                        ui.selectable_value(&mut self.palette_type, PaletteType::Ice, "Ice");
-                        ui.selectable_value(
-                            &mut self.palette_type,
-                            PaletteType::Grayscale,
-                            "Grayscale",
-                        );
-                        ui.selectable_value(
-                            &mut self.palette_type,
-                            PaletteType::Psychedelic,
-                            "Psychedelic",
-                        );
-                    });
+                palette_combo(
+                    ui,
+                    "palette_type",
+                    &mut self.palette_type,
+                    self.custom_palette.as_deref(),
+                );
             });
 
             ui.add(egui::Separator::default().vertical());
@@ -228,10 +253,54 @@ impl FractalControls {
                             ColorProcessorType::OrbitTrapOrigin,
                             ColorProcessorType::OrbitTrapOrigin.display_name(),
                         );
+                        ui.selectable_value(
+                            &mut self.color_processor_type,
+                            ColorProcessorType::OrbitTrapCross,
+                            ColorProcessorType::OrbitTrapCross.display_name(),
+                        );
+                        ui.selectable_value(
+                            &mut self.color_processor_type,
+                            ColorProcessorType::OrbitTrapLine,
+                            ColorProcessorType::OrbitTrapLine.display_name(),
+                        );
+                        ui.selectable_value(
+                            &mut self.color_processor_type,
+                            ColorProcessorType::ImageTrap,
+                            ColorProcessorType::ImageTrap.display_name(),
+                        );
+                        ui.selectable_value(
+                            &mut self.color_processor_type,
+                            ColorProcessorType::NewtonBasin,
+                            ColorProcessorType::NewtonBasin.display_name(),
+                        );
+                        let dual_selected = matches!(
+                            self.color_processor_type,
+                            ColorProcessorType::DualPalette { .. }
+                        );
+                        let default_dual = ColorProcessorType::DualPalette {
+                            first: PaletteType::Fire,
+                            second: PaletteType::Ice,
+                        };
+                        if ui
+                            .selectable_label(dual_selected, default_dual.display_name())
+                            .clicked()
+                            && !dual_selected
+                        {
+                            self.color_processor_type = default_dual;
+                        }
                     });
             });
         });
 
+        if let ColorProcessorType::DualPalette { first, second } = &mut self.color_processor_type {
+            let custom = self.custom_palette.as_deref();
+            ui.horizontal(|ui| {
+                ui.label("Stripes:");
+                palette_combo(ui, "dual_palette_first", first, custom);
+                palette_combo(ui, "dual_palette_second", second, custom);
+            });
+        }
+
         let mut palette_changed = prev_palette != self.palette_type;
         if prev_processor != self.color_processor_type {
             *changed = true;
@@ -244,21 +313,85 @@ impl FractalControls {
                 self.palette_offset = self.pending_palette_offset;
                 palette_changed = true;
             }
+            ui.label("Banding Frequency:");
+            let response = ui.add(
+                egui::Slider::new(&mut self.pending_palette_frequency, 1.0..=16.0)
+                    .text("frequency"),
+            );
+            if response.drag_stopped() {
+                self.palette_frequency = self.pending_palette_frequency;
+                palette_changed = true;
+            }
         } else {
             self.pending_palette_offset = self.palette_offset;
+            self.pending_palette_frequency = self.palette_frequency;
         }
 
         ui.separator();
         ui.label("Iterations:");
-        let response =
-            ui.add(egui::Slider::new(&mut self.pending_max_iterations, 16..=2000).text("max_iter"));
-        if response.drag_stopped() {
-            self.max_iterations = self.pending_max_iterations;
-            *changed = true;
-        }
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::Slider::new(
+                    &mut self.pending_max_iterations,
+                    MIN_ITERATIONS..=MAX_ITERATIONS_CAP,
+                )
+                .text("max_iter"),
+            );
+            if response.drag_stopped() {
+                self.max_iterations = self.pending_max_iterations;
+                *changed = true;
+            }
+
+            // Exact entry, committed on Enter or focus loss rather than per
+            // keystroke; out-of-range values are clamped on commit
+            let mut typed = self.pending_max_iterations;
+            let response = ui
+                .add(
+                    egui::DragValue::new(&mut typed)
+                        .speed(1.0)
+                        .update_while_editing(false),
+                )
+                .on_hover_text("Type an exact iteration count");
+            if response.drag_stopped() || (response.changed() && !response.dragged()) {
+                self.commit_typed_iterations(typed);
+                *changed = true;
+            } else if response.changed() {
+                self.pending_max_iterations = typed.clamp(MIN_ITERATIONS, MAX_ITERATIONS_CAP);
+            }
+        });
 
         ui.separator();
-        ui.label("Fractal Parameters:");
+        ui.horizontal(|ui| {
+            ui.label("Fractal Parameters:");
+            ui.checkbox(&mut self.live_parameter_preview, "Live preview")
+                .on_hover_text("Render a coarse preview while dragging a parameter");
+        });
+
+        if let Some(presets) = metadata.map(|m| &m.presets).filter(|p| !p.is_empty()) {
+            let mut chosen = None;
+            egui::ComboBox::from_id_salt("parameter_preset")
+                .selected_text("Presets")
+                .show_ui(ui, |ui| {
+                    for preset in presets {
+                        if ui
+                            .selectable_label(false, &preset.name)
+                            .on_hover_text(&preset.description)
+                            .clicked()
+                        {
+                            chosen = Some(preset);
+                        }
+                    }
+                });
+            if let Some(preset) = chosen {
+                preset.apply(fractal.as_mut());
+                for (name, _) in &preset.params {
+                    if let Some(value) = fractal.get_parameter(name) {
+                        self.pending_fractal_params.insert(name.clone(), value);
+                    }
+                }
+                *changed = true;
+            }
+        }
 
         for param in fractal.parameters() {
             let mut value = self
@@ -266,18 +399,230 @@ impl FractalControls {
                 .get(&param.name)
                 .copied()
                 .unwrap_or(param.value);
-            let response =
-                ui.add(egui::Slider::new(&mut value, param.min..=param.max).text(&param.name));
-            self.pending_fractal_params
-                .insert(param.name.clone(), value);
-            if response.drag_stopped() {
-                fractal.set_parameter(&param.name, value);
+            let mut commit = false;
+            let mut dragged = false;
+            ui.horizontal(|ui| {
+                let response = ui
+                    .add(
+                        egui::Slider::new(&mut value, param.min..=param.max)
+                            .logarithmic(
+                                param.min > 0.0 && param.max / param.min >= LOG_SLIDER_RATIO,
+                            )
+                            .text(&param.name),
+                    )
+                    .on_hover_text(param.description());
+                commit |= response.drag_stopped();
+                dragged |= response.dragged() && response.changed();
+
+                // Typed entry accepts any value, committed on Enter or focus
+                // loss rather than per keystroke; the fractal clamps it on apply
+                let response = ui.add(
+                    egui::DragValue::new(&mut value)
+                        .speed(0.001)
+                        .update_while_editing(false),
+                );
+                commit |= response.drag_stopped() || (response.changed() && !response.dragged());
+                dragged |= response.dragged() && response.changed();
+            });
+            if commit {
+                value = apply_parameter(fractal.as_mut(), &param.name, value);
                 *changed = true;
+            } else if dragged && self.live_parameter_preview {
+                value = apply_parameter(fractal.as_mut(), &param.name, value);
+                *preview = true;
             }
+            self.pending_fractal_params
+                .insert(param.name.clone(), value);
         }
 
         if palette_changed {
             *changed = true;
         }
     }
+
+    /// Apply a typed iteration count, clamped to the slider's range, and
+    /// return the stored value
+    pub fn commit_typed_iterations(&mut self, typed: u32) -> u32 {
+        let value = typed.clamp(MIN_ITERATIONS, MAX_ITERATIONS_CAP);
+        self.pending_max_iterations = value;
+        self.max_iterations = value;
+        value
+    }
+}
+
+/// Apply a parameter value and return what the fractal actually stored,
+/// so the controls reflect any clamping.
+fn apply_parameter(fractal: &mut dyn Fractal, name: &str, value: f64) -> f64 {
+    fractal.set_parameter(name, value);
+    fractal.get_parameter(name).unwrap_or(value)
+}
+
+/// Palette picker combo box
+fn palette_combo(
+    ui: &mut egui::Ui,
+    id: &str,
+    palette_type: &mut PaletteType,
+    custom: Option<&CustomPalette>,
+) {
+    let name = |palette: PaletteType| match palette {
+        PaletteType::Classic => "Classic",
+        PaletteType::Fire => "Fire",
+        PaletteType::Ice => "Ice",
+        PaletteType::Grayscale => "Grayscale",
+        PaletteType::Psychedelic => "Psychedelic",
+        PaletteType::Custom => "Custom",
+    };
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(name(*palette_type))
+        .show_ui(ui, |ui| {
+            for palette in [
+                PaletteType::Classic,
+                PaletteType::Fire,
+                PaletteType::Ice,
+                PaletteType::Grayscale,
+                PaletteType::Psychedelic,
+                PaletteType::Custom,
+            ] {
+                ui.horizontal(|ui| {
+                    palette_swatch(ui, palette, custom);
+                    ui.selectable_value(palette_type, palette, name(palette));
+                });
+            }
+        });
+}
+
+/// Square tone-curve editor: drag a control point up or down to set its
+/// output. The returned response reports `drag_stopped` when an edit ends.
+pub fn curve_editor(
+    ui: &mut egui::Ui,
+    curve: &mut ColorCurve,
+    color: egui::Color32,
+) -> egui::Response {
+    let (rect, response) = ui.allocate_exact_size(
+        egui::vec2(CURVE_EDITOR_SIZE, CURVE_EDITOR_SIZE),
+        egui::Sense::drag(),
+    );
+    let segments = (CURVE_POINTS - 1) as f32;
+    let to_screen = |i: usize, value: f32| {
+        egui::pos2(
+            rect.left() + rect.width() * i as f32 / segments,
+            rect.bottom() - rect.height() * value,
+        )
+    };
+
+    // Keep editing the point grabbed at drag start even if the pointer
+    // drifts sideways
+    let grabbed_id = response.id.with("grabbed_point");
+    if let Some(pos) = response.interact_pointer_pos() {
+        if response.drag_started() {
+            let i = ((pos.x - rect.left()) / rect.width() * segments).round();
+            let i = i.clamp(0.0, segments) as usize;
+            ui.data_mut(|data| data.insert_temp(grabbed_id, i));
+        }
+        if response.dragged() {
+            if let Some(i) = ui.data(|data| data.get_temp::<usize>(grabbed_id)) {
+                curve.values[i] = ((rect.bottom() - pos.y) / rect.height()).clamp(0.0, 1.0);
+            }
+        }
+    }
+
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, egui::Color32::from_gray(24));
+    painter.line_segment(
+        [rect.left_bottom(), rect.right_top()],
+        egui::Stroke::new(1.0, egui::Color32::from_gray(70)),
+    );
+    let points: Vec<egui::Pos2> = (0..CURVE_POINTS)
+        .map(|i| to_screen(i, curve.values[i]))
+        .collect();
+    painter.add(egui::Shape::line(
+        points.clone(),
+        egui::Stroke::new(2.0, color),
+    ));
+    for point in points {
+        painter.circle_filled(point, CURVE_POINT_RADIUS, color);
+    }
+    response
+}
+
+/// Colors evenly spaced across a palette from t = 0 to t = 1
+fn palette_swatch_colors(
+    palette_type: PaletteType,
+    custom: Option<&CustomPalette>,
+    samples: usize,
+) -> Vec<egui::Color32> {
+    let last = samples.saturating_sub(1).max(1) as f32;
+    (0..samples)
+        .map(|i| palette::get_custom_banded_color(palette_type, custom, i as f32 / last, 0.0, 1.0))
+        .collect()
+}
+
+/// Small gradient strip previewing a palette
+fn palette_swatch(ui: &mut egui::Ui, palette_type: PaletteType, custom: Option<&CustomPalette>) {
+    let (rect, _) = ui.allocate_exact_size(SWATCH_SIZE, egui::Sense::hover());
+    let colors = palette_swatch_colors(palette_type, custom, SWATCH_SAMPLES);
+    let step = rect.width() / colors.len() as f32;
+    for (i, color) in colors.into_iter().enumerate() {
+        let x = rect.left() + i as f32 * step;
+        let cell = egui::Rect::from_min_max(
+            egui::pos2(x, rect.top()),
+            egui::pos2(x + step, rect.bottom()),
+        );
+        ui.painter().rect_filled(cell, 0.0, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fractal::Julia;
+
+    #[test]
+    fn test_palette_swatch_colors() {
+        let colors = palette_swatch_colors(PaletteType::Grayscale, None, SWATCH_SAMPLES);
+        assert_eq!(colors.len(), SWATCH_SAMPLES);
+        assert_eq!(
+            colors[0],
+            palette::get_color(PaletteType::Grayscale, 0.0, 0.0)
+        );
+        assert_eq!(
+            colors[SWATCH_SAMPLES - 1],
+            palette::get_color(PaletteType::Grayscale, 1.0, 0.0)
+        );
+        assert_eq!(palette_swatch_colors(PaletteType::Fire, None, 1).len(), 1);
+        assert!(palette_swatch_colors(PaletteType::Fire, None, 0).is_empty());
+    }
+
+    #[test]
+    fn test_typed_iterations_clamped() {
+        let mut controls = FractalControls::default();
+        assert_eq!(controls.commit_typed_iterations(512), 512);
+        assert_eq!(controls.pending_max_iterations, 512);
+        assert_eq!(controls.max_iterations, 512);
+
+        assert_eq!(
+            controls.commit_typed_iterations(1_000_000),
+            MAX_ITERATIONS_CAP
+        );
+        assert_eq!(controls.pending_max_iterations, MAX_ITERATIONS_CAP);
+        assert_eq!(controls.commit_typed_iterations(0), MIN_ITERATIONS);
+        assert_eq!(controls.max_iterations, MIN_ITERATIONS);
+    }
+
+    #[test]
+    fn test_apply_parameter_clamps_typed_value() {
+        let mut julia = Julia::default();
+        let param = julia
+            .parameters()
+            .into_iter()
+            .find(|p| p.name == "c_real")
+            .unwrap();
+
+        let stored = apply_parameter(&mut julia, "c_real", param.max + 5.0);
+        assert_eq!(stored, param.max);
+        assert_eq!(julia.get_parameter("c_real"), Some(param.max));
+
+        let stored = apply_parameter(&mut julia, "c_real", -0.123456);
+        assert_eq!(stored, -0.123456);
+    }
 }
@@ -171,30 +171,68 @@ impl Viewport {
     }
 
     /// Calculate the pixel shift for pan optimization
-    /// Returns (shift_x, shift_y) in pixels for the given pan amount
+    /// Returns (shift_x, shift_y) in whole pixels for a `pan_fixed(dx, dy)`
     pub fn calculate_pixel_shift(&self, dx: f64, dy: f64, width: u32, height: u32) -> (i32, i32) {
-        // Fractal pan: dx * 0.5 / zoom
-        // Horizontal visible range: 4.0 * aspect / zoom
-        // Vertical visible range: 4.0 / zoom
-        if self.aspect_ratio == 0.0 {
+        if width == 0 || height == 0 || self.zoom <= 0.0 {
             return (0, 0);
         }
-        let shift_x = (-dx * width as f64 / (8.0 * self.aspect_ratio)) as i32;
-        let shift_y = (dy * height as f64 / 8.0) as i32;
+        // Square pixels: the same scale applies to both axes
+        let world_per_pixel = self.world_units_per_pixel(height as f64);
+        let pan_amount = 0.5 / self.zoom;
+        let shift_x = (-dx * pan_amount / world_per_pixel).round() as i32;
+        let shift_y = (dy * pan_amount / world_per_pixel).round() as i32;
 
         (shift_x, shift_y)
     }
 
+    /// World-space center offset equivalent to shifting the image by whole pixels
+    /// (inverse of `calculate_pixel_shift`)
+    pub fn pixel_shift_to_world(&self, shift_x: i32, shift_y: i32, height: u32) -> (f64, f64) {
+        if height == 0 || self.zoom <= 0.0 {
+            return (0.0, 0.0);
+        }
+        let world_per_pixel = self.world_units_per_pixel(height as f64);
+        (
+            -(shift_x as f64) * world_per_pixel,
+            shift_y as f64 * world_per_pixel,
+        )
+    }
+
     /// Get the scale in world units per pixel
     pub fn world_units_per_pixel(&self, screen_pixels: f64) -> f64 {
         4.0 / (screen_pixels * self.zoom)
     }
+
+    /// Pixel spacing at `height` pixels, measured in f64 ulps of the
+    /// largest coordinate in view. Below about 1 neighbouring pixels round
+    /// to the same coordinate and the image breaks up into blocks.
+    pub fn pixel_spacing_ulps(&self, height: u32) -> f64 {
+        let ((min_re, min_im), (max_re, max_im)) = self.visible_rect();
+        let magnitude = [min_re, min_im, max_re, max_im]
+            .iter()
+            .fold(f64::MIN_POSITIVE, |m, v| m.max(v.abs()));
+        self.world_units_per_pixel(height.max(1) as f64) / (magnitude * f64::EPSILON)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_pixel_spacing_ulps() {
+        let vp = Viewport::from_view(-0.75, 0.1, 1.0, 800, 600);
+        assert!(vp.pixel_spacing_ulps(600) > 1e12);
+
+        // Same framing rendered at 8x the rows: 8x finer spacing, now past
+        // what f64 can resolve
+        let deep = Viewport::from_view(-0.75, 0.1, 1e13, 800, 600);
+        let ratio = deep.pixel_spacing_ulps(600) / deep.pixel_spacing_ulps(4800);
+        assert!((ratio - 8.0).abs() < 1e-9);
+        assert!(deep.pixel_spacing_ulps(600) > 1.0);
+        assert!(deep.pixel_spacing_ulps(4800) < 1.0);
+    }
+
     #[test]
     fn test_viewport_default() {
         let vp = Viewport::default();
@@ -244,6 +282,32 @@ mod tests {
         assert!(vp.center().1 > 0.0);
     }
 
+    #[test]
+    fn test_pixel_shift_matches_world_per_pixel() {
+        for &(zoom, width, height) in &[(1.0, 800, 600), (37.5, 1200, 785), (1e9, 333, 777)] {
+            let vp = Viewport::from_view(0.0, 0.0, zoom, width, height);
+            let (sx, sy) = vp.calculate_pixel_shift(1.0, 1.0, width, height);
+
+            // One keyboard pan step expressed in pixels at this scale
+            let expected = 0.5 / zoom / vp.world_units_per_pixel(height as f64);
+            assert_eq!(sx, -(expected.round() as i32));
+            assert_eq!(sy, expected.round() as i32);
+
+            // A pixel at (x, y) before the pan shows the same point at
+            // (x + sx, y + sy) after it, so reused pixels line up exactly
+            let (wx, wy) = vp.pixel_shift_to_world(sx, sy, height);
+            let world_per_pixel = vp.world_units_per_pixel(height as f64);
+            let mut after = vp;
+            after.center.re += wx;
+            after.center.im += wy;
+            let before_px = vp.screen_to_world(100, 100, width, height);
+            let after_px =
+                after.screen_to_world((100 + sx) as u32, (100 + sy) as u32, width, height);
+            assert!((before_px.re - after_px.re).abs() < world_per_pixel * 1e-6);
+            assert!((before_px.im - after_px.im).abs() < world_per_pixel * 1e-6);
+        }
+    }
+
     #[test]
     fn test_zoom() {
         let mut vp = Viewport::new(0.0, 0.0, 1.0);